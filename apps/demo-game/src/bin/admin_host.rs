@@ -1,39 +1,263 @@
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     env,
     net::SocketAddr,
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
 };
 
 use anyhow::{Context, Result};
 use axum::{
     extract::{
         ws::{Message, WebSocket, WebSocketUpgrade},
-        Path, State,
+        FromRequestParts, Path, Query, RawPathParams, State,
     },
-    http::StatusCode,
+    http::{request::Parts, StatusCode},
     response::IntoResponse,
-    routing::{get, post},
+    routing::{delete, get, post},
     Json, Router,
 };
 use kitu_app_actions::{ActionValue, AppActionCatalog, AppActionDefinition};
 use kitu_demo_game::{build_demo_runtime, DemoRuntime};
 use kitu_osc_ir::{OscArg, OscMessage};
+use kitu_runtime::{InterestFilter, WorldDelta, WorldSnapshot};
 use kitu_transport::{
     decode_kep_envelope, decode_osc_packet, encode_kep_envelope, KepEnvelope, KEP_PAYLOAD_OSC,
 };
 use serde::{Deserialize, Serialize};
-use tokio::sync::broadcast;
+use tokio::{
+    sync::broadcast,
+    task::AbortHandle,
+    time::{interval, MissedTickBehavior},
+};
 use tower_http::cors::CorsLayer;
 use tracing::{error, info};
 
 const DEFAULT_BIND: &str = "127.0.0.1:8787";
 const KEP_ROUTE_SERVER_EVENT: &str = "/server/event";
 
+/// Id of the room served by the unscoped routes (`/state`, `/ws`, ...). It always exists.
+const DEFAULT_ROOM: &str = "default";
+const MAX_ROOM_ID_LEN: usize = 64;
+
+/// One independent match or sandbox: its own runtime, tick loop, logs and event subscribers.
 #[derive(Clone)]
-struct AppState {
+struct Room {
+    id: Arc<str>,
     inner: Arc<Mutex<GameState>>,
     events: broadcast::Sender<ServerEvent>,
+    next_client_id: Arc<AtomicU64>,
+    ticker: Arc<Mutex<Option<AbortHandle>>>,
+}
+
+impl Room {
+    fn new(id: &str) -> Result<Self> {
+        let (events, _) = broadcast::channel(256);
+        Ok(Self {
+            id: Arc::from(id),
+            inner: Arc::new(Mutex::new(GameState::new()?)),
+            events,
+            next_client_id: Arc::new(AtomicU64::new(1)),
+            ticker: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Starts the room's real-time tick loop on the current tokio runtime.
+    fn start(&self) {
+        let handle = tokio::spawn(tick_loop(self.clone())).abort_handle();
+        if let Ok(mut ticker) = self.ticker.lock() {
+            if let Some(previous) = ticker.replace(handle) {
+                previous.abort();
+            }
+        }
+    }
+
+    /// Stops the tick loop; the runtime keeps its state but no longer advances on its own.
+    fn stop(&self) {
+        if let Some(handle) = self.ticker.lock().ok().and_then(|mut ticker| ticker.take()) {
+            handle.abort();
+        }
+    }
+
+    /// Registers a client whose OSC outputs are routed through `filter`.
+    fn join(&self, filter: InterestFilter) -> Result<RoomClient, ApiError> {
+        let id = format!("ws-{}", self.next_client_id.fetch_add(1, Ordering::Relaxed));
+        self.inner
+            .lock()
+            .map_err(|_| ApiError::state_poisoned())?
+            .runtime
+            .set_client_interest(id.clone(), filter)
+            .map_err(|err| ApiError::bad_request(err.to_string()))?;
+        Ok(RoomClient {
+            room: self.clone(),
+            id,
+        })
+    }
+
+    fn summary(&self) -> Result<RoomSummary> {
+        let guard = self
+            .inner
+            .lock()
+            .map_err(|_| anyhow::anyhow!("state lock poisoned"))?;
+        Ok(RoomSummary {
+            id: self.id.to_string(),
+            tick: guard.runtime.current_tick().get(),
+            objects: guard.snapshot()?.objects.len(),
+            subscribers: self.events.receiver_count(),
+        })
+    }
+}
+
+/// Creates, lists and destroys rooms by id. Shared by every route as the axum state.
+#[derive(Clone)]
+struct RoomManager {
+    rooms: Arc<Mutex<RoomTable>>,
+}
+
+struct RoomTable {
+    rooms: BTreeMap<String, Room>,
+    next_generated_id: u64,
+}
+
+impl RoomManager {
+    /// Creates the table with the default room, whose tick loop starts right away.
+    fn new() -> Result<Self> {
+        let default = Room::new(DEFAULT_ROOM)?;
+        default.start();
+        let rooms = BTreeMap::from([(DEFAULT_ROOM.to_string(), default)]);
+        Ok(Self {
+            rooms: Arc::new(Mutex::new(RoomTable {
+                rooms,
+                next_generated_id: 1,
+            })),
+        })
+    }
+
+    fn table(&self) -> Result<std::sync::MutexGuard<'_, RoomTable>> {
+        self.rooms
+            .lock()
+            .map_err(|_| anyhow::anyhow!("room table lock poisoned"))
+    }
+
+    fn get(&self, id: &str) -> Result<Option<Room>> {
+        Ok(self.table()?.rooms.get(id).cloned())
+    }
+
+    /// Creates a room, generating `room-{n}` when no id is given.
+    fn create(&self, id: Option<String>) -> Result<Room, ApiError> {
+        let mut table = self.table()?;
+        let id = match id {
+            Some(id) => {
+                validate_room_id(&id)?;
+                if table.rooms.contains_key(&id) {
+                    return Err(ApiError::conflict(format!("room already exists: {id}")));
+                }
+                id
+            }
+            None => loop {
+                let id = format!("room-{}", table.next_generated_id);
+                table.next_generated_id += 1;
+                if !table.rooms.contains_key(&id) {
+                    break id;
+                }
+            },
+        };
+        let room = Room::new(&id)?;
+        room.start();
+        table.rooms.insert(id, room.clone());
+        info!("room {} created", room.id);
+        Ok(room)
+    }
+
+    fn list(&self) -> Result<Vec<RoomSummary>> {
+        let rooms: Vec<Room> = self.table()?.rooms.values().cloned().collect();
+        rooms.iter().map(Room::summary).collect()
+    }
+
+    /// Removes a room, stops its tick loop and tells its subscribers, which then disconnect.
+    fn destroy(&self, id: &str) -> Result<(), ApiError> {
+        if id == DEFAULT_ROOM {
+            return Err(ApiError::bad_request(
+                "the default room cannot be destroyed",
+            ));
+        }
+        let room = self
+            .table()?
+            .rooms
+            .remove(id)
+            .ok_or_else(|| ApiError::not_found(format!("unknown room: {id}")))?;
+        room.stop();
+        let _ = room.events.send(ServerEvent::RoomClosed {
+            room: id.to_string(),
+        });
+        info!("room {id} destroyed");
+        Ok(())
+    }
+}
+
+fn validate_room_id(id: &str) -> Result<(), ApiError> {
+    let valid = !id.is_empty()
+        && id.len() <= MAX_ROOM_ID_LEN
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(ApiError::bad_request(format!(
+            "room ids use 1-{MAX_ROOM_ID_LEN} ASCII letters, digits, `-` or `_`: {id:?}"
+        )))
+    }
+}
+
+/// Resolves the room addressed by the request: the `{room}` path segment of `/rooms/{room}/...`
+/// routes, or the default room for the unscoped routes.
+impl FromRequestParts<RoomManager> for Room {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        rooms: &RoomManager,
+    ) -> Result<Self, Self::Rejection> {
+        let params = RawPathParams::from_request_parts(parts, rooms)
+            .await
+            .map_err(|err| ApiError::bad_request(err.body_text()))?;
+        let id = params
+            .iter()
+            .find_map(|(key, value)| (key == "room").then_some(value))
+            .unwrap_or(DEFAULT_ROOM);
+        rooms
+            .get(id)?
+            .ok_or_else(|| ApiError::not_found(format!("unknown room: {id}")))
+    }
+}
+
+/// One WebSocket connection's interest registration; dropping it unregisters the client.
+struct RoomClient {
+    room: Room,
+    id: String,
+}
+
+impl RoomClient {
+    /// Whether this client receives `event`. Only OSC outputs are routed by interest here; world
+    /// state is narrowed per connection by [`StateStream`].
+    fn sees(&self, event: &ServerEvent) -> bool {
+        match event {
+            ServerEvent::Osc { audience, .. } => audience.contains(&self.id),
+            _ => true,
+        }
+    }
+}
+
+impl Drop for RoomClient {
+    fn drop(&mut self) {
+        if let Ok(mut guard) = self.room.inner.lock() {
+            guard.runtime.remove_client_interest(&self.id);
+        }
+    }
 }
 
 struct GameState {
@@ -51,16 +275,10 @@ impl GameState {
         })
     }
 
-    fn snapshot(&self) -> WorldSnapshot {
-        let runtime_snapshot = self.runtime.inspect_world_state();
-        WorldSnapshot {
-            tick: self.runtime.current_tick().get(),
-            objects: runtime_snapshot
-                .objects
-                .iter()
-                .map(WorldObject::from_runtime)
-                .collect(),
-        }
+    fn snapshot(&self) -> Result<WorldSnapshot> {
+        self.runtime
+            .inspect_world_state()
+            .context("snapshot Kitu runtime")
     }
 
     fn push_log(
@@ -85,17 +303,6 @@ impl GameState {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(rename_all = "camelCase")]
-struct WorldObject {
-    id: String,
-    kind: String,
-    x: f32,
-    y: f32,
-    z: f32,
-    color: String,
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 struct DebugLogEntry {
@@ -114,13 +321,6 @@ enum LogLevel {
     Error,
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-struct WorldSnapshot {
-    tick: u64,
-    objects: Vec<WorldObject>,
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 struct ClientOscMessage {
@@ -154,26 +354,57 @@ struct ActionRunResponse {
     snapshot: WorldSnapshot,
 }
 
+#[derive(Debug, Clone, Default, Deserialize)]
+struct CreateRoomRequest {
+    #[serde(default)]
+    id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct RoomSummary {
+    id: String,
+    tick: u64,
+    objects: usize,
+    subscribers: usize,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ActionPath {
+    id: String,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "type", rename_all = "camelCase")]
 enum ServerEvent {
     Connected {
         protocol: &'static str,
+        room: String,
         tick: u64,
     },
     State {
         snapshot: WorldSnapshot,
     },
+    Delta {
+        delta: WorldDelta,
+    },
     Log {
         entry: DebugLogEntry,
     },
     Osc {
         address: String,
         args: Vec<JsonOscArg>,
+        /// Clients whose interest filter lets this output through.
+        #[serde(skip)]
+        audience: Arc<[String]>,
     },
     Error {
         message: String,
     },
+    /// The room was destroyed; the connection closes after this event.
+    RoomClosed {
+        room: String,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -182,29 +413,115 @@ enum WsOutputMode {
     Kep,
 }
 
+/// How a WebSocket client receives world state after the initial full snapshot.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum WsStateMode {
+    #[default]
+    Full,
+    Delta,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct WsParams {
+    #[serde(default)]
+    state: WsStateMode,
+    /// Comma-separated OSC address prefixes, e.g. `/render/,/game/`.
+    prefixes: Option<String>,
+    /// Comma-separated object kinds, e.g. `player,npc`.
+    kinds: Option<String>,
+    /// Object id at the center of the interest radius, usually the client's player.
+    focus: Option<String>,
+    radius: Option<f32>,
+}
+
+impl WsParams {
+    /// Builds the connection's interest filter; without parameters it accepts every output.
+    fn interest(&self) -> Result<InterestFilter, ApiError> {
+        let list = |value: &Option<String>| -> Vec<String> {
+            value
+                .iter()
+                .flat_map(|value| value.split(','))
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(String::from)
+                .collect()
+        };
+        let filter = InterestFilter {
+            address_prefixes: list(&self.prefixes),
+            kinds: list(&self.kinds),
+            radius: None,
+        };
+        match (&self.focus, self.radius) {
+            (Some(focus), Some(radius)) => Ok(filter.within_radius(focus.clone(), radius)),
+            (None, None) => Ok(filter),
+            _ => Err(ApiError::bad_request(
+                "`focus` and `radius` must be given together",
+            )),
+        }
+    }
+}
+
+/// Tracks the last world state sent on one connection so later states can go out as deltas.
+struct StateStream {
+    mode: WsStateMode,
+    interest: InterestFilter,
+    last_sent: Option<WorldSnapshot>,
+}
+
+impl StateStream {
+    fn new(mode: WsStateMode, interest: InterestFilter) -> Self {
+        Self {
+            mode,
+            interest,
+            last_sent: None,
+        }
+    }
+
+    /// Narrows `State` events to the objects the client's interest filter accepts, then
+    /// rewrites them into `Delta` events against the last state this client saw. Objects that
+    /// leave the client's interest show up as despawned.
+    fn outgoing(&mut self, event: ServerEvent) -> ServerEvent {
+        let ServerEvent::State { snapshot } = event else {
+            return event;
+        };
+        let snapshot = self.interest.visible_snapshot(&snapshot);
+        if self.mode == WsStateMode::Full {
+            return ServerEvent::State { snapshot };
+        }
+        let outgoing = match &self.last_sent {
+            Some(last) => ServerEvent::Delta {
+                delta: last.diff(&snapshot),
+            },
+            None => ServerEvent::State {
+                snapshot: snapshot.clone(),
+            },
+        };
+        self.last_sent = Some(snapshot);
+        outgoing
+    }
+
+    /// Forgets the last state so the next one is sent in full, e.g. after missed events.
+    fn reset(&mut self) {
+        self.last_sent = None;
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     tracing_subscriber::fmt()
         .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
         .init();
 
-    let (events, _) = broadcast::channel(256);
-    let state = AppState {
-        inner: Arc::new(Mutex::new(GameState::new()?)),
-        events,
-    };
-
+    let rooms = RoomManager::new()?;
     let app = Router::new()
         .route("/health", get(health))
-        .route("/state", get(state_snapshot))
-        .route("/logs", get(logs_snapshot))
-        .route("/app-actions", get(app_action_catalog))
-        .route("/app-actions/{id}", get(app_action_definition))
-        .route("/app-actions/{id}/run", post(run_app_action))
-        .route("/ws", get(ws_upgrade))
-        .route("/ws/runtime", get(runtime_ws_upgrade))
+        .route("/rooms", get(list_rooms).post(create_room))
+        .route("/rooms/{room}", delete(destroy_room))
+        .merge(room_routes())
+        .nest("/rooms/{room}", room_routes())
         .layer(CorsLayer::permissive())
-        .with_state(state);
+        .with_state(rooms);
 
     let bind = env::var("KITU_DEMO_GAME_BIND")
         .or_else(|_| env::var("KITU_WEB_ADMIN_BIND"))
@@ -218,6 +535,18 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Per-room routes, served unscoped for the default room and under `/rooms/{room}` for any room.
+fn room_routes() -> Router<RoomManager> {
+    Router::new()
+        .route("/state", get(state_snapshot).put(restore_state))
+        .route("/logs", get(logs_snapshot))
+        .route("/app-actions", get(app_action_catalog))
+        .route("/app-actions/{id}", get(app_action_definition))
+        .route("/app-actions/{id}/run", post(run_app_action))
+        .route("/ws", get(ws_upgrade))
+        .route("/ws/runtime", get(runtime_ws_upgrade))
+}
+
 async fn health() -> Json<serde_json::Value> {
     Json(serde_json::json!({
         "status": "ok",
@@ -225,29 +554,53 @@ async fn health() -> Json<serde_json::Value> {
     }))
 }
 
-async fn state_snapshot(State(state): State<AppState>) -> Result<Json<WorldSnapshot>, ApiError> {
-    Ok(Json(snapshot(&state)?))
+async fn list_rooms(State(rooms): State<RoomManager>) -> Result<Json<Vec<RoomSummary>>, ApiError> {
+    Ok(Json(rooms.list()?))
+}
+
+async fn create_room(
+    State(rooms): State<RoomManager>,
+    request: Option<Json<CreateRoomRequest>>,
+) -> Result<(StatusCode, Json<RoomSummary>), ApiError> {
+    let request = request.map(|Json(request)| request).unwrap_or_default();
+    let room = rooms.create(request.id)?;
+    Ok((StatusCode::CREATED, Json(room.summary()?)))
+}
+
+async fn destroy_room(
+    Path(id): Path<String>,
+    State(rooms): State<RoomManager>,
+) -> Result<StatusCode, ApiError> {
+    rooms.destroy(&id)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn state_snapshot(room: Room) -> Result<Json<WorldSnapshot>, ApiError> {
+    Ok(Json(snapshot(&room)?))
 }
 
-async fn logs_snapshot(
-    State(state): State<AppState>,
-) -> Result<Json<Vec<DebugLogEntry>>, ApiError> {
-    let guard = state.inner.lock().map_err(|_| ApiError::state_poisoned())?;
+async fn restore_state(
+    room: Room,
+    Json(snapshot): Json<WorldSnapshot>,
+) -> Result<Json<WorldSnapshot>, ApiError> {
+    Ok(Json(restore_state_request(&room, snapshot)?))
+}
+
+async fn logs_snapshot(room: Room) -> Result<Json<Vec<DebugLogEntry>>, ApiError> {
+    let guard = room.inner.lock().map_err(|_| ApiError::state_poisoned())?;
     Ok(Json(guard.logs.clone()))
 }
 
-async fn app_action_catalog(
-    State(state): State<AppState>,
-) -> Result<Json<AppActionCatalog>, ApiError> {
-    let guard = state.inner.lock().map_err(|_| ApiError::state_poisoned())?;
+async fn app_action_catalog(room: Room) -> Result<Json<AppActionCatalog>, ApiError> {
+    let guard = room.inner.lock().map_err(|_| ApiError::state_poisoned())?;
     Ok(Json(guard.runtime.app_action_catalog().clone()))
 }
 
 async fn app_action_definition(
-    Path(action_id): Path<String>,
-    State(state): State<AppState>,
+    Path(ActionPath { id: action_id }): Path<ActionPath>,
+    room: Room,
 ) -> Result<Json<AppActionDefinition>, ApiError> {
-    let guard = state.inner.lock().map_err(|_| ApiError::state_poisoned())?;
+    let guard = room.inner.lock().map_err(|_| ApiError::state_poisoned())?;
     let action = guard
         .runtime
         .app_action_catalog()
@@ -258,35 +611,46 @@ async fn app_action_definition(
 }
 
 async fn run_app_action(
-    Path(action_id): Path<String>,
-    State(state): State<AppState>,
+    Path(ActionPath { id: action_id }): Path<ActionPath>,
+    room: Room,
     Json(request): Json<ActionRunRequest>,
 ) -> Result<Json<ActionRunResponse>, ApiError> {
     Ok(Json(run_app_action_request(
-        &state,
+        &room,
         action_id,
         request.inputs,
     )?))
 }
 
-async fn ws_upgrade(ws: WebSocketUpgrade, State(state): State<AppState>) -> impl IntoResponse {
-    ws.on_upgrade(move |socket| ws_loop(socket, state))
+async fn ws_upgrade(
+    ws: WebSocketUpgrade,
+    Query(params): Query<WsParams>,
+    room: Room,
+) -> Result<impl IntoResponse, ApiError> {
+    let interest = params.interest()?;
+    let client = room.join(interest.clone())?;
+    let stream = StateStream::new(params.state, interest);
+    Ok(ws.on_upgrade(move |socket| ws_loop(socket, room, client, stream)))
 }
 
 async fn runtime_ws_upgrade(
     ws: WebSocketUpgrade,
-    State(state): State<AppState>,
-) -> impl IntoResponse {
-    ws.on_upgrade(move |socket| runtime_ws_loop(socket, state))
+    Query(params): Query<WsParams>,
+    room: Room,
+) -> Result<impl IntoResponse, ApiError> {
+    let interest = params.interest()?;
+    let client = room.join(interest.clone())?;
+    let stream = StateStream::new(params.state, interest);
+    Ok(ws.on_upgrade(move |socket| runtime_ws_loop(socket, room, client, stream)))
 }
 
-async fn ws_loop(mut socket: WebSocket, state: AppState) {
-    if let Err(err) = send_initial_state(&mut socket, &state).await {
+async fn ws_loop(mut socket: WebSocket, room: Room, client: RoomClient, mut stream: StateStream) {
+    if let Err(err) = send_initial_state(&mut socket, &room, &mut stream).await {
         error!("failed to send initial state: {err}");
         return;
     }
 
-    let mut receiver = state.events.subscribe();
+    let mut receiver = room.events.subscribe();
     let mut output_mode = WsOutputMode::Json;
 
     loop {
@@ -296,22 +660,22 @@ async fn ws_loop(mut socket: WebSocket, state: AppState) {
                     Some(Ok(Message::Text(text))) => {
                         match serde_json::from_str::<ClientOscMessage>(&text) {
                             Ok(message) => {
-                                if let Err(err) = handle_client_osc(&state, message) {
-                                    broadcast_error(&state, err.to_string());
+                                if let Err(err) = handle_client_osc(&room, message) {
+                                    broadcast_error(&room, err.to_string());
                                 }
                             }
-                            Err(err) => broadcast_error(&state, format!("invalid client message: {err}")),
+                            Err(err) => broadcast_error(&room, format!("invalid client message: {err}")),
                         }
                     }
                     Some(Ok(Message::Binary(bytes))) => {
                         output_mode = WsOutputMode::Kep;
                         match decode_kep_osc_message(&bytes) {
                             Ok(message) => {
-                                if let Err(err) = handle_client_osc_message(&state, message) {
-                                    broadcast_error(&state, err.to_string());
+                                if let Err(err) = handle_client_osc_message(&room, message) {
+                                    broadcast_error(&room, err.to_string());
                                 }
                             }
-                            Err(err) => broadcast_error(&state, format!("invalid client KEP message: {err:#}")),
+                            Err(err) => broadcast_error(&room, format!("invalid client KEP message: {err:#}")),
                         }
                     }
                     Some(Ok(Message::Close(_))) | None => break,
@@ -325,14 +689,24 @@ async fn ws_loop(mut socket: WebSocket, state: AppState) {
             event = receiver.recv() => {
                 match event {
                     Ok(event) => {
+                        if !client.sees(&event) {
+                            continue;
+                        }
+                        let closed = matches!(event, ServerEvent::RoomClosed { .. });
+                        let event = stream.outgoing(event);
                         if let Err(err) = send_event_with_mode(&mut socket, &event, output_mode).await {
                             error!("websocket send error: {err}");
                             break;
                         }
+                        if closed {
+                            break;
+                        }
                     }
                     Err(broadcast::error::RecvError::Lagged(_)) => {
-                        if let Ok(snapshot) = snapshot(&state) {
-                            let _ = send_event_with_mode(&mut socket, &ServerEvent::State { snapshot }, output_mode).await;
+                        stream.reset();
+                        if let Ok(snapshot) = snapshot(&room) {
+                            let event = stream.outgoing(ServerEvent::State { snapshot });
+                            let _ = send_event_with_mode(&mut socket, &event, output_mode).await;
                         }
                     }
                     Err(broadcast::error::RecvError::Closed) => break,
@@ -342,13 +716,18 @@ async fn ws_loop(mut socket: WebSocket, state: AppState) {
     }
 }
 
-async fn runtime_ws_loop(mut socket: WebSocket, state: AppState) {
-    if let Err(err) = send_initial_runtime_state(&mut socket, &state).await {
+async fn runtime_ws_loop(
+    mut socket: WebSocket,
+    room: Room,
+    client: RoomClient,
+    mut stream: StateStream,
+) {
+    if let Err(err) = send_initial_runtime_state(&mut socket, &room, &mut stream).await {
         error!("failed to send initial runtime state: {err}");
         return;
     }
 
-    let mut receiver = state.events.subscribe();
+    let mut receiver = room.events.subscribe();
     let mut output_mode = WsOutputMode::Json;
 
     loop {
@@ -358,22 +737,22 @@ async fn runtime_ws_loop(mut socket: WebSocket, state: AppState) {
                     Some(Ok(Message::Text(text))) => {
                         match serde_json::from_str::<ClientOscMessage>(&text) {
                             Ok(message) => {
-                                if let Err(err) = handle_runtime_osc(&state, message) {
-                                    broadcast_error(&state, err.to_string());
+                                if let Err(err) = handle_runtime_osc(&room, message) {
+                                    broadcast_error(&room, err.to_string());
                                 }
                             }
-                            Err(err) => broadcast_error(&state, format!("invalid runtime client message: {err}")),
+                            Err(err) => broadcast_error(&room, format!("invalid runtime client message: {err}")),
                         }
                     }
                     Some(Ok(Message::Binary(bytes))) => {
                         output_mode = WsOutputMode::Kep;
                         match decode_kep_osc_message(&bytes) {
                             Ok(message) => {
-                                if let Err(err) = handle_runtime_osc_message(&state, message) {
-                                    broadcast_error(&state, err.to_string());
+                                if let Err(err) = handle_runtime_osc_message(&room, message) {
+                                    broadcast_error(&room, err.to_string());
                                 }
                             }
-                            Err(err) => broadcast_error(&state, format!("invalid runtime KEP message: {err:#}")),
+                            Err(err) => broadcast_error(&room, format!("invalid runtime KEP message: {err:#}")),
                         }
                     }
                     Some(Ok(Message::Close(_))) | None => break,
@@ -387,14 +766,24 @@ async fn runtime_ws_loop(mut socket: WebSocket, state: AppState) {
             event = receiver.recv() => {
                 match event {
                     Ok(event) => {
+                        if !client.sees(&event) {
+                            continue;
+                        }
+                        let closed = matches!(event, ServerEvent::RoomClosed { .. });
+                        let event = stream.outgoing(event);
                         if let Err(err) = send_event_with_mode(&mut socket, &event, output_mode).await {
                             error!("runtime websocket send error: {err}");
                             break;
                         }
+                        if closed {
+                            break;
+                        }
                     }
                     Err(broadcast::error::RecvError::Lagged(_)) => {
-                        if let Ok(snapshot) = snapshot(&state) {
-                            let _ = send_event_with_mode(&mut socket, &ServerEvent::State { snapshot }, output_mode).await;
+                        stream.reset();
+                        if let Ok(snapshot) = snapshot(&room) {
+                            let event = stream.outgoing(ServerEvent::State { snapshot });
+                            let _ = send_event_with_mode(&mut socket, &event, output_mode).await;
                         }
                     }
                     Err(broadcast::error::RecvError::Closed) => break,
@@ -404,9 +793,13 @@ async fn runtime_ws_loop(mut socket: WebSocket, state: AppState) {
     }
 }
 
-async fn send_initial_state(socket: &mut WebSocket, state: &AppState) -> Result<()> {
+async fn send_initial_state(
+    socket: &mut WebSocket,
+    room: &Room,
+    stream: &mut StateStream,
+) -> Result<()> {
     let (tick, logs) = {
-        let guard = state
+        let guard = room
             .inner
             .lock()
             .map_err(|_| anyhow::anyhow!("state lock poisoned"))?;
@@ -417,15 +810,16 @@ async fn send_initial_state(socket: &mut WebSocket, state: &AppState) -> Result<
         socket,
         &ServerEvent::Connected {
             protocol: "osc-ir-json-v1",
+            room: room.id.to_string(),
             tick,
         },
     )
     .await?;
     send_event(
         socket,
-        &ServerEvent::State {
-            snapshot: snapshot(state)?,
-        },
+        &stream.outgoing(ServerEvent::State {
+            snapshot: snapshot(room)?,
+        }),
     )
     .await?;
 
@@ -436,9 +830,13 @@ async fn send_initial_state(socket: &mut WebSocket, state: &AppState) -> Result<
     Ok(())
 }
 
-async fn send_initial_runtime_state(socket: &mut WebSocket, state: &AppState) -> Result<()> {
+async fn send_initial_runtime_state(
+    socket: &mut WebSocket,
+    room: &Room,
+    stream: &mut StateStream,
+) -> Result<()> {
     let tick = {
-        let guard = state
+        let guard = room
             .inner
             .lock()
             .map_err(|_| anyhow::anyhow!("state lock poisoned"))?;
@@ -449,15 +847,16 @@ async fn send_initial_runtime_state(socket: &mut WebSocket, state: &AppState) ->
         socket,
         &ServerEvent::Connected {
             protocol: "kitu-runtime-osc-ir-json-v1",
+            room: room.id.to_string(),
             tick,
         },
     )
     .await?;
     send_event(
         socket,
-        &ServerEvent::State {
-            snapshot: snapshot(state)?,
-        },
+        &stream.outgoing(ServerEvent::State {
+            snapshot: snapshot(room)?,
+        }),
     )
     .await?;
 
@@ -488,45 +887,46 @@ async fn send_event_with_mode(
     }
 }
 
-fn handle_client_osc(state: &AppState, client_message: ClientOscMessage) -> Result<()> {
-    handle_client_osc_message(state, client_message.to_osc_message())
+fn handle_client_osc(room: &Room, client_message: ClientOscMessage) -> Result<()> {
+    handle_client_osc_message(room, client_message.to_osc_message())
 }
 
-fn handle_client_osc_message(state: &AppState, osc_message: OscMessage) -> Result<()> {
+fn handle_client_osc_message(room: &Room, osc_message: OscMessage) -> Result<()> {
     if let Some((action_id, inputs)) = action_request_from_osc_message(&osc_message)? {
-        run_app_action_request(state, action_id, inputs)?;
+        run_app_action_request(room, action_id, inputs)?;
         return Ok(());
     }
 
-    let events = run_runtime_osc_request(state, osc_message)?;
+    let events = run_runtime_osc_request(room, osc_message)?;
     for event in events {
-        let _ = state.events.send(event);
+        let _ = room.events.send(event);
     }
     Ok(())
 }
 
-fn handle_runtime_osc(state: &AppState, client_message: ClientOscMessage) -> Result<()> {
-    handle_runtime_osc_message(state, client_message.to_osc_message())
+fn handle_runtime_osc(room: &Room, client_message: ClientOscMessage) -> Result<()> {
+    handle_runtime_osc_message(room, client_message.to_osc_message())
 }
 
-fn handle_runtime_osc_message(state: &AppState, osc_message: OscMessage) -> Result<()> {
-    let events = run_runtime_osc_request(state, osc_message)?;
+fn handle_runtime_osc_message(room: &Room, osc_message: OscMessage) -> Result<()> {
+    let events = run_runtime_osc_request(room, osc_message)?;
     for event in events {
-        let _ = state.events.send(event);
+        let _ = room.events.send(event);
     }
     Ok(())
 }
 
-fn run_runtime_osc_request(state: &AppState, osc_message: OscMessage) -> Result<Vec<ServerEvent>> {
+fn run_runtime_osc_request(room: &Room, osc_message: OscMessage) -> Result<Vec<ServerEvent>> {
     let mut bundle = kitu_osc_ir::OscBundle::new();
     bundle.push(osc_message.clone());
 
-    let mut guard = state
+    let mut guard = room
         .inner
         .lock()
         .map_err(|_| anyhow::anyhow!("state lock poisoned"))?;
     let mut outgoing_events = Vec::new();
 
+    // The room's tick loop applies the input on its next tick, or on a step while paused.
     guard.runtime.enqueue_input(bundle);
     outgoing_events.push(ServerEvent::Log {
         entry: guard.push_log(
@@ -536,21 +936,73 @@ fn run_runtime_osc_request(state: &AppState, osc_message: OscMessage) -> Result<
         ),
     });
 
-    guard.runtime.tick_once().context("tick Kitu runtime")?;
-    for bundle in guard.runtime.drain_output_buffer() {
-        for message in bundle.messages {
-            outgoing_events.push(ServerEvent::Osc {
-                address: message.address.clone(),
-                args: message.args.into_iter().map(JsonOscArg::from).collect(),
-            });
+    Ok(outgoing_events)
+}
+
+/// Advances the room's runtime every frame by the wall-clock time since its previous update.
+///
+/// Rooms do not use `RuntimeDriver`: HTTP and websocket handlers run app actions, restores and
+/// pause/step against the runtime and append to the room log under the same lock, and the state
+/// events below need each update's [`UpdateReport`](kitu_runtime::UpdateReport), which the
+/// driver does not publish.
+async fn tick_loop(room: Room) {
+    let frame_time = match room.inner.lock() {
+        Ok(guard) => guard.runtime.config().frame_time(),
+        Err(_) => return,
+    };
+    let mut frames = interval(frame_time);
+    frames.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut last_update = Instant::now();
+    loop {
+        frames.tick().await;
+        let now = Instant::now();
+        match tick_room(&room, now - last_update) {
+            Ok(events) => {
+                for event in events {
+                    let _ = room.events.send(event);
+                }
+            }
+            Err(err) => broadcast_error(&room, format!("{err:#}")),
         }
+        last_update = now;
     }
+}
 
-    outgoing_events.push(ServerEvent::State {
-        snapshot: guard.snapshot(),
-    });
+/// Runs the ticks `elapsed` allows and returns their OSC outputs, followed by the new state when
+/// the ticks spawned, despawned, moved or re-kinded a world object.
+fn tick_room(room: &Room, elapsed: Duration) -> Result<Vec<ServerEvent>> {
+    let mut guard = room
+        .inner
+        .lock()
+        .map_err(|_| anyhow::anyhow!("state lock poisoned"))?;
+    let report = guard
+        .runtime
+        .update(elapsed.as_secs_f32())
+        .context("update Kitu runtime")?;
+    let mut events = drain_osc_events(&mut guard.runtime);
+    if report.objects_changed {
+        events.push(ServerEvent::State {
+            snapshot: guard.snapshot()?,
+        });
+    }
+    Ok(events)
+}
 
-    Ok(outgoing_events)
+/// Turns pending runtime outputs into OSC events addressed to the clients that can see them.
+fn drain_osc_events(runtime: &mut DemoRuntime) -> Vec<ServerEvent> {
+    let messages: Vec<OscMessage> = runtime
+        .drain_output_buffer()
+        .into_iter()
+        .flat_map(|bundle| bundle.messages)
+        .collect();
+    messages
+        .into_iter()
+        .map(|message| ServerEvent::Osc {
+            audience: runtime.interested_clients(&message).into(),
+            address: message.address,
+            args: message.args.into_iter().map(JsonOscArg::from).collect(),
+        })
+        .collect()
 }
 
 fn decode_kep_osc_message(bytes: &[u8]) -> Result<OscMessage> {
@@ -572,11 +1024,11 @@ fn encode_server_event_envelope(event: &ServerEvent) -> Result<Vec<u8>> {
 }
 
 fn run_app_action_request(
-    state: &AppState,
+    room: &Room,
     action_id: String,
     inputs: HashMap<String, ActionValue>,
 ) -> Result<ActionRunResponse> {
-    let mut guard = state
+    let mut guard = room
         .inner
         .lock()
         .map_err(|_| anyhow::anyhow!("state lock poisoned"))?;
@@ -605,17 +1057,11 @@ fn run_app_action_request(
         Some(osc_message.address.clone()),
     );
 
-    guard.runtime.tick_once().context("tick Kitu runtime")?;
-    for bundle in guard.runtime.drain_output_buffer() {
-        for message in bundle.messages {
-            outgoing_events.push(ServerEvent::Osc {
-                address: message.address.clone(),
-                args: message.args.into_iter().map(JsonOscArg::from).collect(),
-            });
-        }
-    }
+    // Outputs of simulation steps are sent right away; those of other actions follow with the
+    // room's next tick.
+    outgoing_events.extend(drain_osc_events(&mut guard.runtime));
 
-    let snapshot = guard.snapshot();
+    let snapshot = guard.snapshot()?;
     outgoing_events.push(ServerEvent::State {
         snapshot: snapshot.clone(),
     });
@@ -628,12 +1074,40 @@ fn run_app_action_request(
     drop(guard);
 
     for event in outgoing_events {
-        let _ = state.events.send(event);
+        let _ = room.events.send(event);
     }
 
     Ok(response)
 }
 
+fn restore_state_request(room: &Room, snapshot: WorldSnapshot) -> Result<WorldSnapshot> {
+    let mut guard = room
+        .inner
+        .lock()
+        .map_err(|_| anyhow::anyhow!("state lock poisoned"))?;
+    guard
+        .runtime
+        .restore_world_state(&snapshot)
+        .context("restore world snapshot")?;
+    let entry = guard.push_log(
+        LogLevel::Info,
+        format!(
+            "world restored to tick {} with {} objects",
+            snapshot.tick,
+            snapshot.objects.len()
+        ),
+        None,
+    );
+    let restored = guard.snapshot()?;
+    drop(guard);
+
+    let _ = room.events.send(ServerEvent::Log { entry });
+    let _ = room.events.send(ServerEvent::State {
+        snapshot: restored.clone(),
+    });
+    Ok(restored)
+}
+
 fn action_request_from_osc_message(
     message: &OscMessage,
 ) -> Result<Option<(String, HashMap<String, ActionValue>)>> {
@@ -678,7 +1152,36 @@ fn action_request_from_osc_message(
                 ]),
             )))
         }
+        "/admin/world/despawn" => {
+            let id = string_arg(message, 0)
+                .ok_or_else(|| anyhow::anyhow!("/admin/world/despawn expects object id"))?;
+            Ok(Some((
+                "despawn-object".to_string(),
+                HashMap::from([("id".to_string(), ActionValue::String(id.to_string()))]),
+            )))
+        }
         "/admin/world/reset" => Ok(Some(("reset-world".to_string(), HashMap::new()))),
+        "/admin/sim/pause" => Ok(Some(("pause-simulation".to_string(), HashMap::new()))),
+        "/admin/sim/resume" => Ok(Some(("resume-simulation".to_string(), HashMap::new()))),
+        "/admin/sim/step" => {
+            let ticks = match message.args.first() {
+                None => 1,
+                Some(OscArg::Int(value)) => *value,
+                Some(_) => anyhow::bail!("/admin/sim/step expects an integer tick count"),
+            };
+            Ok(Some((
+                "step-simulation".to_string(),
+                HashMap::from([("ticks".to_string(), ActionValue::Int(ticks))]),
+            )))
+        }
+        "/admin/sim/time-scale" => {
+            let scale = numeric_arg(message, 0)
+                .ok_or_else(|| anyhow::anyhow!("/admin/sim/time-scale expects scale"))?;
+            Ok(Some((
+                "set-time-scale".to_string(),
+                HashMap::from([("scale".to_string(), ActionValue::Float(scale))]),
+            )))
+        }
         _ => Ok(None),
     }
 }
@@ -699,40 +1202,16 @@ fn string_arg(message: &OscMessage, index: usize) -> Option<&str> {
     }
 }
 
-fn color_for_kind(kind: &str) -> &'static str {
-    match kind {
-        "player" => "#38bdf8",
-        "spawn-point" => "#2dd4bf",
-        "enemy" => "#fb7185",
-        "treasure" => "#facc15",
-        "trigger" => "#a78bfa",
-        _ => "#60a5fa",
-    }
-}
-
-fn snapshot(state: &AppState) -> Result<WorldSnapshot> {
-    let guard = state
+fn snapshot(room: &Room) -> Result<WorldSnapshot> {
+    let guard = room
         .inner
         .lock()
         .map_err(|_| anyhow::anyhow!("state lock poisoned"))?;
-    Ok(guard.snapshot())
+    guard.snapshot()
 }
 
-fn broadcast_error(state: &AppState, message: String) {
-    let _ = state.events.send(ServerEvent::Error { message });
-}
-
-impl WorldObject {
-    fn from_runtime(object: &kitu_runtime::WorldObject) -> Self {
-        Self {
-            id: object.id.clone(),
-            kind: object.kind.clone(),
-            x: object.transform.x,
-            y: object.transform.y,
-            z: object.transform.z,
-            color: color_for_kind(&object.kind).to_string(),
-        }
-    }
+fn broadcast_error(room: &Room, message: String) {
+    let _ = room.events.send(ServerEvent::Error { message });
 }
 
 impl ClientOscMessage {
@@ -779,23 +1258,41 @@ impl From<OscArg> for JsonOscArg {
 }
 
 #[derive(Debug)]
-struct ApiError(anyhow::Error);
+struct ApiError {
+    status: StatusCode,
+    error: anyhow::Error,
+}
 
 impl ApiError {
+    fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            error: anyhow::anyhow!(message.into()),
+        }
+    }
+
     fn state_poisoned() -> Self {
-        Self(anyhow::anyhow!("state lock poisoned"))
+        Self::new(StatusCode::INTERNAL_SERVER_ERROR, "state lock poisoned")
     }
 
     fn bad_request(message: impl Into<String>) -> Self {
-        Self(anyhow::anyhow!(message.into()))
+        Self::new(StatusCode::BAD_REQUEST, message)
+    }
+
+    fn not_found(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::NOT_FOUND, message)
+    }
+
+    fn conflict(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::CONFLICT, message)
     }
 }
 
 impl IntoResponse for ApiError {
     fn into_response(self) -> axum::response::Response {
         (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({ "error": self.0.to_string() })),
+            self.status,
+            Json(serde_json::json!({ "error": self.error.to_string() })),
         )
             .into_response()
     }
@@ -803,25 +1300,30 @@ impl IntoResponse for ApiError {
 
 impl From<anyhow::Error> for ApiError {
     fn from(value: anyhow::Error) -> Self {
-        Self(value)
+        Self {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            error: value,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use kitu_runtime::WorldTransform;
+
     use super::*;
 
-    fn test_state() -> AppState {
-        let (events, _) = broadcast::channel(16);
-        AppState {
-            inner: Arc::new(Mutex::new(GameState::new().unwrap())),
-            events,
-        }
+    fn test_room() -> Room {
+        Room::new("test").unwrap()
+    }
+
+    fn one_frame(room: &Room) -> Duration {
+        room.inner.lock().unwrap().runtime.config().frame_time()
     }
 
     #[test]
     fn runtime_osc_request_executes_player_move_slice() {
-        let state = test_state();
+        let room = test_room();
         let request = ClientOscMessage {
             address: "/input/move".to_string(),
             args: vec![
@@ -831,11 +1333,15 @@ mod tests {
             ],
         };
 
-        let events = run_runtime_osc_request(&state, request.to_osc_message()).unwrap();
+        let events = run_runtime_osc_request(&room, request.to_osc_message()).unwrap();
+        assert!(events
+            .iter()
+            .all(|event| matches!(event, ServerEvent::Log { .. })));
+        let events = tick_room(&room, one_frame(&room)).unwrap();
         let render = events
             .iter()
             .find_map(|event| match event {
-                ServerEvent::Osc { address, args } if address == "/render/player/transform" => {
+                ServerEvent::Osc { address, args, .. } if address == "/render/player/transform" => {
                     Some(args)
                 }
                 _ => None,
@@ -858,17 +1364,17 @@ mod tests {
         assert!(snapshot.objects.iter().any(|object| {
             object.id == "player:local"
                 && object.kind == "player"
-                && object.x == 1.25
-                && object.y == 0.0
-                && object.z == -0.5
+                && object.transform.x == 1.25
+                && object.transform.y == 0.0
+                && object.transform.z == -0.5
         }));
     }
 
     #[test]
     fn app_action_spawn_broadcasts_world_state_for_unity_clients() {
-        let state = test_state();
+        let room = test_room();
         let response = run_app_action_request(
-            &state,
+            &room,
             "spawn-object".to_string(),
             HashMap::from([
                 ("kind".to_string(), ActionValue::String("enemy".to_string())),
@@ -881,13 +1387,14 @@ mod tests {
 
         assert_eq!(response.snapshot.objects.len(), 1);
         assert_eq!(response.snapshot.objects[0].kind, "enemy");
-        assert_eq!(response.snapshot.objects[0].x, 2.0);
-        assert_eq!(response.snapshot.objects[0].y, 0.5);
-        assert_eq!(response.snapshot.objects[0].z, -3.0);
+        assert_eq!(
+            response.snapshot.objects[0].transform,
+            WorldTransform::new(2.0, 0.5, -3.0)
+        );
 
-        let mut receiver = state.events.subscribe();
+        let mut receiver = room.events.subscribe();
         let events = run_app_action_request(
-            &state,
+            &room,
             "spawn-object".to_string(),
             HashMap::from([
                 (
@@ -917,25 +1424,368 @@ mod tests {
         assert!(saw_state, "expected state broadcast after spawn action");
     }
 
+    #[test]
+    fn paused_host_holds_inputs_until_stepped() {
+        let room = test_room();
+        let pause = ClientOscMessage {
+            address: "/admin/sim/pause".to_string(),
+            args: Vec::new(),
+        };
+        handle_client_osc(&room, pause).unwrap();
+
+        let request = ClientOscMessage {
+            address: "/input/move".to_string(),
+            args: vec![
+                JsonOscArg::Str("player:local".to_string()),
+                JsonOscArg::Float(1.0),
+                JsonOscArg::Float(0.0),
+            ],
+        };
+        let events = run_runtime_osc_request(&room, request.to_osc_message()).unwrap();
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, ServerEvent::Osc { .. })));
+        assert!(tick_room(&room, one_frame(&room) * 10).unwrap().is_empty());
+        let paused = snapshot(&room).unwrap();
+        assert_eq!(paused.tick, 0);
+        assert!(paused.objects.is_empty());
+
+        let response = run_app_action_request(
+            &room,
+            "step-simulation".to_string(),
+            HashMap::from([("ticks".to_string(), ActionValue::Int(1))]),
+        )
+        .unwrap();
+        assert_eq!(response.snapshot.tick, 1);
+        assert_eq!(response.snapshot.objects[0].id, "player:local");
+
+        let resume = ClientOscMessage {
+            address: "/admin/sim/resume".to_string(),
+            args: Vec::new(),
+        };
+        handle_client_osc(&room, resume).unwrap();
+        assert_eq!(snapshot(&room).unwrap().tick, 1);
+        run_runtime_osc_request(&room, request.to_osc_message()).unwrap();
+        tick_room(&room, one_frame(&room)).unwrap();
+        let resumed = snapshot(&room).unwrap();
+        assert_eq!(resumed.tick, 2);
+        assert_eq!(resumed.objects[0].transform.x, 2.0);
+    }
+
+    #[test]
+    fn restore_state_resets_the_scene_and_broadcasts_it() {
+        let room = test_room();
+        let scene = snapshot(&room).unwrap();
+        run_app_action_request(
+            &room,
+            "spawn-object".to_string(),
+            HashMap::from([("kind".to_string(), ActionValue::String("enemy".to_string()))]),
+        )
+        .unwrap();
+
+        let mut receiver = room.events.subscribe();
+        let restored = restore_state_request(&room, scene.clone()).unwrap();
+
+        assert_eq!(restored, scene);
+        assert_eq!(snapshot(&room).unwrap(), scene);
+        let broadcast =
+            std::iter::from_fn(|| receiver.try_recv().ok()).find_map(|event| match event {
+                ServerEvent::State { snapshot } => Some(snapshot),
+                _ => None,
+            });
+        assert_eq!(broadcast, Some(scene.clone()));
+
+        let mut invalid = scene;
+        invalid.schema_version = 0;
+        assert!(restore_state_request(&room, invalid).is_err());
+    }
+
+    #[test]
+    fn delta_clients_receive_a_full_state_then_deltas_keyed_by_tick() {
+        let room = test_room();
+        let mut full = StateStream::new(WsStateMode::Full, InterestFilter::default());
+        let mut delta = StateStream::new(WsStateMode::Delta, InterestFilter::default());
+        let initial = snapshot(&room).unwrap();
+        assert!(matches!(
+            delta.outgoing(ServerEvent::State {
+                snapshot: initial.clone()
+            }),
+            ServerEvent::State { .. }
+        ));
+        assert!(tick_room(&room, one_frame(&room)).unwrap().is_empty());
+
+        let mut receiver = room.events.subscribe();
+        let response = run_app_action_request(
+            &room,
+            "spawn-object".to_string(),
+            HashMap::from([("kind".to_string(), ActionValue::String("enemy".to_string()))]),
+        )
+        .unwrap();
+        let broadcast = std::iter::from_fn(|| receiver.try_recv().ok())
+            .find(|event| matches!(event, ServerEvent::State { .. }))
+            .expect("expected state broadcast");
+
+        assert!(matches!(
+            full.outgoing(broadcast.clone()),
+            ServerEvent::State { .. }
+        ));
+        let ServerEvent::Delta { delta: world_delta } = delta.outgoing(broadcast.clone()) else {
+            panic!("expected delta event");
+        };
+        assert_eq!((world_delta.base_tick, world_delta.tick), (0, 1));
+        assert_eq!(world_delta.spawned.len(), 1);
+        assert_eq!(
+            initial.apply_delta(&world_delta).unwrap(),
+            response.snapshot
+        );
+
+        let json = serde_json::to_value(delta.outgoing(broadcast.clone())).unwrap();
+        assert_eq!(json["type"], "delta");
+        assert_eq!(json["delta"]["base_tick"], 1);
+        delta.reset();
+        assert!(matches!(
+            delta.outgoing(broadcast),
+            ServerEvent::State { .. }
+        ));
+    }
+
+    #[test]
+    fn admin_websocket_despawn_removes_one_object() {
+        let room = test_room();
+        for kind in ["enemy", "treasure"] {
+            let mut spawn = OscMessage::new("/admin/world/spawn");
+            spawn.push_arg(OscArg::Str(kind.to_string()));
+            handle_client_osc_message(&room, spawn).unwrap();
+        }
+        let enemy_id = snapshot(&room).unwrap().objects[0].id.clone();
+
+        let mut despawn = OscMessage::new("/admin/world/despawn");
+        despawn.push_arg(OscArg::Str(enemy_id.clone()));
+        handle_client_osc_message(&room, despawn).unwrap();
+
+        let objects = snapshot(&room).unwrap().objects;
+        assert_eq!(objects.len(), 1);
+        assert_eq!(objects[0].kind, "treasure");
+        assert!(action_request_from_osc_message(&OscMessage::new("/admin/world/despawn")).is_err());
+    }
+
     #[test]
     fn admin_websocket_accepts_project_action_osc() {
-        let state = test_state();
-        let mut receiver = state.events.subscribe();
+        let room = test_room();
+        let mut receiver = room.events.subscribe();
         let mut message = OscMessage::new("/game/enemy/spawn");
         message.push_arg(OscArg::Str("slime".to_string()));
         message.push_arg(OscArg::Float(1.0));
         message.push_arg(OscArg::Float(2.0));
 
-        handle_client_osc_message(&state, message).unwrap();
+        handle_client_osc_message(&room, message).unwrap();
 
-        let mut saw_state = false;
+        let mut saw_log = false;
         while let Ok(event) = receiver.try_recv() {
-            if let ServerEvent::State { .. } = event {
-                saw_state = true;
+            if let ServerEvent::Log { entry } = event {
+                saw_log |= entry.osc_address.as_deref() == Some("/game/enemy/spawn");
             }
         }
 
-        assert!(saw_state, "expected state broadcast after project OSC");
+        assert!(
+            saw_log,
+            "expected the project OSC to be logged as runtime input"
+        );
+    }
+
+    #[tokio::test]
+    async fn rooms_run_independent_runtimes_and_logs() {
+        let rooms = RoomManager::new().unwrap();
+        let arena = rooms.create(Some("arena".to_string())).unwrap();
+        let generated = rooms.create(None).unwrap();
+        assert_eq!(&*generated.id, "room-1");
+
+        let mut spawn = OscMessage::new("/admin/world/spawn");
+        spawn.push_arg(OscArg::Str("enemy".to_string()));
+        handle_client_osc_message(&arena, spawn).unwrap();
+        tick_room(&arena, one_frame(&arena)).unwrap();
+
+        let default = rooms.get(DEFAULT_ROOM).unwrap().unwrap();
+        assert_eq!(snapshot(&arena).unwrap().objects.len(), 1);
+        assert!(snapshot(&default).unwrap().objects.is_empty());
+        assert!(default.inner.lock().unwrap().logs.is_empty());
+        assert_eq!(
+            rooms.list().unwrap(),
+            vec![
+                RoomSummary {
+                    id: "arena".to_string(),
+                    tick: 1,
+                    objects: 1,
+                    subscribers: 0,
+                },
+                RoomSummary {
+                    id: DEFAULT_ROOM.to_string(),
+                    tick: 0,
+                    objects: 0,
+                    subscribers: 0,
+                },
+                RoomSummary {
+                    id: "room-1".to_string(),
+                    tick: 0,
+                    objects: 0,
+                    subscribers: 0,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn destroying_a_room_closes_its_subscribers() {
+        let rooms = RoomManager::new().unwrap();
+        let arena = rooms.create(Some("arena".to_string())).unwrap();
+        let mut receiver = arena.events.subscribe();
+
+        rooms.destroy("arena").unwrap();
+
+        assert!(rooms.get("arena").unwrap().is_none());
+        assert!(arena.ticker.lock().unwrap().is_none());
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(ServerEvent::RoomClosed { room }) if room == "arena"
+        ));
+        let missing = rooms.destroy("arena").unwrap_err();
+        assert_eq!(missing.status, StatusCode::NOT_FOUND);
+        let default = rooms.destroy(DEFAULT_ROOM).unwrap_err();
+        assert_eq!(default.status, StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn websocket_clients_receive_only_outputs_they_are_interested_in() {
+        let room = test_room();
+        let params = |query: &str| -> WsParams {
+            serde_json::from_value(serde_json::json!(query
+                .split('&')
+                .filter_map(|pair| pair.split_once('='))
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect::<BTreeMap<_, _>>()))
+            .unwrap()
+        };
+        assert!(params("focus=player:local").interest().is_err());
+        assert_eq!(
+            params("prefixes=/render/, /game/&kinds=player")
+                .interest()
+                .unwrap(),
+            InterestFilter::default()
+                .with_address_prefix("/render/")
+                .with_address_prefix("/game/")
+                .with_kind("player")
+        );
+        assert!(room
+            .join(InterestFilter::default().within_radius("player:local", f32::NAN))
+            .is_err());
+
+        let everyone = room.join(InterestFilter::default()).unwrap();
+        let local = room
+            .join(InterestFilter::default().within_radius("player:local", 2.0))
+            .unwrap();
+        let npcs = room
+            .join(InterestFilter::default().with_kind("npc"))
+            .unwrap();
+
+        let render_events = |id: &str, x: f32| -> Vec<ServerEvent> {
+            let request = ClientOscMessage {
+                address: "/input/move".to_string(),
+                args: vec![
+                    JsonOscArg::Str(id.to_string()),
+                    JsonOscArg::Float(x),
+                    JsonOscArg::Float(0.0),
+                ],
+            };
+            run_runtime_osc_request(&room, request.to_osc_message()).unwrap();
+            tick_room(&room, one_frame(&room))
+                .unwrap()
+                .into_iter()
+                .filter(|event| matches!(event, ServerEvent::Osc { .. }))
+                .collect()
+        };
+        let near = render_events("player:local", 1.0);
+        assert_eq!(near.len(), 1);
+        assert!(everyone.sees(&near[0]) && local.sees(&near[0]));
+        assert!(!npcs.sees(&near[0]));
+
+        let far = render_events("player:remote", 10.0);
+        assert_eq!(far.len(), 1);
+        assert!(everyone.sees(&far[0]));
+        assert!(!local.sees(&far[0]));
+
+        let log = ServerEvent::Error {
+            message: "not routed".to_string(),
+        };
+        assert!(npcs.sees(&log));
+
+        let state = ServerEvent::State {
+            snapshot: snapshot(&room).unwrap(),
+        };
+        let local_filter = InterestFilter::default().within_radius("player:local", 2.0);
+        let ServerEvent::State { snapshot: visible } =
+            StateStream::new(WsStateMode::Full, local_filter.clone()).outgoing(state.clone())
+        else {
+            panic!("expected state event");
+        };
+        let ids: Vec<&str> = visible
+            .objects
+            .iter()
+            .map(|object| object.id.as_str())
+            .collect();
+        assert_eq!(ids, ["player:local"]);
+        let mut deltas = StateStream::new(WsStateMode::Delta, local_filter);
+        deltas.outgoing(state);
+        render_events("player:remote", 10.0);
+        let ServerEvent::Delta { delta } = deltas.outgoing(ServerEvent::State {
+            snapshot: snapshot(&room).unwrap(),
+        }) else {
+            panic!("expected delta event");
+        };
+        assert!(delta.spawned.is_empty() && delta.changed.is_empty());
+
+        let id = local.id.clone();
+        drop(local);
+        let guard = room.inner.lock().unwrap();
+        assert!(guard.runtime.client_interest(&id).is_none());
+        assert!(guard.runtime.client_interest(&everyone.id).is_some());
+    }
+
+    #[tokio::test]
+    async fn room_ids_are_validated_and_unique() {
+        let rooms = RoomManager::new().unwrap();
+        rooms.create(Some("match_1".to_string())).unwrap();
+
+        let duplicate = rooms
+            .create(Some("match_1".to_string()))
+            .map(|_| ())
+            .unwrap_err();
+        assert_eq!(duplicate.status, StatusCode::CONFLICT);
+        for invalid in ["", "has space", "a/b", &"x".repeat(MAX_ROOM_ID_LEN + 1)] {
+            let error = rooms
+                .create(Some(invalid.to_string()))
+                .map(|_| ())
+                .unwrap_err();
+            assert_eq!(error.status, StatusCode::BAD_REQUEST, "{invalid:?}");
+        }
+
+        rooms.create(Some("room-1".to_string())).unwrap();
+        assert_eq!(&*rooms.create(None).unwrap().id, "room-2");
+    }
+
+    #[tokio::test]
+    async fn idle_rooms_tick_on_their_own_until_destroyed() {
+        let rooms = RoomManager::new().unwrap();
+        let arena = rooms.create(Some("arena".to_string())).unwrap();
+        let tick = |room: &Room| room.inner.lock().unwrap().runtime.current_tick().get();
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(tick(&arena) > 0);
+        assert!(tick(&rooms.get(DEFAULT_ROOM).unwrap().unwrap()) > 0);
+
+        rooms.destroy("arena").unwrap();
+        let stopped_at = tick(&arena);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(tick(&arena), stopped_at);
     }
 
     #[test]
@@ -4,8 +4,12 @@
 //! using the framework crates, not another reusable framework component.
 
 use anyhow::{Context, Result};
+use kitu_core::{KituError, Tick};
+use kitu_ecs::{EcsWorld, EventCursor, System};
+use kitu_osc_ir::{OscArg, OscMessage};
 use kitu_runtime::{build_runtime, Runtime};
 use kitu_transport::LocalChannel;
+use serde::{Deserialize, Serialize};
 
 /// Stable app identifier used in project-scoped app actions.
 pub const APP_ID: &str = "demo-game";
@@ -16,15 +20,97 @@ pub const APP_ACTIONS_TOML: &str = include_str!("../kitu-app-actions.toml");
 /// Runtime type used by the demo game host and scenario tests.
 pub type DemoRuntime = Runtime<LocalChannel>;
 
+/// Height a grounded object reaches on `/input/jump`.
+pub const JUMP_HEIGHT: f32 = 1.0;
+
+/// Distance a jumping object falls back towards the ground (`y = 0`) each tick.
+pub const FALL_PER_TICK: f32 = 0.25;
+
+/// Snapshot component key marking objects that are in the air after a jump.
+pub const AIRBORNE_KEY: &str = "demo.airborne";
+
+/// Parsed `/input/jump [entity_id]` input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JumpInput {
+    /// World object that jumps.
+    pub entity_id: String,
+}
+
 /// Builds a demo-game runtime from Kitu framework crates.
 pub fn build_demo_runtime() -> Result<DemoRuntime> {
     let mut runtime = build_runtime(LocalChannel::connected());
     runtime
         .load_project_app_actions_from_toml(APP_ID, APP_ACTIONS_TOML)
         .context("load demo-game app actions")?;
+    runtime
+        .register_input_handler("/input/jump", parse_jump_input)
+        .context("register /input/jump handler")?;
+    runtime
+        .world_mut()
+        .register_serializable_component::<Airborne>(AIRBORNE_KEY)
+        .context("register airborne component")?;
+    runtime
+        .world_mut()
+        .schedule_system(JumpSystem::default())
+        .context("schedule jump system")?;
     Ok(runtime)
 }
 
+fn parse_jump_input(message: &OscMessage) -> kitu_core::Result<JumpInput> {
+    match message.args.as_slice() {
+        [OscArg::Str(entity_id)] if !entity_id.is_empty() => Ok(JumpInput {
+            entity_id: entity_id.clone(),
+        }),
+        _ => Err(KituError::InvalidInput("/input/jump expects [entity_id]")),
+    }
+}
+
+/// Marks an object in the air; registered so snapshots, state hashes and rollback see it.
+#[derive(Debug, Serialize, Deserialize)]
+struct Airborne;
+
+/// Lifts grounded objects on [`JumpInput`] and lets them fall back over the following ticks.
+#[derive(Default)]
+struct JumpSystem {
+    jumps: EventCursor<JumpInput>,
+}
+
+impl System for JumpSystem {
+    fn run(&mut self, world: &mut EcsWorld, _tick: Tick) -> kitu_core::Result<()> {
+        for entity in world.query_entities::<(Airborne,)>() {
+            let Some(object) = world.world_object_for_entity(entity) else {
+                continue;
+            };
+            let mut transform = object.transform;
+            transform.y = (transform.y - FALL_PER_TICK).max(0.0);
+            world.move_world_object(&object.id, transform)?;
+            if transform.y <= 0.0 {
+                world.remove_component::<Airborne>(entity);
+            }
+        }
+
+        let jumpers: Vec<String> = world
+            .read_events(&mut self.jumps)
+            .into_iter()
+            .map(|jump| jump.entity_id.clone())
+            .collect();
+        for entity_id in jumpers {
+            // Unknown objects and objects already in the air ignore the jump.
+            let Some(object) = world.world_object(&entity_id) else {
+                continue;
+            };
+            if world.get_component::<Airborne>(object.entity).is_some() {
+                continue;
+            }
+            let mut transform = object.transform;
+            transform.y += JUMP_HEIGHT;
+            world.move_world_object(&entity_id, transform)?;
+            world.insert_component(object.entity, Airborne)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -39,4 +125,104 @@ mod tests {
         assert!(catalog.action("player.godmode").is_some());
         assert!(catalog.action("map.fast-travel").is_some());
     }
+
+    #[test]
+    fn jump_input_lifts_the_player_and_gravity_brings_it_back() {
+        let mut runtime = build_demo_runtime().unwrap();
+        let mut spawn = OscMessage::new("/input/move");
+        spawn.push_arg(OscArg::Str("player:local".to_string()));
+        spawn.push_arg(OscArg::Float(0.0));
+        spawn.push_arg(OscArg::Float(0.0));
+        let mut jump = OscMessage::new("/input/jump");
+        jump.push_arg(OscArg::Str("player:local".to_string()));
+
+        let mut bundle = kitu_osc_ir::OscBundle::new();
+        bundle.push(spawn);
+        runtime.enqueue_input(bundle);
+        runtime.tick_once().unwrap();
+        let mut bundle = kitu_osc_ir::OscBundle::new();
+        bundle.push(jump);
+        runtime.enqueue_input(bundle);
+        runtime.tick_once().unwrap();
+
+        let height = |runtime: &DemoRuntime| {
+            runtime.inspect_world_state().unwrap().objects[0]
+                .transform
+                .y
+        };
+        assert_eq!(height(&runtime), JUMP_HEIGHT);
+        runtime.run_for_ticks(2).unwrap();
+        assert_eq!(height(&runtime), JUMP_HEIGHT - 2.0 * FALL_PER_TICK);
+        runtime.run_for_ticks(10).unwrap();
+        assert_eq!(height(&runtime), 0.0);
+
+        let mut invalid = kitu_osc_ir::OscBundle::new();
+        invalid.push(OscMessage::new("/input/jump"));
+        runtime.enqueue_input(invalid);
+        assert!(runtime.tick_once().is_err());
+    }
+
+    #[test]
+    fn rollback_across_a_jump_matches_the_on_time_run() {
+        let bundle = |message: OscMessage| {
+            let mut bundle = kitu_osc_ir::OscBundle::new();
+            bundle.push(message);
+            bundle
+        };
+        let step = |dx: f32| {
+            let mut message = OscMessage::new("/input/move");
+            message.push_arg(OscArg::Str("player:local".to_string()));
+            message.push_arg(OscArg::Float(dx));
+            message.push_arg(OscArg::Float(0.0));
+            bundle(message)
+        };
+        let mut jump = OscMessage::new("/input/jump");
+        jump.push_arg(OscArg::Str("player:local".to_string()));
+        let jump = bundle(jump);
+
+        // Jumps at tick 1 and lands at tick 5; the step at tick 2 happens mid-air.
+        let run = |late: bool| {
+            let mut runtime = build_demo_runtime().unwrap();
+            runtime.set_rollback_window(8);
+            runtime.enqueue_input(step(0.0));
+            runtime.tick_once().unwrap();
+            runtime.enqueue_input(jump.clone());
+            runtime.tick_once().unwrap();
+            if !late {
+                runtime.enqueue_input(step(1.0));
+            }
+            runtime.run_for_ticks(5).unwrap();
+            if late {
+                runtime
+                    .enqueue_input_at(Tick::start().advance_by(2), step(1.0))
+                    .unwrap();
+            }
+            runtime
+        };
+
+        let on_time = run(false);
+        let landed = on_time.inspect_world_state().unwrap();
+        assert_eq!(landed.objects[0].transform.y, 0.0);
+        assert_eq!(run(true).inspect_world_state().unwrap(), landed);
+
+        let mut mid_air = build_demo_runtime().unwrap();
+        mid_air.enqueue_input(step(0.0));
+        mid_air.tick_once().unwrap();
+        mid_air.enqueue_input(jump.clone());
+        mid_air.tick_once().unwrap();
+        let snapshot = mid_air.inspect_world_state().unwrap();
+        let player = snapshot.objects[0].entity;
+        assert_eq!(
+            snapshot.components[AIRBORNE_KEY],
+            std::collections::BTreeMap::from([(player, serde_json::Value::Null)])
+        );
+
+        let mut restored = run(false);
+        restored.restore_world_state(&snapshot).unwrap();
+        restored.tick_once().unwrap();
+        let height = restored.inspect_world_state().unwrap().objects[0]
+            .transform
+            .y;
+        assert_eq!(height, JUMP_HEIGHT - FALL_PER_TICK);
+    }
 }
@@ -71,6 +71,7 @@ fn execute_steps(runtime: &mut DemoRuntime, steps: Vec<ScenarioStep>) -> Result<
         if action_id == "spawn-object" {
             last_object_id = runtime
                 .inspect_world_state()
+                .unwrap()
                 .objects
                 .last()
                 .map(|object| object.id.clone());
@@ -83,7 +84,7 @@ fn execute_steps(runtime: &mut DemoRuntime, steps: Vec<ScenarioStep>) -> Result<
 }
 
 fn assert_expected_state(runtime: &DemoRuntime, expected: &Expected) -> Result<()> {
-    let snapshot = runtime.inspect_world_state();
+    let snapshot = runtime.inspect_world_state().unwrap();
     if runtime.current_tick().get() != expected.expected_tick {
         bail!(
             "tick mismatch: expected {}, observed {}",
@@ -386,7 +386,12 @@ pub fn kitu_general_actions() -> Vec<AppActionDefinition> {
     vec![
         spawn_object_action(),
         move_object_action(),
+        despawn_object_action(),
         reset_world_action(),
+        pause_simulation_action(),
+        resume_simulation_action(),
+        step_simulation_action(),
+        set_time_scale_action(),
     ]
 }
 
@@ -857,6 +862,28 @@ fn move_object_action() -> AppActionDefinition {
     }
 }
 
+fn despawn_object_action() -> AppActionDefinition {
+    AppActionDefinition {
+        id: "despawn-object".to_string(),
+        scope: AppActionScope::KituGeneral,
+        label: "Despawn Object".to_string(),
+        description: Some("Remove one runtime world object.".to_string()),
+        cli: CliCommandSpec {
+            command: "world despawn".to_string(),
+        },
+        ui: UiActionSpec {
+            kind: UiActionKind::Form,
+            submit_label: "Despawn".to_string(),
+            destructive: true,
+        },
+        inputs: vec![string_input("id", "Object", true, None)],
+        output: OscTemplate {
+            address: "/admin/world/despawn".to_string(),
+            args: vec![input_arg("id")],
+        },
+    }
+}
+
 fn reset_world_action() -> AppActionDefinition {
     AppActionDefinition {
         id: "reset-world".to_string(),
@@ -879,6 +906,96 @@ fn reset_world_action() -> AppActionDefinition {
     }
 }
 
+fn pause_simulation_action() -> AppActionDefinition {
+    AppActionDefinition {
+        id: "pause-simulation".to_string(),
+        scope: AppActionScope::KituGeneral,
+        label: "Pause Simulation".to_string(),
+        description: Some("Stop advancing runtime ticks from elapsed time.".to_string()),
+        cli: CliCommandSpec {
+            command: "sim pause".to_string(),
+        },
+        ui: UiActionSpec {
+            kind: UiActionKind::Button,
+            submit_label: "Pause".to_string(),
+            destructive: false,
+        },
+        inputs: Vec::new(),
+        output: OscTemplate {
+            address: "/admin/sim/pause".to_string(),
+            args: Vec::new(),
+        },
+    }
+}
+
+fn resume_simulation_action() -> AppActionDefinition {
+    AppActionDefinition {
+        id: "resume-simulation".to_string(),
+        scope: AppActionScope::KituGeneral,
+        label: "Resume Simulation".to_string(),
+        description: Some("Advance runtime ticks from elapsed time again.".to_string()),
+        cli: CliCommandSpec {
+            command: "sim resume".to_string(),
+        },
+        ui: UiActionSpec {
+            kind: UiActionKind::Button,
+            submit_label: "Resume".to_string(),
+            destructive: false,
+        },
+        inputs: Vec::new(),
+        output: OscTemplate {
+            address: "/admin/sim/resume".to_string(),
+            args: Vec::new(),
+        },
+    }
+}
+
+fn step_simulation_action() -> AppActionDefinition {
+    AppActionDefinition {
+        id: "step-simulation".to_string(),
+        scope: AppActionScope::KituGeneral,
+        label: "Step Simulation".to_string(),
+        description: Some(
+            "Run an exact number of runtime ticks, usually while paused.".to_string(),
+        ),
+        cli: CliCommandSpec {
+            command: "sim step".to_string(),
+        },
+        ui: UiActionSpec {
+            kind: UiActionKind::Form,
+            submit_label: "Step".to_string(),
+            destructive: false,
+        },
+        inputs: vec![int_input("ticks", "Ticks", true, Some(1))],
+        output: OscTemplate {
+            address: "/admin/sim/step".to_string(),
+            args: vec![input_arg("ticks")],
+        },
+    }
+}
+
+fn set_time_scale_action() -> AppActionDefinition {
+    AppActionDefinition {
+        id: "set-time-scale".to_string(),
+        scope: AppActionScope::KituGeneral,
+        label: "Set Time Scale".to_string(),
+        description: Some("Scale elapsed time before it is converted into ticks.".to_string()),
+        cli: CliCommandSpec {
+            command: "sim time-scale".to_string(),
+        },
+        ui: UiActionSpec {
+            kind: UiActionKind::Form,
+            submit_label: "Apply".to_string(),
+            destructive: false,
+        },
+        inputs: vec![float_input("scale", "Scale", true, Some(1.0))],
+        output: OscTemplate {
+            address: "/admin/sim/time-scale".to_string(),
+            args: vec![input_arg("scale")],
+        },
+    }
+}
+
 fn string_input(name: &str, label: &str, required: bool, default: Option<&str>) -> ActionInputSpec {
     ActionInputSpec {
         name: name.to_string(),
@@ -899,6 +1016,16 @@ fn float_input(name: &str, label: &str, required: bool, default: Option<f32>) ->
     }
 }
 
+fn int_input(name: &str, label: &str, required: bool, default: Option<i32>) -> ActionInputSpec {
+    ActionInputSpec {
+        name: name.to_string(),
+        label: label.to_string(),
+        value_type: ActionInputType::Int,
+        required,
+        default: default.map(ActionValue::Int),
+    }
+}
+
 fn input_arg(name: &str) -> OscTemplateArg {
     OscTemplateArg::Input {
         name: name.to_string(),
@@ -935,6 +1062,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn built_in_despawn_materializes_osc_message() {
+        let catalog = kitu_general_catalog();
+        let action = catalog.action("despawn-object").unwrap();
+        assert!(action.ui.destructive);
+        assert_eq!(action.cli.command, "world despawn");
+
+        let message = catalog
+            .materialize_message(
+                "despawn-object",
+                &HashMap::from([("id".to_string(), ActionValue::String("0v0".to_string()))]),
+            )
+            .unwrap();
+
+        assert_eq!(message.address, "/admin/world/despawn");
+        assert_eq!(message.args, vec![OscArg::Str("0v0".to_string())]);
+        assert!(catalog
+            .materialize_message("despawn-object", &HashMap::new())
+            .is_err());
+    }
+
+    #[test]
+    fn built_in_simulation_controls_materialize_osc_messages() {
+        let catalog = kitu_general_catalog();
+        assert_eq!(
+            catalog
+                .materialize_message("pause-simulation", &HashMap::new())
+                .unwrap()
+                .address,
+            "/admin/sim/pause"
+        );
+
+        let step = catalog
+            .materialize_message("step-simulation", &HashMap::new())
+            .unwrap();
+        assert_eq!(step.address, "/admin/sim/step");
+        assert_eq!(step.args, vec![OscArg::Int(1)]);
+
+        let scale = catalog
+            .materialize_message(
+                "set-time-scale",
+                &HashMap::from([("scale".to_string(), ActionValue::Float(0.25))]),
+            )
+            .unwrap();
+        assert_eq!(scale.address, "/admin/sim/time-scale");
+        assert_eq!(scale.args, vec![OscArg::Float(0.25)]);
+    }
+
     #[test]
     fn project_manifest_loads_action_template() {
         let source = r#"
@@ -0,0 +1,411 @@
+//! Portable fixed-point numbers for simulation math.
+//!
+//! [`Fixed`] is a signed Q32.32 number: an `i64` counting steps of 2^-32. Every operation,
+//! including [`sqrt`](Fixed::sqrt), is made of integer additions, multiplications, divisions and
+//! shifts with a spelled-out rounding rule, so the Rust host, the wasm build and a port to another
+//! language compute bit-identical results. Conversions from and to `f32` are exact IEEE-754
+//! operations and therefore portable as well.
+//!
+//! Arithmetic wraps on overflow in debug and release builds alike; the representable range is
+//! about ±2.1 billion, far beyond any world coordinate.
+
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use serde::{Deserialize, Serialize};
+
+const FRAC_BITS: u32 = 32;
+/// 2^32 as a float, used to convert between the raw bits and `f32`/`f64`.
+const SCALE: f64 = 4_294_967_296.0;
+
+/// Signed Q32.32 fixed-point number.
+///
+/// Multiplication rounds toward negative infinity, division and [`from_ratio`](Self::from_ratio)
+/// toward zero, and [`sqrt`](Self::sqrt) down.
+///
+/// # Examples
+///
+/// ```
+/// use kitu_core::Fixed;
+///
+/// let speed = Fixed::from_int(6);
+/// let dt = Fixed::from_ratio(1, 60);
+/// let step = speed * dt;
+/// assert!((step.to_f32() - 0.1).abs() < 1e-6);
+///
+/// let hypot = (Fixed::from_int(9) + Fixed::from_int(16)).sqrt();
+/// assert_eq!(hypot, Fixed::from_int(5));
+/// ```
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Fixed(i64);
+
+impl Fixed {
+    /// Zero.
+    pub const ZERO: Self = Self(0);
+    /// One.
+    pub const ONE: Self = Self(1 << FRAC_BITS);
+    /// One half.
+    pub const HALF: Self = Self(1 << (FRAC_BITS - 1));
+    /// Smallest positive value, 2^-32.
+    pub const EPSILON: Self = Self(1);
+    /// Smallest representable value.
+    pub const MIN: Self = Self(i64::MIN);
+    /// Largest representable value.
+    pub const MAX: Self = Self(i64::MAX);
+
+    /// Creates a number from its raw Q32.32 representation.
+    pub const fn from_bits(bits: i64) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw Q32.32 representation.
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    /// Converts an integer exactly.
+    pub const fn from_int(value: i32) -> Self {
+        Self((value as i64) << FRAC_BITS)
+    }
+
+    /// Returns `numerator / denominator`, rounded toward zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    pub const fn from_ratio(numerator: i64, denominator: i64) -> Self {
+        Self((((numerator as i128) << FRAC_BITS) / denominator as i128) as i64)
+    }
+
+    /// Converts a float, truncating toward zero to the nearest step.
+    ///
+    /// Values outside the range saturate and NaN becomes zero.
+    pub fn from_f32(value: f32) -> Self {
+        // Scaling by a power of two is exact, and the saturating cast truncates.
+        Self((f64::from(value) * SCALE) as i64)
+    }
+
+    /// Converts to the nearest `f32`, ties to even.
+    pub fn to_f32(self) -> f32 {
+        // Dividing by a power of two is exact, so the integer conversion is the only rounding.
+        self.0 as f32 / SCALE as f32
+    }
+
+    /// Converts to the nearest `f64`, ties to even.
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / SCALE
+    }
+
+    /// Returns the absolute value.
+    pub const fn abs(self) -> Self {
+        Self(self.0.wrapping_abs())
+    }
+
+    /// Returns `true` if the number is below zero.
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Returns the square root rounded down, or zero for negative numbers.
+    pub fn sqrt(self) -> Self {
+        if self.0 <= 0 {
+            return Self::ZERO;
+        }
+        // sqrt(bits * 2^32) is the Q32.32 root of the value; it always fits in 48 bits.
+        Self(((self.0 as u128) << FRAC_BITS).isqrt() as i64)
+    }
+}
+
+impl From<i32> for Fixed {
+    fn from(value: i32) -> Self {
+        Self::from_int(value)
+    }
+}
+
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
+
+impl Add for Fixed {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0.wrapping_add(other.0))
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0.wrapping_sub(other.0))
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self(((i128::from(self.0) * i128::from(other.0)) >> FRAC_BITS) as i64)
+    }
+}
+
+impl Div for Fixed {
+    type Output = Self;
+
+    /// Divides, rounding toward zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    fn div(self, other: Self) -> Self {
+        Self(((i128::from(self.0) << FRAC_BITS) / i128::from(other.0)) as i64)
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+}
+
+impl AddAssign for Fixed {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Fixed {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Fixed {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for Fixed {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+/// Three-component vector of [`Fixed`] numbers.
+///
+/// # Examples
+///
+/// ```
+/// use kitu_core::{Fixed, FixedVec3};
+///
+/// let velocity = FixedVec3::new(Fixed::from_int(3), Fixed::ZERO, Fixed::from_int(4));
+/// assert_eq!(velocity.length(), Fixed::from_int(5));
+///
+/// let clamped = velocity.clamp_length(Fixed::ONE);
+/// assert_eq!(clamped.x, Fixed::from_ratio(3, 5));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FixedVec3 {
+    /// X component.
+    pub x: Fixed,
+    /// Y component.
+    pub y: Fixed,
+    /// Z component.
+    pub z: Fixed,
+}
+
+impl FixedVec3 {
+    /// Zero vector.
+    pub const ZERO: Self = Self::new(Fixed::ZERO, Fixed::ZERO, Fixed::ZERO);
+
+    /// Creates a vector from components.
+    pub const fn new(x: Fixed, y: Fixed, z: Fixed) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Converts float components with [`Fixed::from_f32`].
+    pub fn from_f32(x: f32, y: f32, z: f32) -> Self {
+        Self::new(Fixed::from_f32(x), Fixed::from_f32(y), Fixed::from_f32(z))
+    }
+
+    /// Converts the components with [`Fixed::to_f32`].
+    pub fn to_f32(self) -> [f32; 3] {
+        [self.x.to_f32(), self.y.to_f32(), self.z.to_f32()]
+    }
+
+    /// Returns the dot product.
+    pub fn dot(self, other: Self) -> Fixed {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns the length rounded down.
+    ///
+    /// The squares are summed at full precision, so the result is exact to the last step and
+    /// only overflows when the length itself is not representable.
+    pub fn length(self) -> Fixed {
+        let square = |value: Fixed| u128::from(value.0.unsigned_abs()).pow(2);
+        // The Q64.64 sum of squares has a Q32.32 root.
+        let sum = square(self.x) + square(self.y) + square(self.z);
+        Fixed(sum.isqrt() as i64)
+    }
+
+    /// Returns the vector scaled to unit length, or zero for a zero vector.
+    pub fn normalize_or_zero(self) -> Self {
+        let length = self.length();
+        if length == Fixed::ZERO {
+            return Self::ZERO;
+        }
+        self / length
+    }
+
+    /// Returns the vector shortened to `max` if it is longer.
+    pub fn clamp_length(self, max: Fixed) -> Self {
+        let length = self.length();
+        if length > max {
+            self * (max / length)
+        } else {
+            self
+        }
+    }
+}
+
+impl Add for FixedVec3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for FixedVec3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Neg for FixedVec3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<Fixed> for FixedVec3 {
+    type Output = Self;
+
+    fn mul(self, factor: Fixed) -> Self {
+        Self::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl Div<Fixed> for FixedVec3 {
+    type Output = Self;
+
+    fn div(self, divisor: Fixed) -> Self {
+        Self::new(self.x / divisor, self.y / divisor, self.z / divisor)
+    }
+}
+
+impl AddAssign for FixedVec3 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for FixedVec3 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: Fixed, expected: f64) {
+        let error = (actual.to_f64() - expected).abs();
+        assert!(error < 1e-8, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn arithmetic_rounds_as_documented() {
+        let third = Fixed::from_ratio(1, 3);
+        assert_eq!(third.to_bits(), 0x5555_5555);
+        assert_eq!(Fixed::from_ratio(-1, 3).to_bits(), -0x5555_5555);
+        assert_eq!(Fixed::ONE / Fixed::from_int(3), third);
+
+        // Products floor, so tiny negative products round away from zero.
+        assert_eq!(Fixed::EPSILON * Fixed::HALF, Fixed::ZERO);
+        assert_eq!(-Fixed::EPSILON * Fixed::HALF, -Fixed::EPSILON);
+
+        assert_eq!(Fixed::from_int(7) - Fixed::from_int(9), Fixed::from_int(-2));
+        assert_eq!(Fixed::MAX + Fixed::EPSILON, Fixed::MIN);
+        assert_eq!(Fixed::from_int(2).sqrt().to_bits(), 0x1_6a09_e667);
+        assert_eq!(Fixed::from_int(-4).sqrt(), Fixed::ZERO);
+    }
+
+    #[test]
+    fn float_conversions_are_exact_where_possible() {
+        assert_eq!(Fixed::from_f32(1.5), Fixed::ONE + Fixed::HALF);
+        assert_eq!(Fixed::from_f32(-0.25).to_f32(), -0.25);
+        assert_eq!(Fixed::from_f32(-0.0), Fixed::ZERO);
+        assert_eq!(Fixed::from_f32(f32::NAN), Fixed::ZERO);
+        assert_eq!(Fixed::from_f32(f32::INFINITY), Fixed::MAX);
+        assert_eq!(Fixed::from_f32(1e-12), Fixed::ZERO);
+        assert_eq!(Fixed::from_f32(0.1).to_f32(), 0.1);
+    }
+
+    #[test]
+    fn results_are_pinned_across_platforms() {
+        let value = Fixed::from_ratio(7, 10);
+        assert_eq!(value.to_bits(), 3_006_477_107);
+        assert_eq!((value * value).to_bits(), 2_104_533_974);
+        assert_eq!((Fixed::ONE / value).to_bits(), 6_135_667_566);
+        assert_eq!(value.sqrt().to_bits(), 3_593_427_451);
+        let vector = FixedVec3::new(value, Fixed::ZERO, Fixed::ONE);
+        assert_eq!(vector.length().to_bits(), 5_242_675_716);
+    }
+
+    #[test]
+    fn vectors_measure_and_clamp_without_overflow() {
+        let half_max = Fixed::from_bits(i64::MAX / 2);
+        let far = FixedVec3::new(half_max, half_max, half_max);
+        let expected = half_max.to_f64() * 3.0_f64.sqrt();
+        assert!((far.length().to_f64() / expected - 1.0).abs() < 1e-12);
+
+        let diagonal = FixedVec3::from_f32(1.0, 0.0, 1.0);
+        let unit = diagonal.normalize_or_zero();
+        assert_close(unit.x, std::f64::consts::FRAC_1_SQRT_2);
+        assert_close(unit.length(), 1.0);
+        assert_eq!(FixedVec3::ZERO.normalize_or_zero(), FixedVec3::ZERO);
+        assert_eq!(diagonal.clamp_length(Fixed::from_int(2)), diagonal);
+        assert_eq!(diagonal.dot(diagonal), Fixed::from_int(2));
+        assert_eq!((-diagonal).to_f32(), [-1.0, 0.0, -1.0]);
+    }
+
+    #[test]
+    fn values_round_trip_through_serde() {
+        let vector = FixedVec3::new(
+            Fixed::from_bits(13_493_037_705),
+            -Fixed::HALF,
+            Fixed::EPSILON,
+        );
+        let json = serde_json::to_string(&vector).unwrap();
+        assert_eq!(json, r#"{"x":13493037705,"y":-2147483648,"z":1}"#);
+        assert_eq!(serde_json::from_str::<FixedVec3>(&json).unwrap(), vector);
+    }
+}
@@ -3,6 +3,9 @@
 //! # Responsibilities
 //! - Provide the shared [`KituError`] type and [`Result`] alias used across workspace crates.
 //! - Define tick and timestamp helpers that keep scheduling consistent between runtime and tools.
+//! - Provide seeded, portable random streams ([`RngStreams`]) so gameplay randomness replays exactly.
+//! - Provide integer-only fixed-point math ([`Fixed`], [`FixedVec3`]) for bit-identical simulation
+//!   across the native host, wasm and Unity.
 //! - Host small, dependency-light utilities that other crates can import without pulling heavy stacks.
 //!
 //! # Integration
@@ -10,8 +13,13 @@
 //! and frontend bindings by centralizing foundational primitives. See `doc/crates-overview.md` for a
 //! workspace map and module responsibilities.
 
+mod fixed;
+mod rng;
+
 use std::time::Duration;
 
+pub use fixed::{Fixed, FixedVec3};
+pub use rng::{DeterministicRng, RngStreams};
 use thiserror::Error;
 
 /// Convenient result alias used across Kitu crates.
@@ -28,6 +36,14 @@ pub enum KituError {
     /// Represents invalid user or data input.
     #[error("invalid input: {0}")]
     InvalidInput(&'static str),
+
+    /// Represents an invalid system schedule, such as an unknown stage or an ordering cycle.
+    #[error("invalid schedule: {0}")]
+    InvalidSchedule(String),
+
+    /// Represents a failure to encode or decode data, such as a malformed snapshot.
+    #[error("serialization failed: {0}")]
+    Serialization(String),
 }
 
 /// Tick represents a deterministic, monotonic counter for the runtime loop.
@@ -105,7 +121,11 @@ mod tests {
     fn errors_display_meaningful_messages() {
         let not_impl = KituError::NotImplemented("feature".into()).to_string();
         let invalid = KituError::InvalidInput("bad").to_string();
+        let schedule = KituError::InvalidSchedule("unknown stage `late`".into()).to_string();
+        let serialization = KituError::Serialization("unexpected end of input".into()).to_string();
         assert!(not_impl.contains("feature"));
         assert!(invalid.contains("bad"));
+        assert!(schedule.contains("late"));
+        assert!(serialization.contains("end of input"));
     }
 }
@@ -0,0 +1,917 @@
+//! Collider shapes and per-tick contact detection.
+//!
+//! Entities take part in collision when they carry both a [`Collider`] and a [`WorldTransform`].
+//! Shapes are centred on the entity's world position and stay axis-aligned: rotation and scale
+//! are ignored. [`EcsWorld::detect_contacts`] runs a sort-and-sweep broad phase along the x axis
+//! and an exact narrow phase per shape pair; [`EcsWorld::resolve_contacts`] optionally pushes
+//! dynamic colliders out of static ones.
+//!
+//! Detection is deterministic: candidate pairs are visited in a fixed order, ties are broken by
+//! entity, and contacts come back sorted by entity pair. The `_fixed` variants read positions from a
+//! caller-owned map of [`FixedVec3`]s and run the broad phase, the narrow phase and the push-out in
+//! [`Fixed`] arithmetic instead of `f32`, so a port of the same steps to another language
+//! reproduces every contact bit for bit.
+
+use std::{collections::BTreeMap, ops::Neg};
+
+use kitu_core::{Fixed, FixedVec3, KituError, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{EcsWorld, Entity, Vec3, WorldTransform};
+
+/// Geometry of a [`Collider`], in world units around the entity's position.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ColliderShape {
+    /// Sphere; when every object stays on one plane it behaves as a circle.
+    Sphere {
+        /// Sphere radius.
+        radius: f32,
+    },
+    /// Axis-aligned box.
+    Aabb {
+        /// Half the box size along each axis.
+        half_extents: Vec3,
+    },
+    /// Capsule whose core segment runs along the world y axis.
+    Capsule {
+        /// Half the length of the core segment, excluding the caps.
+        half_height: f32,
+        /// Radius around the core segment.
+        radius: f32,
+    },
+}
+
+/// Collision shape attached to an entity.
+///
+/// # Examples
+///
+/// ```
+/// use kitu_ecs::{Collider, EcsWorld, Vec3, WorldTransform};
+///
+/// let mut world = EcsWorld::new();
+/// let wall = world.spawn_world_object("wall", WorldTransform::new(0.0, 0.0, 0.0)).unwrap();
+/// let ball = world.spawn_world_object("ball", WorldTransform::new(1.25, 0.0, 0.0)).unwrap();
+/// world.set_collider(wall.entity, Collider::aabb(Vec3::new(1.0, 1.0, 1.0)).into_static()).unwrap();
+/// world.set_collider(ball.entity, Collider::sphere(0.5)).unwrap();
+///
+/// let contacts = world.detect_contacts();
+/// assert_eq!(contacts.len(), 1);
+/// assert_eq!(contacts[0].normal, Vec3::new(1.0, 0.0, 0.0));
+///
+/// world.resolve_contacts(&contacts);
+/// assert!(world.detect_contacts().is_empty());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Collider {
+    /// Shape of the collider.
+    pub shape: ColliderShape,
+    /// Static colliders never move during [`EcsWorld::resolve_contacts`] and never collide with
+    /// each other.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_static: bool,
+}
+
+impl Collider {
+    /// Creates a dynamic sphere (or circle) collider.
+    pub const fn sphere(radius: f32) -> Self {
+        Self::dynamic(ColliderShape::Sphere { radius })
+    }
+
+    /// Creates a dynamic axis-aligned box collider.
+    pub const fn aabb(half_extents: Vec3) -> Self {
+        Self::dynamic(ColliderShape::Aabb { half_extents })
+    }
+
+    /// Creates a dynamic capsule collider standing along the y axis.
+    pub const fn capsule(half_height: f32, radius: f32) -> Self {
+        Self::dynamic(ColliderShape::Capsule {
+            half_height,
+            radius,
+        })
+    }
+
+    /// Marks the collider as static scenery.
+    pub const fn into_static(self) -> Self {
+        Self {
+            is_static: true,
+            ..self
+        }
+    }
+
+    const fn dynamic(shape: ColliderShape) -> Self {
+        Self {
+            shape,
+            is_static: false,
+        }
+    }
+
+    /// Checks that every dimension is finite and non-negative.
+    pub fn validate(&self) -> Result<()> {
+        let dimensions = match self.shape {
+            ColliderShape::Sphere { radius } => [radius, 0.0, 0.0],
+            ColliderShape::Aabb { half_extents } => {
+                [half_extents.x, half_extents.y, half_extents.z]
+            }
+            ColliderShape::Capsule {
+                half_height,
+                radius,
+            } => [half_height, radius, 0.0],
+        };
+        if dimensions
+            .iter()
+            .all(|value| value.is_finite() && *value >= 0.0)
+        {
+            Ok(())
+        } else {
+            Err(KituError::InvalidInput(
+                "collider dimensions must be finite and non-negative",
+            ))
+        }
+    }
+
+    /// Half size of the bounding box around the collider.
+    fn half_bounds(&self) -> Vec3 {
+        match self.shape {
+            ColliderShape::Sphere { radius } => Vec3::new(radius, radius, radius),
+            ColliderShape::Aabb { half_extents } => half_extents,
+            ColliderShape::Capsule {
+                half_height,
+                radius,
+            } => Vec3::new(radius, half_height + radius, radius),
+        }
+    }
+}
+
+/// Overlap between two colliders found by [`EcsWorld::detect_contacts`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contact {
+    /// Lower entity of the pair.
+    pub a: Entity,
+    /// Higher entity of the pair.
+    pub b: Entity,
+    /// Unit direction from `a` toward `b`; moving `b` along it by `depth` separates the pair.
+    pub normal: Vec3,
+    /// Penetration depth, always greater than zero.
+    pub depth: f32,
+}
+
+/// Overlap between two colliders found by [`EcsWorld::detect_contacts_fixed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedContact {
+    /// Lower entity of the pair.
+    pub a: Entity,
+    /// Higher entity of the pair.
+    pub b: Entity,
+    /// Unit direction from `a` toward `b`; moving `b` along it by `depth` separates the pair.
+    pub normal: FixedVec3,
+    /// Penetration depth, always greater than zero.
+    pub depth: Fixed,
+}
+
+impl From<FixedContact> for Contact {
+    /// Rounds the normal and depth to `f32`.
+    fn from(contact: FixedContact) -> Self {
+        Self {
+            a: contact.a,
+            b: contact.b,
+            normal: contact.normal.into(),
+            depth: contact.depth.to_f32(),
+        }
+    }
+}
+
+struct Body {
+    entity: Entity,
+    collider: Collider,
+    center: Vec3,
+    min: Vec3,
+    max: Vec3,
+}
+
+impl EcsWorld {
+    /// Attaches or replaces the collider of an entity after validating its dimensions.
+    pub fn set_collider(&mut self, entity: Entity, collider: Collider) -> Result<Option<Collider>> {
+        collider.validate()?;
+        self.insert_component(entity, collider)
+    }
+
+    /// Returns every overlapping collider pair, sorted by `(a, b)`.
+    ///
+    /// Pairs of two static colliders are skipped, and shapes that only touch are not reported.
+    pub fn detect_contacts(&self) -> Vec<Contact> {
+        self.collect_contacts(narrow_phase)
+    }
+
+    /// Like [`detect_contacts`](Self::detect_contacts), but entirely in fixed-point arithmetic.
+    ///
+    /// Each entity is placed at its entry in `positions`, or at its [`WorldTransform`]
+    /// translation converted with [`Fixed::from_f32`] if it has none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    ///
+    /// use kitu_core::{Fixed, FixedVec3};
+    /// use kitu_ecs::{Collider, EcsWorld, Vec3, WorldTransform};
+    ///
+    /// let mut world = EcsWorld::new();
+    /// let wall = world.spawn_world_object("wall", WorldTransform::new(0.0, 0.0, 0.0)).unwrap();
+    /// let ball = world.spawn_world_object("ball", WorldTransform::new(9.0, 0.0, 0.0)).unwrap();
+    /// world.set_collider(wall.entity, Collider::aabb(Vec3::ONE).into_static()).unwrap();
+    /// world.set_collider(ball.entity, Collider::sphere(0.5)).unwrap();
+    ///
+    /// // The authoritative position overrides the transform.
+    /// let start = FixedVec3::new(Fixed::from_ratio(5, 4), Fixed::ZERO, Fixed::ZERO);
+    /// let mut positions = BTreeMap::from([(ball.entity, start)]);
+    /// let contacts = world.detect_contacts_fixed(&positions);
+    /// assert_eq!(contacts[0].depth, Fixed::from_ratio(1, 4));
+    ///
+    /// assert_eq!(world.resolve_contacts_fixed(&contacts, &mut positions), 1);
+    /// assert_eq!(positions[&ball.entity].x, Fixed::from_ratio(3, 2));
+    /// assert_eq!(world.world_object_for_entity(ball.entity).unwrap().transform.x, 1.5);
+    /// ```
+    pub fn detect_contacts_fixed(
+        &self,
+        positions: &BTreeMap<Entity, FixedVec3>,
+    ) -> Vec<FixedContact> {
+        fixed_point::detect_contacts(self, positions)
+    }
+
+    fn collect_contacts(
+        &self,
+        narrow_phase: fn(&Body, &Body) -> Option<(Vec3, f32)>,
+    ) -> Vec<Contact> {
+        let mut bodies: Vec<Body> = self
+            .query::<(Collider, WorldTransform)>()
+            .map(|(entity, (collider, transform))| {
+                let center = transform.translation();
+                let half = collider.half_bounds();
+                Body {
+                    entity,
+                    collider: *collider,
+                    center,
+                    min: center - half,
+                    max: center + half,
+                }
+            })
+            .collect();
+        bodies.sort_by(|left, right| {
+            left.min
+                .x
+                .total_cmp(&right.min.x)
+                .then(left.entity.cmp(&right.entity))
+        });
+
+        let mut contacts = Vec::new();
+        for (index, first) in bodies.iter().enumerate() {
+            for second in &bodies[index + 1..] {
+                if second.min.x > first.max.x {
+                    break;
+                }
+                if first.collider.is_static && second.collider.is_static {
+                    continue;
+                }
+                let overlaps = first.min.y <= second.max.y
+                    && second.min.y <= first.max.y
+                    && first.min.z <= second.max.z
+                    && second.min.z <= first.max.z;
+                if !overlaps {
+                    continue;
+                }
+                let (a, b) = if first.entity < second.entity {
+                    (first, second)
+                } else {
+                    (second, first)
+                };
+                if let Some((normal, depth)) = narrow_phase(a, b) {
+                    contacts.push(Contact {
+                        a: a.entity,
+                        b: b.entity,
+                        normal,
+                        depth,
+                    });
+                }
+            }
+        }
+        contacts.sort_by_key(|contact| (contact.a, contact.b));
+        contacts
+    }
+
+    /// Pushes dynamic colliders out of the static colliders they overlap.
+    ///
+    /// Contacts are applied in order, each moving the dynamic entity by the full depth along the
+    /// contact normal. Pairs of two dynamic colliders are left alone. Moved entities inside a
+    /// hierarchy have their local transform rewritten, like
+    /// [`move_world_object`](Self::move_world_object). Returns how many contacts moved an entity.
+    pub fn resolve_contacts(&mut self, contacts: &[Contact]) -> usize {
+        let mut resolved = 0;
+        for contact in contacts {
+            let Some((entity, depth)) = self.pushed_entity(contact.a, contact.b, contact.depth)
+            else {
+                continue;
+            };
+            let Some(transform) = self.get_component::<WorldTransform>(entity).copied() else {
+                continue;
+            };
+            let position = transform.translation() + contact.normal * depth;
+            self.set_world_transform(entity, transform.with_translation(position));
+            resolved += 1;
+        }
+        resolved
+    }
+
+    /// Like [`resolve_contacts`](Self::resolve_contacts), but moves the entries of `positions`
+    /// in fixed-point arithmetic.
+    ///
+    /// Pushed entities without an entry start from their [`WorldTransform`] translation. Each
+    /// pushed entity's transform is then set to its new position rounded to `f32`.
+    pub fn resolve_contacts_fixed(
+        &mut self,
+        contacts: &[FixedContact],
+        positions: &mut BTreeMap<Entity, FixedVec3>,
+    ) -> usize {
+        let mut resolved = 0;
+        for contact in contacts {
+            let Some((entity, depth)) = self.pushed_entity(contact.a, contact.b, contact.depth)
+            else {
+                continue;
+            };
+            let Some(transform) = self.get_component::<WorldTransform>(entity).copied() else {
+                continue;
+            };
+            let position = positions
+                .get(&entity)
+                .copied()
+                .unwrap_or_else(|| transform.translation().into())
+                + contact.normal * depth;
+            positions.insert(entity, position);
+            self.set_world_transform(entity, transform.with_translation(position.into()));
+            resolved += 1;
+        }
+        resolved
+    }
+
+    /// Returns the dynamic entity of a static/dynamic pair and the signed depth to push it by
+    /// along the normal.
+    fn pushed_entity<D: Neg<Output = D>>(
+        &self,
+        a: Entity,
+        b: Entity,
+        depth: D,
+    ) -> Option<(Entity, D)> {
+        let is_static = |entity| {
+            self.get_component::<Collider>(entity)
+                .is_some_and(|collider| collider.is_static)
+        };
+        match (is_static(a), is_static(b)) {
+            (true, false) => Some((b, depth)),
+            (false, true) => Some((a, -depth)),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the contact normal from `a` toward `b` and the penetration depth, if they overlap.
+fn narrow_phase(a: &Body, b: &Body) -> Option<(Vec3, f32)> {
+    match (rounded_segment(a), rounded_segment(b)) {
+        (Some(first), Some(second)) => {
+            let (from, to) = closest_vertical_points(a.center, first.0, b.center, second.0);
+            sphere_sphere(from, first.1, to, second.1)
+        }
+        (Some(round), None) => {
+            round_box(a.center, round.0, round.1, b.min, b.max).map(|(normal, depth)| {
+                // `round_box` points from the box toward the round shape.
+                (normal * -1.0, depth)
+            })
+        }
+        (None, Some(round)) => round_box(b.center, round.0, round.1, a.min, a.max),
+        (None, None) => box_box(a, b),
+    }
+}
+
+/// Describes spheres and capsules as a vertical segment of the given half height plus a radius.
+fn rounded_segment(body: &Body) -> Option<(f32, f32)> {
+    match body.collider.shape {
+        ColliderShape::Sphere { radius } => Some((0.0, radius)),
+        ColliderShape::Capsule {
+            half_height,
+            radius,
+        } => Some((half_height, radius)),
+        ColliderShape::Aabb { .. } => None,
+    }
+}
+
+/// Closest points between two vertical segments centred on `a` and `b`.
+fn closest_vertical_points(a: Vec3, a_half: f32, b: Vec3, b_half: f32) -> (Vec3, Vec3) {
+    let (a_low, a_high) = (a.y - a_half, a.y + a_half);
+    let (b_low, b_high) = (b.y - b_half, b.y + b_half);
+    let (a_y, b_y) = if a_high < b_low {
+        (a_high, b_low)
+    } else if b_high < a_low {
+        (a_low, b_high)
+    } else {
+        let y = (a_low.max(b_low) + a_high.min(b_high)) * 0.5;
+        (y, y)
+    };
+    (Vec3::new(a.x, a_y, a.z), Vec3::new(b.x, b_y, b.z))
+}
+
+fn sphere_sphere(a: Vec3, a_radius: f32, b: Vec3, b_radius: f32) -> Option<(Vec3, f32)> {
+    let delta = b - a;
+    let distance = length(delta);
+    let depth = a_radius + b_radius - distance;
+    if depth <= 0.0 {
+        return None;
+    }
+    let normal = if distance > 0.0 {
+        delta * (1.0 / distance)
+    } else {
+        Vec3::new(1.0, 0.0, 0.0)
+    };
+    Some((normal, depth))
+}
+
+/// Tests a rounded segment against a box, returning the normal from the box toward the segment.
+fn round_box(
+    center: Vec3,
+    half_height: f32,
+    radius: f32,
+    min: Vec3,
+    max: Vec3,
+) -> Option<(Vec3, f32)> {
+    // The segment point nearest the box: inside the box's y range when they overlap.
+    let low = (center.y - half_height).max(min.y);
+    let high = (center.y + half_height).min(max.y);
+    let y = if low <= high {
+        center.y.clamp(low, high)
+    } else if center.y + half_height < min.y {
+        center.y + half_height
+    } else {
+        center.y - half_height
+    };
+    let point = Vec3::new(center.x, y, center.z);
+    let closest = Vec3::new(
+        point.x.clamp(min.x, max.x),
+        point.y.clamp(min.y, max.y),
+        point.z.clamp(min.z, max.z),
+    );
+    let delta = point - closest;
+    let distance = length(delta);
+    if distance > 0.0 {
+        let depth = radius - distance;
+        return (depth > 0.0).then(|| (delta * (1.0 / distance), depth));
+    }
+
+    // The segment point is inside the box: leave through the nearest face.
+    let faces = [
+        (point.x - min.x, Vec3::new(-1.0, 0.0, 0.0)),
+        (max.x - point.x, Vec3::new(1.0, 0.0, 0.0)),
+        (point.y - min.y, Vec3::new(0.0, -1.0, 0.0)),
+        (max.y - point.y, Vec3::new(0.0, 1.0, 0.0)),
+        (point.z - min.z, Vec3::new(0.0, 0.0, -1.0)),
+        (max.z - point.z, Vec3::new(0.0, 0.0, 1.0)),
+    ];
+    let (gap, normal) = faces
+        .into_iter()
+        .reduce(|best, face| if face.0 < best.0 { face } else { best })?;
+    Some((normal, gap + radius))
+}
+
+fn box_box(a: &Body, b: &Body) -> Option<(Vec3, f32)> {
+    let overlap =
+        |a_min: f32, a_max: f32, b_min: f32, b_max: f32| a_max.min(b_max) - a_min.max(b_min);
+    let sign = |a: f32, b: f32| if b < a { -1.0 } else { 1.0 };
+    let axes = [
+        (
+            overlap(a.min.x, a.max.x, b.min.x, b.max.x),
+            Vec3::new(sign(a.center.x, b.center.x), 0.0, 0.0),
+        ),
+        (
+            overlap(a.min.y, a.max.y, b.min.y, b.max.y),
+            Vec3::new(0.0, sign(a.center.y, b.center.y), 0.0),
+        ),
+        (
+            overlap(a.min.z, a.max.z, b.min.z, b.max.z),
+            Vec3::new(0.0, 0.0, sign(a.center.z, b.center.z)),
+        ),
+    ];
+    let (depth, normal) = axes
+        .into_iter()
+        .reduce(|best, axis| if axis.0 < best.0 { axis } else { best })?;
+    (depth > 0.0).then_some((normal, depth))
+}
+
+fn length(vector: Vec3) -> f32 {
+    (vector.x * vector.x + vector.y * vector.y + vector.z * vector.z).sqrt()
+}
+
+/// Detection above, step for step in [`Fixed`] arithmetic.
+mod fixed_point {
+    use std::collections::BTreeMap;
+
+    use kitu_core::{Fixed, FixedVec3};
+
+    use super::{Collider, ColliderShape, FixedContact};
+    use crate::{EcsWorld, Entity, WorldTransform};
+
+    /// A collider placed at its fixed-point position; `round` is the segment half height and
+    /// radius of spheres and capsules.
+    struct Shape {
+        entity: Entity,
+        is_static: bool,
+        center: FixedVec3,
+        min: FixedVec3,
+        max: FixedVec3,
+        round: Option<(Fixed, Fixed)>,
+    }
+
+    impl Shape {
+        fn new(entity: Entity, collider: &Collider, center: FixedVec3) -> Self {
+            let (half, round) = match collider.shape {
+                ColliderShape::Sphere { radius } => {
+                    let radius = Fixed::from_f32(radius);
+                    (
+                        FixedVec3::new(radius, radius, radius),
+                        Some((Fixed::ZERO, radius)),
+                    )
+                }
+                ColliderShape::Aabb { half_extents } => (half_extents.into(), None),
+                ColliderShape::Capsule {
+                    half_height,
+                    radius,
+                } => {
+                    let (half_height, radius) =
+                        (Fixed::from_f32(half_height), Fixed::from_f32(radius));
+                    (
+                        FixedVec3::new(radius, half_height + radius, radius),
+                        Some((half_height, radius)),
+                    )
+                }
+            };
+            Self {
+                entity,
+                is_static: collider.is_static,
+                center,
+                min: center - half,
+                max: center + half,
+                round,
+            }
+        }
+    }
+
+    pub(super) fn detect_contacts(
+        world: &EcsWorld,
+        positions: &BTreeMap<Entity, FixedVec3>,
+    ) -> Vec<FixedContact> {
+        let mut shapes: Vec<Shape> = world
+            .query::<(Collider, WorldTransform)>()
+            .map(|(entity, (collider, transform))| {
+                let center = positions
+                    .get(&entity)
+                    .copied()
+                    .unwrap_or_else(|| transform.translation().into());
+                Shape::new(entity, collider, center)
+            })
+            .collect();
+        shapes.sort_by_key(|shape| (shape.min.x, shape.entity));
+
+        let mut contacts = Vec::new();
+        for (index, first) in shapes.iter().enumerate() {
+            for second in &shapes[index + 1..] {
+                if second.min.x > first.max.x {
+                    break;
+                }
+                if first.is_static && second.is_static {
+                    continue;
+                }
+                let overlaps = first.min.y <= second.max.y
+                    && second.min.y <= first.max.y
+                    && first.min.z <= second.max.z
+                    && second.min.z <= first.max.z;
+                if !overlaps {
+                    continue;
+                }
+                let (a, b) = if first.entity < second.entity {
+                    (first, second)
+                } else {
+                    (second, first)
+                };
+                if let Some((normal, depth)) = narrow_phase(a, b) {
+                    contacts.push(FixedContact {
+                        a: a.entity,
+                        b: b.entity,
+                        normal,
+                        depth,
+                    });
+                }
+            }
+        }
+        contacts.sort_by_key(|contact| (contact.a, contact.b));
+        contacts
+    }
+
+    fn narrow_phase(a: &Shape, b: &Shape) -> Option<(FixedVec3, Fixed)> {
+        match (a.round, b.round) {
+            (Some(first), Some(second)) => {
+                let (from, to) = closest_vertical_points(a.center, first.0, b.center, second.0);
+                sphere_sphere(from, first.1, to, second.1)
+            }
+            (Some(round), None) => round_box(a.center, round.0, round.1, b.min, b.max)
+                .map(|(normal, depth)| (-normal, depth)),
+            (None, Some(round)) => round_box(b.center, round.0, round.1, a.min, a.max),
+            (None, None) => box_box(a, b),
+        }
+    }
+
+    fn axis(x: i32, y: i32, z: i32) -> FixedVec3 {
+        FixedVec3::new(Fixed::from_int(x), Fixed::from_int(y), Fixed::from_int(z))
+    }
+
+    fn closest_vertical_points(
+        a: FixedVec3,
+        a_half: Fixed,
+        b: FixedVec3,
+        b_half: Fixed,
+    ) -> (FixedVec3, FixedVec3) {
+        let (a_low, a_high) = (a.y - a_half, a.y + a_half);
+        let (b_low, b_high) = (b.y - b_half, b.y + b_half);
+        let (a_y, b_y) = if a_high < b_low {
+            (a_high, b_low)
+        } else if b_high < a_low {
+            (a_low, b_high)
+        } else {
+            let y = (a_low.max(b_low) + a_high.min(b_high)) * Fixed::HALF;
+            (y, y)
+        };
+        (FixedVec3::new(a.x, a_y, a.z), FixedVec3::new(b.x, b_y, b.z))
+    }
+
+    fn sphere_sphere(
+        a: FixedVec3,
+        a_radius: Fixed,
+        b: FixedVec3,
+        b_radius: Fixed,
+    ) -> Option<(FixedVec3, Fixed)> {
+        let delta = b - a;
+        let distance = delta.length();
+        let depth = a_radius + b_radius - distance;
+        if depth <= Fixed::ZERO {
+            return None;
+        }
+        let normal = if distance > Fixed::ZERO {
+            delta / distance
+        } else {
+            axis(1, 0, 0)
+        };
+        Some((normal, depth))
+    }
+
+    fn round_box(
+        center: FixedVec3,
+        half_height: Fixed,
+        radius: Fixed,
+        min: FixedVec3,
+        max: FixedVec3,
+    ) -> Option<(FixedVec3, Fixed)> {
+        let low = (center.y - half_height).max(min.y);
+        let high = (center.y + half_height).min(max.y);
+        let y = if low <= high {
+            center.y.clamp(low, high)
+        } else if center.y + half_height < min.y {
+            center.y + half_height
+        } else {
+            center.y - half_height
+        };
+        let point = FixedVec3::new(center.x, y, center.z);
+        let closest = FixedVec3::new(
+            point.x.clamp(min.x, max.x),
+            point.y.clamp(min.y, max.y),
+            point.z.clamp(min.z, max.z),
+        );
+        let delta = point - closest;
+        let distance = delta.length();
+        if distance > Fixed::ZERO {
+            let depth = radius - distance;
+            return (depth > Fixed::ZERO).then(|| (delta / distance, depth));
+        }
+
+        let faces = [
+            (point.x - min.x, axis(-1, 0, 0)),
+            (max.x - point.x, axis(1, 0, 0)),
+            (point.y - min.y, axis(0, -1, 0)),
+            (max.y - point.y, axis(0, 1, 0)),
+            (point.z - min.z, axis(0, 0, -1)),
+            (max.z - point.z, axis(0, 0, 1)),
+        ];
+        let (gap, normal) = faces
+            .into_iter()
+            .reduce(|best, face| if face.0 < best.0 { face } else { best })?;
+        Some((normal, gap + radius))
+    }
+
+    fn box_box(a: &Shape, b: &Shape) -> Option<(FixedVec3, Fixed)> {
+        let overlap = |a_min: Fixed, a_max: Fixed, b_min: Fixed, b_max: Fixed| {
+            a_max.min(b_max) - a_min.max(b_min)
+        };
+        let sign = |a: Fixed, b: Fixed| if b < a { -1 } else { 1 };
+        let axes = [
+            (
+                overlap(a.min.x, a.max.x, b.min.x, b.max.x),
+                axis(sign(a.center.x, b.center.x), 0, 0),
+            ),
+            (
+                overlap(a.min.y, a.max.y, b.min.y, b.max.y),
+                axis(0, sign(a.center.y, b.center.y), 0),
+            ),
+            (
+                overlap(a.min.z, a.max.z, b.min.z, b.max.z),
+                axis(0, 0, sign(a.center.z, b.center.z)),
+            ),
+        ];
+        let (depth, normal) = axes
+            .into_iter()
+            .reduce(|best, axis| if axis.0 < best.0 { axis } else { best })?;
+        (depth > Fixed::ZERO).then_some((normal, depth))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn world_with(colliders: &[(Vec3, Collider)]) -> (EcsWorld, Vec<Entity>) {
+        let mut world = EcsWorld::new();
+        let entities = colliders
+            .iter()
+            .map(|(position, collider)| {
+                let object = world
+                    .spawn_world_object(
+                        "body",
+                        WorldTransform::new(position.x, position.y, position.z),
+                    )
+                    .unwrap();
+                world.set_collider(object.entity, *collider).unwrap();
+                object.entity
+            })
+            .collect();
+        (world, entities)
+    }
+
+    fn fixed_contacts(world: &EcsWorld) -> Vec<Contact> {
+        world
+            .detect_contacts_fixed(&BTreeMap::new())
+            .into_iter()
+            .map(Contact::from)
+            .collect()
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-5, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn narrow_phase_covers_every_shape_pair() {
+        let unit_box = Collider::aabb(Vec3::new(1.0, 1.0, 1.0));
+        let capsule = Collider::capsule(1.0, 0.5);
+        let cases = [
+            // Spheres side by side.
+            (
+                Collider::sphere(1.0),
+                Vec3::new(1.5, 0.0, 0.0),
+                Collider::sphere(1.0),
+                Vec3::new(1.0, 0.0, 0.0),
+                0.5,
+            ),
+            // Sphere against a box face.
+            (
+                unit_box,
+                Vec3::new(1.25, 0.0, 0.0),
+                Collider::sphere(0.5),
+                Vec3::new(1.0, 0.0, 0.0),
+                0.25,
+            ),
+            // Boxes overlapping least along z.
+            (
+                unit_box,
+                Vec3::new(0.5, 0.0, 1.75),
+                unit_box,
+                Vec3::new(0.0, 0.0, 1.0),
+                0.25,
+            ),
+            // Standing capsules overlapping sideways.
+            (
+                capsule,
+                Vec3::new(0.75, 0.5, 0.0),
+                capsule,
+                Vec3::new(1.0, 0.0, 0.0),
+                0.25,
+            ),
+            // Capsule cap resting into a sphere from above.
+            (
+                Collider::sphere(0.5),
+                Vec3::new(0.0, 1.75, 0.0),
+                capsule,
+                Vec3::new(0.0, 1.0, 0.0),
+                0.25,
+            ),
+            // Capsule leaning into a box.
+            (
+                unit_box,
+                Vec3::new(0.0, 0.0, -1.25),
+                capsule,
+                Vec3::new(0.0, 0.0, -1.0),
+                0.25,
+            ),
+        ];
+        for (first, position, second, normal, depth) in cases {
+            let (world, _) = world_with(&[(Vec3::ZERO, first), (position, second)]);
+            for contacts in [world.detect_contacts(), fixed_contacts(&world)] {
+                assert_eq!(contacts.len(), 1, "{first:?} vs {second:?}");
+                assert_eq!(contacts[0].normal, normal, "{first:?} vs {second:?}");
+                assert_close(contacts[0].depth, depth);
+            }
+        }
+
+        // Bounding boxes overlap, but the sphere misses the box corner and the capsule.
+        let (apart, _) = world_with(&[
+            (Vec3::ZERO, Collider::sphere(1.0)),
+            (Vec3::new(1.8, 1.8, 0.0), unit_box),
+            (Vec3::new(0.0, 3.5, 0.0), capsule),
+        ]);
+        assert!(apart.detect_contacts().is_empty());
+        assert!(apart.detect_contacts_fixed(&BTreeMap::new()).is_empty());
+    }
+
+    #[test]
+    fn broad_phase_reports_pairs_in_entity_order_and_skips_static_pairs() {
+        let wall = Collider::aabb(Vec3::new(1.0, 1.0, 1.0)).into_static();
+        let (world, entities) = world_with(&[
+            (Vec3::new(4.0, 0.0, 0.0), Collider::sphere(1.0)),
+            (Vec3::new(1.5, 0.0, 0.0), wall),
+            (Vec3::new(0.0, 0.0, 0.0), wall),
+            (Vec3::new(3.0, 0.0, 0.0), Collider::sphere(1.0)),
+            (Vec3::new(50.0, 0.0, 0.0), Collider::sphere(1.0)),
+        ]);
+
+        for contacts in [world.detect_contacts(), fixed_contacts(&world)] {
+            let pairs: Vec<_> = contacts
+                .iter()
+                .map(|contact| (contact.a, contact.b))
+                .collect();
+            assert_eq!(
+                pairs,
+                [(entities[0], entities[3]), (entities[1], entities[3])]
+            );
+        }
+    }
+
+    #[test]
+    fn resolution_only_pushes_dynamic_colliders_out_of_static_ones() {
+        let (mut world, entities) = world_with(&[
+            (
+                Vec3::ZERO,
+                Collider::aabb(Vec3::new(1.0, 1.0, 1.0)).into_static(),
+            ),
+            (Vec3::new(0.0, 0.0, 1.25), Collider::capsule(0.5, 0.5)),
+            (Vec3::new(0.0, 0.0, 2.0), Collider::sphere(0.5)),
+        ]);
+
+        let contacts = world.detect_contacts();
+        assert_eq!(contacts.len(), 2);
+        assert_eq!(world.resolve_contacts(&contacts), 1);
+        let pushed = world.get_component::<WorldTransform>(entities[1]).unwrap();
+        assert_close(pushed.z, 1.5);
+        let other = world.get_component::<WorldTransform>(entities[2]).unwrap();
+        assert_eq!(other.z, 2.0);
+
+        assert!(world
+            .set_collider(entities[0], Collider::sphere(f32::NAN))
+            .is_err());
+    }
+
+    #[test]
+    fn fixed_resolution_moves_the_given_positions() {
+        let (mut world, entities) = world_with(&[
+            (
+                Vec3::ZERO,
+                Collider::aabb(Vec3::new(1.0, 1.0, 1.0)).into_static(),
+            ),
+            (Vec3::new(0.0, 0.0, 1.25), Collider::sphere(0.5)),
+        ]);
+        // A position one step past the transform, which `f32` cannot represent.
+        let start = FixedVec3::from(Vec3::new(0.0, 0.0, 1.25))
+            + FixedVec3::new(Fixed::ZERO, Fixed::ZERO, Fixed::EPSILON);
+        let mut positions = BTreeMap::from([(entities[1], start)]);
+
+        let contacts = world.detect_contacts_fixed(&positions);
+        assert_eq!(contacts.len(), 1);
+        assert_eq!(contacts[0].depth, Fixed::from_ratio(1, 4) - Fixed::EPSILON);
+        assert_eq!(world.resolve_contacts_fixed(&contacts, &mut positions), 1);
+        assert_eq!(positions[&entities[1]].z, Fixed::from_ratio(3, 2));
+        let pushed = world.get_component::<WorldTransform>(entities[1]).unwrap();
+        assert_eq!(pushed.z, 1.5);
+        assert!(world.detect_contacts_fixed(&positions).is_empty());
+    }
+}
@@ -0,0 +1,427 @@
+//! Typed component storage keyed by entity, plus tuple queries over it.
+//!
+//! Each component type gets its own column indexed by entity slot, so lookups are O(1) and a
+//! value is only returned for the exact entity generation it was inserted for.
+//!
+//! Columns also record change ticks: when each value was added and last mutably accessed, and
+//! which entities lost the component. Mutable access counts as a change even if the value ends up
+//! equal, so callers that want precise tracking should skip writes that would not change anything.
+//!
+//! Component types registered with
+//! [`register_serializable_component`](EcsWorld::register_serializable_component) are serialized
+//! into [`WorldSnapshot::components`](crate::WorldSnapshot::components), so snapshots, restores and
+//! rollback carry them.
+
+use std::{
+    any::{Any, TypeId},
+    collections::{BTreeMap, HashMap},
+};
+
+use kitu_core::{KituError, Result, Tick};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::{EcsWorld, Entity};
+
+/// Marker for data that can be stored against an entity.
+///
+/// Every `Send + Sync + 'static` type is a component; no registration is required before insert.
+pub trait Component: Send + Sync + 'static {}
+
+impl<T: Send + Sync + 'static> Component for T {}
+
+/// Ticks at which a component value was added and last changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComponentTicks {
+    /// Tick when the component was inserted on the entity.
+    pub added: Tick,
+    /// Tick of the most recent insert or mutable access.
+    pub changed: Tick,
+}
+
+trait ComponentColumn: Send + Sync {
+    fn is_empty(&self) -> bool;
+    fn remove_entity(&mut self, entity: Entity, tick: Tick);
+    fn prune_removed(&mut self, before: Tick);
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+struct Slot<C> {
+    generation: u32,
+    ticks: ComponentTicks,
+    value: C,
+}
+
+struct Column<C> {
+    slots: Vec<Option<Slot<C>>>,
+    removed: Vec<(Entity, Tick)>,
+}
+
+impl<C> Column<C> {
+    fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            removed: Vec::new(),
+        }
+    }
+
+    fn slot(&self, entity: Entity) -> Option<&Slot<C>> {
+        self.slots
+            .get(entity.index() as usize)?
+            .as_ref()
+            .filter(|slot| slot.generation == entity.generation())
+    }
+
+    fn slot_mut(&mut self, entity: Entity) -> Option<&mut Slot<C>> {
+        self.slots
+            .get_mut(entity.index() as usize)?
+            .as_mut()
+            .filter(|slot| slot.generation == entity.generation())
+    }
+
+    fn insert(&mut self, entity: Entity, value: C, tick: Tick) -> Option<C> {
+        if let Some(slot) = self.slot_mut(entity) {
+            slot.ticks.changed = tick;
+            return Some(std::mem::replace(&mut slot.value, value));
+        }
+        let index = entity.index() as usize;
+        if self.slots.len() <= index {
+            self.slots.resize_with(index + 1, || None);
+        }
+        self.slots[index] = Some(Slot {
+            generation: entity.generation(),
+            ticks: ComponentTicks {
+                added: tick,
+                changed: tick,
+            },
+            value,
+        });
+        None
+    }
+
+    fn remove(&mut self, entity: Entity, tick: Tick) -> Option<C> {
+        self.slot(entity)?;
+        let slot = self.slots[entity.index() as usize].take()?;
+        self.removed.push((entity, tick));
+        Some(slot.value)
+    }
+
+    fn entities_where(&self, predicate: impl Fn(&ComponentTicks) -> bool) -> Vec<Entity> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| {
+                let slot = slot.as_ref()?;
+                predicate(&slot.ticks).then(|| Entity::new(index as u32, slot.generation))
+            })
+            .collect()
+    }
+}
+
+impl<C: Component> ComponentColumn for Column<C> {
+    fn is_empty(&self) -> bool {
+        self.slots.iter().all(Option::is_none)
+    }
+
+    fn remove_entity(&mut self, entity: Entity, tick: Tick) {
+        self.remove(entity, tick);
+    }
+
+    fn prune_removed(&mut self, before: Tick) {
+        self.removed.retain(|(_, tick)| *tick >= before);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Per-type component columns owned by the world.
+pub(crate) struct ComponentStore {
+    columns: HashMap<TypeId, Box<dyn ComponentColumn>>,
+    change_tick: Tick,
+}
+
+impl Default for ComponentStore {
+    fn default() -> Self {
+        Self {
+            columns: HashMap::new(),
+            change_tick: Tick::start(),
+        }
+    }
+}
+
+impl ComponentStore {
+    pub(crate) fn change_tick(&self) -> Tick {
+        self.change_tick
+    }
+
+    /// Sets the tick stamped on later changes and forgets removals older than the previous tick.
+    pub(crate) fn set_change_tick(&mut self, tick: Tick) {
+        self.change_tick = tick;
+        let keep_from = Tick::start().advance_by(tick.get().saturating_sub(1));
+        for column in self.columns.values_mut() {
+            column.prune_removed(keep_from);
+        }
+    }
+
+    pub(crate) fn insert<C: Component>(&mut self, entity: Entity, component: C) -> Option<C> {
+        let tick = self.change_tick;
+        self.columns
+            .entry(TypeId::of::<C>())
+            .or_insert_with(|| Box::new(Column::<C>::new()))
+            .as_any_mut()
+            .downcast_mut::<Column<C>>()
+            .expect("component column type matches its TypeId")
+            .insert(entity, component, tick)
+    }
+
+    pub(crate) fn get<C: Component>(&self, entity: Entity) -> Option<&C> {
+        Some(&self.column::<C>()?.slot(entity)?.value)
+    }
+
+    pub(crate) fn get_mut<C: Component>(&mut self, entity: Entity) -> Option<&mut C> {
+        let tick = self.change_tick;
+        let slot = self.column_mut::<C>()?.slot_mut(entity)?;
+        slot.ticks.changed = tick;
+        Some(&mut slot.value)
+    }
+
+    pub(crate) fn ticks<C: Component>(&self, entity: Entity) -> Option<ComponentTicks> {
+        Some(self.column::<C>()?.slot(entity)?.ticks)
+    }
+
+    pub(crate) fn remove<C: Component>(&mut self, entity: Entity) -> Option<C> {
+        let tick = self.change_tick;
+        self.column_mut::<C>()?.remove(entity, tick)
+    }
+
+    pub(crate) fn remove_entity(&mut self, entity: Entity) {
+        let tick = self.change_tick;
+        for column in self.columns.values_mut() {
+            column.remove_entity(entity, tick);
+        }
+    }
+
+    pub(crate) fn added_since<C: Component>(&self, since: Tick) -> Vec<Entity> {
+        self.column::<C>()
+            .map(|column| column.entities_where(|ticks| ticks.added >= since))
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn changed_since<C: Component>(&self, since: Tick) -> Vec<Entity> {
+        self.column::<C>()
+            .map(|column| column.entities_where(|ticks| ticks.changed >= since))
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn removed_since<C: Component>(&self, since: Tick) -> Vec<Entity> {
+        self.column::<C>()
+            .map(|column| {
+                column
+                    .removed
+                    .iter()
+                    .filter(|(_, tick)| *tick >= since)
+                    .map(|(entity, _)| *entity)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the types that at least one entity currently holds.
+    pub(crate) fn occupied_types(&self) -> impl Iterator<Item = TypeId> + '_ {
+        self.columns
+            .iter()
+            .filter(|(_, column)| !column.is_empty())
+            .map(|(type_id, _)| *type_id)
+    }
+
+    fn values<C: Component>(&self) -> impl Iterator<Item = (Entity, &C)> {
+        self.column::<C>()
+            .into_iter()
+            .flat_map(|column| column.slots.iter().enumerate())
+            .filter_map(|(index, slot)| {
+                let slot = slot.as_ref()?;
+                Some((Entity::new(index as u32, slot.generation), &slot.value))
+            })
+    }
+
+    fn column<C: Component>(&self) -> Option<&Column<C>> {
+        self.columns
+            .get(&TypeId::of::<C>())?
+            .as_any()
+            .downcast_ref::<Column<C>>()
+    }
+
+    fn column_mut<C: Component>(&mut self) -> Option<&mut Column<C>> {
+        self.columns
+            .get_mut(&TypeId::of::<C>())?
+            .as_any_mut()
+            .downcast_mut::<Column<C>>()
+    }
+}
+
+type SerializeFn = fn(&ComponentStore) -> serde_json::Result<BTreeMap<Entity, Value>>;
+type DeserializeFn = fn(Value) -> serde_json::Result<Box<dyn Any + Send + Sync>>;
+type InsertFn = fn(&mut ComponentStore, Entity, Box<dyn Any + Send + Sync>);
+
+struct SerializableComponent {
+    type_id: TypeId,
+    serialize: SerializeFn,
+    deserialize: DeserializeFn,
+    insert: InsertFn,
+}
+
+/// Component values decoded from a snapshot, ready to be inserted.
+pub(crate) struct RestoredComponents(Vec<(InsertFn, Entity, Box<dyn Any + Send + Sync>)>);
+
+/// Component types whose values are part of world snapshots, by snapshot key.
+#[derive(Default)]
+pub(crate) struct SerializableComponents {
+    entries: BTreeMap<String, SerializableComponent>,
+}
+
+impl SerializableComponents {
+    pub(crate) fn register<C>(&mut self, key: String) -> Result<()>
+    where
+        C: Component + Serialize + DeserializeOwned,
+    {
+        if key.is_empty() {
+            return Err(KituError::InvalidInput("component key cannot be empty"));
+        }
+        match self.entries.get(&key) {
+            Some(existing) if existing.type_id == TypeId::of::<C>() => return Ok(()),
+            Some(_) => {
+                return Err(KituError::InvalidInput(
+                    "component key is already used by another type",
+                ));
+            }
+            None => {}
+        }
+        if self.captures(TypeId::of::<C>()) {
+            return Err(KituError::InvalidInput(
+                "component type is already registered under another key",
+            ));
+        }
+        self.entries.insert(
+            key,
+            SerializableComponent {
+                type_id: TypeId::of::<C>(),
+                serialize: serialize_column::<C>,
+                deserialize: deserialize_component::<C>,
+                insert: insert_component::<C>,
+            },
+        );
+        Ok(())
+    }
+
+    pub(crate) fn captures(&self, type_id: TypeId) -> bool {
+        self.entries.values().any(|entry| entry.type_id == type_id)
+    }
+
+    /// Serializes every registered type that some entity holds, keyed by snapshot key.
+    pub(crate) fn snapshot(
+        &self,
+        store: &ComponentStore,
+    ) -> Result<BTreeMap<String, BTreeMap<Entity, Value>>> {
+        let mut snapshot = BTreeMap::new();
+        for (key, entry) in &self.entries {
+            let values = (entry.serialize)(store)
+                .map_err(|error| KituError::Serialization(format!("component `{key}`: {error}")))?;
+            if !values.is_empty() {
+                snapshot.insert(key.clone(), values);
+            }
+        }
+        Ok(snapshot)
+    }
+
+    /// Decodes snapshot values for registered keys without touching the store.
+    pub(crate) fn decode_snapshot(
+        &self,
+        values: &BTreeMap<String, BTreeMap<Entity, Value>>,
+    ) -> Result<RestoredComponents> {
+        let mut restored = Vec::new();
+        for (key, by_entity) in values {
+            let entry = self.entries.get(key).ok_or_else(|| {
+                KituError::Serialization(format!("component `{key}` is not registered"))
+            })?;
+            for (entity, value) in by_entity {
+                let decoded = (entry.deserialize)(value.clone()).map_err(|error| {
+                    KituError::Serialization(format!("component `{key}`: {error}"))
+                })?;
+                restored.push((entry.insert, *entity, decoded));
+            }
+        }
+        Ok(RestoredComponents(restored))
+    }
+
+    pub(crate) fn apply_restored(store: &mut ComponentStore, restored: RestoredComponents) {
+        for (insert, entity, value) in restored.0 {
+            insert(store, entity, value);
+        }
+    }
+}
+
+impl RestoredComponents {
+    pub(crate) fn entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.0.iter().map(|(_, entity, _)| *entity)
+    }
+}
+
+fn serialize_column<C: Component + Serialize>(
+    store: &ComponentStore,
+) -> serde_json::Result<BTreeMap<Entity, Value>> {
+    store
+        .values::<C>()
+        .map(|(entity, value)| Ok((entity, serde_json::to_value(value)?)))
+        .collect()
+}
+
+fn deserialize_component<C: Component + DeserializeOwned>(
+    value: Value,
+) -> serde_json::Result<Box<dyn Any + Send + Sync>> {
+    Ok(Box::new(serde_json::from_value::<C>(value)?))
+}
+
+fn insert_component<C: Component>(
+    store: &mut ComponentStore,
+    entity: Entity,
+    value: Box<dyn Any + Send + Sync>,
+) {
+    if let Ok(value) = value.downcast::<C>() {
+        store.insert(entity, *value);
+    }
+}
+
+/// A set of component types fetched together for one entity.
+///
+/// Implemented for tuples of one to four component types, e.g. `(WorldTransform, Health)`.
+pub trait ComponentQuery {
+    /// Borrowed view returned for each matching entity.
+    type Item<'w>;
+
+    /// Fetches every component in the set, or `None` when any of them is missing.
+    fn fetch(world: &EcsWorld, entity: Entity) -> Option<Self::Item<'_>>;
+}
+
+macro_rules! impl_component_query {
+    ($($name:ident),+) => {
+        impl<$($name: Component),+> ComponentQuery for ($($name,)+) {
+            type Item<'w> = ($(&'w $name,)+);
+
+            fn fetch(world: &EcsWorld, entity: Entity) -> Option<Self::Item<'_>> {
+                Some(($(world.get_component::<$name>(entity)?,)+))
+            }
+        }
+    };
+}
+
+impl_component_query!(A);
+impl_component_query!(A, B);
+impl_component_query!(A, B, C);
+impl_component_query!(A, B, C, D);
@@ -0,0 +1,483 @@
+//! Compact differences between two [`WorldSnapshot`]s.
+//!
+//! A [`WorldDelta`] lists the objects spawned, despawned and changed between a base snapshot
+//! and a target snapshot, plus changed resources, serializable components and allocator state. Objects are matched by
+//! entity handle, and changed objects only carry the fields that differ. Hosts that already
+//! sent a full snapshot can stream deltas keyed by tick and let clients rebuild each state with
+//! [`WorldSnapshot::apply_delta`].
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use kitu_core::{KituError, Result};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::{
+    Collider, Entity, EntityAllocatorState, LocalTransform, WorldObject, WorldSnapshot,
+    WorldTransform, WORLD_SNAPSHOT_SCHEMA_VERSION,
+};
+
+/// Difference between a base [`WorldSnapshot`] and a later one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorldDelta {
+    /// Snapshot layout version the delta was computed with.
+    pub schema_version: u32,
+    /// Tick of the snapshot the delta applies to.
+    pub base_tick: u64,
+    /// Tick of the snapshot the delta produces.
+    pub tick: u64,
+    /// Objects present in the target but not in the base, in entity order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub spawned: Vec<WorldObject>,
+    /// Entities of objects present in the base but not in the target, in entity order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub despawned: Vec<Entity>,
+    /// Objects present in both snapshots whose fields differ, in entity order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changed: Vec<WorldObjectChange>,
+    /// Resources added or changed in the target, keyed by snapshot key.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub resources: BTreeMap<String, serde_json::Value>,
+    /// Resource keys present in the base but not in the target.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed_resources: Vec<String>,
+    /// Component keys added or changed in the target, each with all of its values.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub components: BTreeMap<String, BTreeMap<Entity, serde_json::Value>>,
+    /// Component keys present in the base but not in the target.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed_components: Vec<String>,
+    /// Target allocator state, present only when it differs from the base.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entities: Option<EntityAllocatorState>,
+}
+
+/// Fields of one world object that changed between two snapshots.
+///
+/// `None` means the field is unchanged. For `parent`, `local` and `collider`, `Some(None)` means
+/// the value was cleared, e.g. after the object was detached from its parent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorldObjectChange {
+    /// Entity handle of the changed object.
+    pub entity: Entity,
+    /// New external identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// New value of [`WorldObject::named`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub named: Option<bool>,
+    /// New object category.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// New world transform.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transform: Option<WorldTransform>,
+    /// New parent, or `Some(None)` when the object became a root.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "present_option"
+    )]
+    pub parent: Option<Option<Entity>>,
+    /// New local transform, or `Some(None)` when it was removed.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "present_option"
+    )]
+    pub local: Option<Option<LocalTransform>>,
+    /// New collider, or `Some(None)` when it was removed.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "present_option"
+    )]
+    pub collider: Option<Option<Collider>>,
+}
+
+impl WorldObjectChange {
+    fn between(base: &WorldObject, target: &WorldObject) -> Option<Self> {
+        let change = Self {
+            entity: target.entity,
+            id: (base.id != target.id).then(|| target.id.clone()),
+            named: (base.named != target.named).then_some(target.named),
+            kind: (base.kind != target.kind).then(|| target.kind.clone()),
+            transform: (base.transform != target.transform).then_some(target.transform),
+            parent: (base.parent != target.parent).then_some(target.parent),
+            local: (base.local != target.local).then_some(target.local),
+            collider: (base.collider != target.collider).then_some(target.collider),
+        };
+        (change != Self::unchanged(target.entity)).then_some(change)
+    }
+
+    fn unchanged(entity: Entity) -> Self {
+        Self {
+            entity,
+            id: None,
+            named: None,
+            kind: None,
+            transform: None,
+            parent: None,
+            local: None,
+            collider: None,
+        }
+    }
+
+    fn apply_to(&self, object: &mut WorldObject) {
+        if let Some(id) = &self.id {
+            object.id.clone_from(id);
+        }
+        if let Some(named) = self.named {
+            object.named = named;
+        }
+        if let Some(kind) = &self.kind {
+            object.kind.clone_from(kind);
+        }
+        if let Some(transform) = self.transform {
+            object.transform = transform;
+        }
+        if let Some(parent) = self.parent {
+            object.parent = parent;
+        }
+        if let Some(local) = self.local {
+            object.local = local;
+        }
+        if let Some(collider) = self.collider {
+            object.collider = collider;
+        }
+    }
+}
+
+/// Keeps an explicit `null` distinct from a missing field.
+fn present_option<'de, D, T>(deserializer: D) -> std::result::Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+impl WorldDelta {
+    /// Returns `true` when applying the delta changes nothing but the tick.
+    pub fn is_empty(&self) -> bool {
+        self.spawned.is_empty()
+            && self.despawned.is_empty()
+            && self.changed.is_empty()
+            && self.resources.is_empty()
+            && self.removed_resources.is_empty()
+            && self.components.is_empty()
+            && self.removed_components.is_empty()
+            && self.entities.is_none()
+    }
+
+    /// Encodes the delta as JSON.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self).map_err(|error| KituError::Serialization(error.to_string()))
+    }
+
+    /// Decodes a JSON delta, rejecting unsupported schema versions.
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str::<Self>(json)
+            .map_err(|error| KituError::Serialization(error.to_string()))?
+            .checked_version()
+    }
+
+    /// Encodes the delta as MessagePack with named fields.
+    pub fn to_msgpack(&self) -> Result<Vec<u8>> {
+        rmp_serde::to_vec_named(self).map_err(|error| KituError::Serialization(error.to_string()))
+    }
+
+    /// Decodes a MessagePack delta, rejecting unsupported schema versions.
+    pub fn from_msgpack(bytes: &[u8]) -> Result<Self> {
+        rmp_serde::from_slice::<Self>(bytes)
+            .map_err(|error| KituError::Serialization(error.to_string()))?
+            .checked_version()
+    }
+
+    fn checked_version(self) -> Result<Self> {
+        if self.schema_version == 0 || self.schema_version > WORLD_SNAPSHOT_SCHEMA_VERSION {
+            return Err(KituError::Serialization(format!(
+                "unsupported world delta schema version {} (supported: 1..={})",
+                self.schema_version, WORLD_SNAPSHOT_SCHEMA_VERSION
+            )));
+        }
+        Ok(self)
+    }
+}
+
+impl WorldSnapshot {
+    /// Computes the delta that turns `self` into `target`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_ecs::{EcsWorld, WorldTransform};
+    ///
+    /// let mut world = EcsWorld::new();
+    /// world.spawn_world_object_with_id("player:local", "player", WorldTransform::new(0.0, 0.0, 0.0)).unwrap();
+    /// let base = world.world_snapshot().unwrap();
+    ///
+    /// world.move_world_object("player:local", WorldTransform::new(1.0, 0.0, 0.0)).unwrap();
+    /// world.spawn_world_object("coin", WorldTransform::new(4.0, 0.0, 0.0)).unwrap();
+    /// let target = world.world_snapshot().unwrap();
+    ///
+    /// let delta = base.diff(&target);
+    /// assert_eq!(delta.spawned.len(), 1);
+    /// assert_eq!(delta.changed[0].transform, Some(WorldTransform::new(1.0, 0.0, 0.0)));
+    /// assert_eq!(base.apply_delta(&delta).unwrap(), target);
+    /// ```
+    pub fn diff(&self, target: &WorldSnapshot) -> WorldDelta {
+        let base_objects: BTreeMap<Entity, &WorldObject> = self
+            .objects
+            .iter()
+            .map(|object| (object.entity, object))
+            .collect();
+        let target_entities: BTreeSet<Entity> =
+            target.objects.iter().map(|object| object.entity).collect();
+
+        let mut spawned = Vec::new();
+        let mut changed = Vec::new();
+        for object in &target.objects {
+            match base_objects.get(&object.entity) {
+                Some(base) => changed.extend(WorldObjectChange::between(base, object)),
+                None => spawned.push(object.clone()),
+            }
+        }
+        spawned.sort_by_key(|object| object.entity);
+        changed.sort_by_key(|change| change.entity);
+
+        WorldDelta {
+            schema_version: WORLD_SNAPSHOT_SCHEMA_VERSION,
+            base_tick: self.tick,
+            tick: target.tick,
+            spawned,
+            despawned: base_objects
+                .keys()
+                .filter(|entity| !target_entities.contains(entity))
+                .copied()
+                .collect(),
+            changed,
+            resources: target
+                .resources
+                .iter()
+                .filter(|(key, value)| self.resources.get(*key) != Some(*value))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+            removed_resources: self
+                .resources
+                .keys()
+                .filter(|key| !target.resources.contains_key(*key))
+                .cloned()
+                .collect(),
+            components: target
+                .components
+                .iter()
+                .filter(|(key, values)| self.components.get(*key) != Some(*values))
+                .map(|(key, values)| (key.clone(), values.clone()))
+                .collect(),
+            removed_components: self
+                .components
+                .keys()
+                .filter(|key| !target.components.contains_key(*key))
+                .cloned()
+                .collect(),
+            entities: (self.entities != target.entities).then(|| target.entities.clone()),
+        }
+    }
+
+    /// Applies a delta computed against this snapshot, returning the target snapshot.
+    ///
+    /// Objects in the result are ordered by entity slot, matching
+    /// [`EcsWorld::world_snapshot`](crate::EcsWorld::world_snapshot). The delta is rejected when
+    /// its base tick differs from this snapshot's tick or when it despawns or changes objects
+    /// that are missing, or spawns objects that already exist.
+    pub fn apply_delta(&self, delta: &WorldDelta) -> Result<WorldSnapshot> {
+        if delta.base_tick != self.tick {
+            return Err(KituError::InvalidInput(
+                "world delta base tick does not match the snapshot",
+            ));
+        }
+        let mut objects: BTreeMap<Entity, WorldObject> = self
+            .objects
+            .iter()
+            .map(|object| (object.entity, object.clone()))
+            .collect();
+        for entity in &delta.despawned {
+            objects.remove(entity).ok_or(KituError::InvalidInput(
+                "world delta despawns an unknown object",
+            ))?;
+        }
+        for change in &delta.changed {
+            let object = objects
+                .get_mut(&change.entity)
+                .ok_or(KituError::InvalidInput(
+                    "world delta changes an unknown object",
+                ))?;
+            change.apply_to(object);
+        }
+        for object in &delta.spawned {
+            if objects.insert(object.entity, object.clone()).is_some() {
+                return Err(KituError::InvalidInput(
+                    "world delta spawns an object that already exists",
+                ));
+            }
+        }
+
+        let mut resources = self.resources.clone();
+        for key in &delta.removed_resources {
+            resources.remove(key);
+        }
+        resources.extend(
+            delta
+                .resources
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+
+        let mut components = self.components.clone();
+        for key in &delta.removed_components {
+            components.remove(key);
+        }
+        components.extend(
+            delta
+                .components
+                .iter()
+                .map(|(key, values)| (key.clone(), values.clone())),
+        );
+
+        Ok(WorldSnapshot {
+            schema_version: WORLD_SNAPSHOT_SCHEMA_VERSION,
+            tick: delta.tick,
+            objects: objects.into_values().collect(),
+            resources,
+            components,
+            entities: delta
+                .entities
+                .clone()
+                .unwrap_or_else(|| self.entities.clone()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{EcsWorld, Vec3};
+
+    use super::*;
+
+    fn scene() -> EcsWorld {
+        let mut world = EcsWorld::new();
+        let ship = world
+            .spawn_world_object_with_id("ship", "ship", WorldTransform::new(0.0, 0.0, 0.0))
+            .unwrap();
+        let turret = world
+            .spawn_world_object_with_id("turret", "turret", WorldTransform::new(1.0, 0.0, 0.0))
+            .unwrap();
+        world.attach_child(ship.entity, turret.entity).unwrap();
+        world
+            .spawn_world_object_with_id("rock", "prop", WorldTransform::new(5.0, 0.0, 5.0))
+            .unwrap();
+        world.insert_serializable_resource("score", 1_u32).unwrap();
+        world
+    }
+
+    #[test]
+    fn diff_reports_spawns_despawns_and_changed_fields_only() {
+        let mut world = scene();
+        let base = world.world_snapshot().unwrap();
+
+        let turret = world.world_object_entity("turret").unwrap();
+        world.detach_child(turret);
+        world
+            .move_world_object("ship", WorldTransform::new(0.0, 0.0, 2.0))
+            .unwrap();
+        world.despawn_world_object("rock").unwrap();
+        let coin = world
+            .spawn_world_object("coin", WorldTransform::new(3.0, 0.0, 0.0))
+            .unwrap();
+        *world.get_resource_mut::<u32>().unwrap() = 2;
+        world.set_change_tick(world.change_tick().next());
+        let target = world.world_snapshot().unwrap();
+
+        let delta = base.diff(&target);
+
+        assert_eq!((delta.base_tick, delta.tick), (0, 1));
+        assert_eq!(delta.spawned, vec![target.objects[2].clone()]);
+        assert_eq!(delta.spawned[0].entity, coin.entity);
+        assert_eq!(delta.despawned, vec![base.objects[2].entity]);
+        assert_eq!(
+            delta.changed,
+            vec![
+                WorldObjectChange {
+                    transform: Some(WorldTransform::new(0.0, 0.0, 2.0)),
+                    ..WorldObjectChange::unchanged(base.objects[0].entity)
+                },
+                WorldObjectChange {
+                    parent: Some(None),
+                    local: Some(None),
+                    ..WorldObjectChange::unchanged(turret)
+                },
+            ]
+        );
+        assert_eq!(
+            delta.resources,
+            BTreeMap::from([("score".to_string(), json!(2))])
+        );
+        assert!(delta.entities.is_some());
+        assert_eq!(base.apply_delta(&delta).unwrap(), target);
+    }
+
+    #[test]
+    fn deltas_round_trip_through_json_and_msgpack() {
+        let mut world = scene();
+        let base = world.world_snapshot().unwrap();
+        let turret = world.world_object_entity("turret").unwrap();
+        world.detach_child(turret);
+        let target = world.world_snapshot().unwrap();
+        let delta = base.diff(&target);
+
+        let json = delta.to_json().unwrap();
+        assert!(json.contains("\"parent\":null"));
+        assert!(!json.contains("\"spawned\""));
+        assert_eq!(WorldDelta::from_json(&json).unwrap(), delta);
+        assert_eq!(
+            WorldDelta::from_msgpack(&delta.to_msgpack().unwrap()).unwrap(),
+            delta
+        );
+        assert!(json.len() < target.to_json().unwrap().len());
+
+        let unchanged = target.diff(&target);
+        assert!(unchanged.is_empty());
+        assert_eq!(target.apply_delta(&unchanged).unwrap(), target);
+    }
+
+    #[test]
+    fn apply_rejects_deltas_for_another_base() {
+        let mut world = scene();
+        let base = world.world_snapshot().unwrap();
+        world
+            .move_world_object("rock", WorldTransform::new(6.0, 0.0, 5.0))
+            .unwrap();
+        world.set_change_tick(world.change_tick().next());
+        let delta = base.diff(&world.world_snapshot().unwrap());
+
+        let mut later = base.clone();
+        later.tick = 7;
+        assert!(later.apply_delta(&delta).is_err());
+
+        let mut missing = base.clone();
+        missing.objects.retain(|object| object.id != "rock");
+        assert!(missing.apply_delta(&delta).is_err());
+
+        let mut respawn = base.diff(&base);
+        respawn.spawned.push(base.objects[0].clone());
+        assert!(base.apply_delta(&respawn).is_err());
+
+        let moved = base.apply_delta(&delta).unwrap();
+        assert_eq!(
+            moved.objects[2].transform.translation(),
+            Vec3::new(6.0, 0.0, 5.0)
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! Generational entity handles and the allocator that hands them out.
+//!
+//! An [`Entity`] is a slot index plus the generation the slot had when the handle was issued.
+//! Despawning bumps the slot generation, so handles kept past a despawn are detected as stale
+//! instead of silently aliasing whatever entity reuses the slot.
+
+use std::{fmt, str::FromStr};
+
+use kitu_core::KituError;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Compact generational handle identifying one entity in an [`EcsWorld`](crate::EcsWorld).
+///
+/// Handles display as `{index}v{generation}` and parse back from the same form; serde uses the
+/// same string form.
+///
+/// # Examples
+///
+/// ```
+/// use kitu_ecs::{EcsWorld, Entity};
+///
+/// let mut world = EcsWorld::new();
+/// let entity = world.spawn_entity();
+/// let parsed: Entity = entity.to_string().parse().unwrap();
+/// assert_eq!(parsed, entity);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Entity {
+    index: u32,
+    generation: u32,
+}
+
+impl Entity {
+    pub(crate) const fn new(index: u32, generation: u32) -> Self {
+        Self { index, generation }
+    }
+
+    /// Returns the slot index shared by every generation of this entity.
+    pub const fn index(self) -> u32 {
+        self.index
+    }
+
+    /// Returns the generation of the slot when this handle was issued.
+    pub const fn generation(self) -> u32 {
+        self.generation
+    }
+
+    /// Packs the handle into a single integer, e.g. for FFI or wire formats.
+    pub const fn to_bits(self) -> u64 {
+        ((self.generation as u64) << 32) | self.index as u64
+    }
+
+    /// Rebuilds a handle packed by [`Entity::to_bits`].
+    pub const fn from_bits(bits: u64) -> Self {
+        Self {
+            index: bits as u32,
+            generation: (bits >> 32) as u32,
+        }
+    }
+}
+
+impl fmt::Display for Entity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}v{}", self.index, self.generation)
+    }
+}
+
+impl FromStr for Entity {
+    type Err = KituError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (index, generation) = value.split_once('v').ok_or(KituError::InvalidInput(
+            "entity handle must look like `{index}v{generation}`",
+        ))?;
+        let index = index
+            .parse()
+            .map_err(|_| KituError::InvalidInput("entity index must be an unsigned integer"))?;
+        let generation = generation.parse().map_err(|_| {
+            KituError::InvalidInput("entity generation must be an unsigned integer")
+        })?;
+        Ok(Self { index, generation })
+    }
+}
+
+impl Serialize for Entity {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Entity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Serializable state of the world's entity allocator.
+///
+/// Slots not listed in `free` are alive. Restoring this state makes later spawns reuse slots in
+/// the same order and under the same generations as the world it was captured from.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntityAllocatorState {
+    /// Current generation of every slot, indexed by slot.
+    pub generations: Vec<u32>,
+    /// Free slots in reuse order; the last entry is handed out first.
+    pub free: Vec<u32>,
+}
+
+/// Hands out entity slots, recycling despawned slots under a new generation.
+#[derive(Debug, Default)]
+pub(crate) struct EntityAllocator {
+    generations: Vec<u32>,
+    alive: Vec<bool>,
+    free: Vec<u32>,
+}
+
+impl EntityAllocator {
+    pub(crate) fn allocate(&mut self) -> Entity {
+        if let Some(index) = self.free.pop() {
+            let slot = index as usize;
+            self.alive[slot] = true;
+            return Entity::new(index, self.generations[slot]);
+        }
+        let index = u32::try_from(self.generations.len()).expect("entity index space exhausted");
+        self.generations.push(0);
+        self.alive.push(true);
+        Entity::new(index, 0)
+    }
+
+    pub(crate) fn free(&mut self, entity: Entity) -> bool {
+        if !self.is_alive(entity) {
+            return false;
+        }
+        let slot = entity.index as usize;
+        self.alive[slot] = false;
+        self.generations[slot] = self.generations[slot].wrapping_add(1);
+        self.free.push(entity.index);
+        true
+    }
+
+    pub(crate) fn is_alive(&self, entity: Entity) -> bool {
+        let slot = entity.index as usize;
+        self.alive.get(slot).copied().unwrap_or(false)
+            && self.generations[slot] == entity.generation
+    }
+
+    /// Captures slot generations and the free list so handles can be reissued identically.
+    pub(crate) fn state(&self) -> EntityAllocatorState {
+        EntityAllocatorState {
+            generations: self.generations.clone(),
+            free: self.free.clone(),
+        }
+    }
+
+    /// Rebuilds an allocator from captured state, rejecting out-of-range or duplicate free slots.
+    pub(crate) fn from_state(state: &EntityAllocatorState) -> Result<Self, KituError> {
+        let mut alive = vec![true; state.generations.len()];
+        for index in &state.free {
+            match alive.get_mut(*index as usize) {
+                Some(slot) if *slot => *slot = false,
+                Some(_) => {
+                    return Err(KituError::InvalidInput(
+                        "entity allocator free list contains a slot twice",
+                    ))
+                }
+                None => {
+                    return Err(KituError::InvalidInput(
+                        "entity allocator free list refers to an unknown slot",
+                    ))
+                }
+            }
+        }
+        Ok(Self {
+            generations: state.generations.clone(),
+            alive,
+            free: state.free.clone(),
+        })
+    }
+
+    /// Iterates alive entities in ascending slot order.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.alive
+            .iter()
+            .enumerate()
+            .filter(|(_, alive)| **alive)
+            .map(|(slot, _)| Entity::new(slot as u32, self.generations[slot]))
+    }
+}
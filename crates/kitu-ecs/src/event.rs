@@ -0,0 +1,192 @@
+//! Typed, double-buffered event channels between systems.
+//!
+//! Events sent during tick `T` are readable by later systems in `T` and by any system in `T + 1`.
+//! When dispatch advances to `T + 2` (or skips ahead) they are dropped. Buffers rotate only when
+//! [`EcsWorld::dispatch`](crate::EcsWorld::dispatch) sees a new tick, so repeated reads within a
+//! tick and replays of the same tick sequence observe the same events.
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt,
+    marker::PhantomData,
+};
+
+use kitu_core::Tick;
+
+/// Marker for data that can be sent through an event channel.
+///
+/// Every `Send + Sync + 'static` type is an event; channels are created on first use.
+pub trait Event: Send + Sync + 'static {}
+
+impl<T: Send + Sync + 'static> Event for T {}
+
+/// Per-reader position in an event channel.
+///
+/// Systems keep one cursor per event type so each event is observed exactly once per reader.
+pub struct EventCursor<E> {
+    next_sequence: u64,
+    marker: PhantomData<fn() -> E>,
+}
+
+impl<E> Default for EventCursor<E> {
+    fn default() -> Self {
+        Self {
+            next_sequence: 0,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<E> Clone for EventCursor<E> {
+    fn clone(&self) -> Self {
+        Self {
+            next_sequence: self.next_sequence,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<E> fmt::Debug for EventCursor<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventCursor")
+            .field("next_sequence", &self.next_sequence)
+            .finish()
+    }
+}
+
+struct EventInstance<E> {
+    sequence: u64,
+    event: E,
+}
+
+struct EventChannel<E> {
+    previous: Vec<EventInstance<E>>,
+    current: Vec<EventInstance<E>>,
+    next_sequence: u64,
+    tick: Option<Tick>,
+}
+
+impl<E: Event> EventChannel<E> {
+    fn new(tick: Option<Tick>) -> Self {
+        Self {
+            previous: Vec::new(),
+            current: Vec::new(),
+            next_sequence: 0,
+            tick,
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &EventInstance<E>> {
+        self.previous.iter().chain(&self.current)
+    }
+}
+
+trait ErasedChannel: Send + Sync {
+    fn update(&mut self, tick: Tick);
+    fn reset(&mut self, tick: Option<Tick>);
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<E: Event> ErasedChannel for EventChannel<E> {
+    fn update(&mut self, tick: Tick) {
+        match self.tick {
+            Some(current) if current == tick => {}
+            // Events sent before the first dispatch belong to that first tick.
+            None => {}
+            Some(current) if current.next() == tick => {
+                self.previous = std::mem::take(&mut self.current);
+            }
+            Some(_) => {
+                self.previous.clear();
+                self.current.clear();
+            }
+        }
+        self.tick = Some(tick);
+    }
+
+    fn reset(&mut self, tick: Option<Tick>) {
+        self.previous.clear();
+        self.current.clear();
+        self.tick = tick;
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Event channels owned by the world, keyed by event type.
+#[derive(Default)]
+pub(crate) struct EventChannels {
+    channels: HashMap<TypeId, Box<dyn ErasedChannel>>,
+    tick: Option<Tick>,
+}
+
+impl EventChannels {
+    pub(crate) fn send<E: Event>(&mut self, event: E) {
+        let channel = self.channel_mut::<E>();
+        let sequence = channel.next_sequence;
+        channel.next_sequence += 1;
+        channel.current.push(EventInstance { sequence, event });
+    }
+
+    pub(crate) fn iter<E: Event>(&self) -> impl Iterator<Item = &E> {
+        self.channel::<E>()
+            .into_iter()
+            .flat_map(|channel| channel.iter().map(|instance| &instance.event))
+    }
+
+    pub(crate) fn read<E: Event>(&self, cursor: &mut EventCursor<E>) -> Vec<&E> {
+        let Some(channel) = self.channel::<E>() else {
+            return Vec::new();
+        };
+        let start = cursor.next_sequence;
+        cursor.next_sequence = channel.next_sequence;
+        channel
+            .iter()
+            .filter(|instance| instance.sequence >= start)
+            .map(|instance| &instance.event)
+            .collect()
+    }
+
+    pub(crate) fn update(&mut self, tick: Tick) {
+        self.tick = Some(tick);
+        for channel in self.channels.values_mut() {
+            channel.update(tick);
+        }
+    }
+
+    /// Drops every buffered event while keeping sequence numbers, so existing cursors stay valid.
+    ///
+    /// Events sent afterwards belong to `tick`, so a dispatch of the following tick rotates them
+    /// into its previous buffer; with `None` they belong to whichever tick is dispatched first.
+    pub(crate) fn reset(&mut self, tick: Option<Tick>) {
+        self.tick = tick;
+        for channel in self.channels.values_mut() {
+            channel.reset(tick);
+        }
+    }
+
+    fn channel<E: Event>(&self) -> Option<&EventChannel<E>> {
+        self.channels
+            .get(&TypeId::of::<E>())?
+            .as_any()
+            .downcast_ref()
+    }
+
+    fn channel_mut<E: Event>(&mut self) -> &mut EventChannel<E> {
+        let tick = self.tick;
+        self.channels
+            .entry(TypeId::of::<E>())
+            .or_insert_with(|| Box::new(EventChannel::<E>::new(tick)))
+            .as_any_mut()
+            .downcast_mut()
+            .expect("event channel type matches its TypeId")
+    }
+}
@@ -0,0 +1,355 @@
+//! Stable hashing of authoritative world state.
+//!
+//! [`WorldSnapshot::state_hash`] feeds a fixed little-endian encoding of the snapshot into
+//! 64-bit FNV-1a, so the same state hashes to the same value on every platform, build and run.
+//! `std`'s hashers are avoided on purpose: their output is randomly keyed or allowed to change
+//! between releases. [`WorldSnapshot::fixed_state_hash`] encodes every float as its [`Fixed`]
+//! value instead, for runs that simulate in fixed point.
+
+use kitu_core::{Fixed, Result};
+
+use crate::{
+    Collider, ColliderShape, EcsWorld, Entity, LocalTransform, Quat, Vec3, WorldSnapshot,
+    WorldTransform,
+};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+struct StateHasher {
+    hash: u64,
+    fixed_point: bool,
+}
+
+impl StateHasher {
+    fn new() -> Self {
+        Self {
+            hash: FNV_OFFSET_BASIS,
+            fixed_point: false,
+        }
+    }
+
+    fn fixed_point() -> Self {
+        Self {
+            fixed_point: true,
+            ..Self::new()
+        }
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.hash ^= u64::from(*byte);
+            self.hash = self.hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn u8(&mut self, value: u8) {
+        self.bytes(&[value]);
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn len(&mut self, len: usize) {
+        self.u64(len as u64);
+    }
+
+    fn str(&mut self, value: &str) {
+        self.len(value.len());
+        self.bytes(value.as_bytes());
+    }
+
+    /// Hashes floats by bit pattern, folding `-0.0` into `0.0` and every NaN payload into one
+    /// value so platform-specific float details do not leak into the hash.
+    ///
+    /// Fixed-point hashers hash the [`Fixed`] bits instead, which fold both cases into zero.
+    fn f32(&mut self, value: f32) {
+        if self.fixed_point {
+            self.u64(Fixed::from_f32(value).to_bits() as u64);
+            return;
+        }
+        let canonical = if value.is_nan() {
+            f32::NAN
+        } else if value == 0.0 {
+            0.0
+        } else {
+            value
+        };
+        self.u32(canonical.to_bits());
+    }
+
+    fn entity(&mut self, entity: Entity) {
+        self.u64(entity.to_bits());
+    }
+
+    fn vec3(&mut self, value: Vec3) {
+        self.f32(value.x);
+        self.f32(value.y);
+        self.f32(value.z);
+    }
+
+    fn quat(&mut self, value: Quat) {
+        self.f32(value.x);
+        self.f32(value.y);
+        self.f32(value.z);
+        self.f32(value.w);
+    }
+
+    fn world_transform(&mut self, value: &WorldTransform) {
+        self.vec3(value.translation());
+        self.quat(value.rotation);
+        self.vec3(value.scale);
+    }
+
+    fn local_transform(&mut self, value: &LocalTransform) {
+        self.vec3(value.translation);
+        self.quat(value.rotation);
+        self.vec3(value.scale);
+    }
+
+    fn collider(&mut self, value: &Collider) {
+        match value.shape {
+            ColliderShape::Sphere { radius } => {
+                self.u8(0);
+                self.f32(radius);
+            }
+            ColliderShape::Aabb { half_extents } => {
+                self.u8(1);
+                self.vec3(half_extents);
+            }
+            ColliderShape::Capsule {
+                half_height,
+                radius,
+            } => {
+                self.u8(2);
+                self.f32(half_height);
+                self.f32(radius);
+            }
+        }
+        self.u8(u8::from(value.is_static));
+    }
+
+    fn option<T>(&mut self, value: Option<T>, hash: impl FnOnce(&mut Self, T)) {
+        match value {
+            Some(value) => {
+                self.u8(1);
+                hash(self, value);
+            }
+            None => self.u8(0),
+        }
+    }
+}
+
+impl WorldSnapshot {
+    /// Returns a platform-independent 64-bit hash of the whole snapshot.
+    ///
+    /// The hash covers the tick, every object field, serializable resources and components and the entity
+    /// allocator, but not [`schema_version`](Self::schema_version), so a state re-encoded under
+    /// a newer layout keeps its hash. Objects are hashed in snapshot order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_ecs::{EcsWorld, WorldTransform};
+    ///
+    /// let mut world = EcsWorld::new();
+    /// world.spawn_world_object_with_id("player:local", "player", WorldTransform::new(0.0, 0.0, 0.0)).unwrap();
+    /// let before = world.world_snapshot().unwrap().state_hash();
+    ///
+    /// world.move_world_object("player:local", WorldTransform::new(0.5, 0.0, 0.0)).unwrap();
+    /// assert_ne!(world.world_snapshot().unwrap().state_hash(), before);
+    /// assert_eq!(world.world_snapshot().unwrap().state_hash(), world.state_hash().unwrap());
+    /// ```
+    pub fn state_hash(&self) -> u64 {
+        self.hash_with(StateHasher::new())
+    }
+
+    /// Returns a hash like [`state_hash`](Self::state_hash) that encodes every float component as
+    /// its [`Fixed`] value.
+    ///
+    /// Floats that convert to the same fixed-point number hash equal, so states produced by
+    /// fixed-point simulation can be checked by hosts that keep positions as fixed-point numbers
+    /// without reproducing `f32` bit patterns.
+    pub fn fixed_state_hash(&self) -> u64 {
+        self.hash_with(StateHasher::fixed_point())
+    }
+
+    fn hash_with(&self, mut hasher: StateHasher) -> u64 {
+        hasher.u64(self.tick);
+
+        hasher.len(self.objects.len());
+        for object in &self.objects {
+            hasher.entity(object.entity);
+            hasher.str(&object.id);
+            // `named` is not hashed: names never look like handles, so the id already tells.
+            hasher.str(&object.kind);
+            hasher.world_transform(&object.transform);
+            hasher.option(object.parent, StateHasher::entity);
+            hasher.option(object.local.as_ref(), StateHasher::local_transform);
+            // Only hashed when present, so states without colliders keep their version 2 hash.
+            if let Some(collider) = &object.collider {
+                hasher.u8(0xc0);
+                hasher.collider(collider);
+            }
+        }
+
+        hasher.len(self.resources.len());
+        for (key, value) in &self.resources {
+            hasher.str(key);
+            hasher.str(&value.to_string());
+        }
+        // Only hashed when present, so states without components keep their version 3 hash.
+        if !self.components.is_empty() {
+            hasher.u8(0xc1);
+            hasher.len(self.components.len());
+            for (key, values) in &self.components {
+                hasher.str(key);
+                hasher.len(values.len());
+                for (entity, value) in values {
+                    hasher.entity(*entity);
+                    hasher.str(&value.to_string());
+                }
+            }
+        }
+
+        hasher.len(self.entities.generations.len());
+        for generation in &self.entities.generations {
+            hasher.u32(*generation);
+        }
+        hasher.len(self.entities.free.len());
+        for slot in &self.entities.free {
+            hasher.u32(*slot);
+        }
+        hasher.hash
+    }
+}
+
+impl EcsWorld {
+    /// Returns the [`state_hash`](WorldSnapshot::state_hash) of the current world snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Fails like [`world_snapshot`](Self::world_snapshot).
+    pub fn state_hash(&self) -> Result<u64> {
+        Ok(self.world_snapshot()?.state_hash())
+    }
+
+    /// Returns the [`fixed_state_hash`](WorldSnapshot::fixed_state_hash) of the current world
+    /// snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Fails like [`world_snapshot`](Self::world_snapshot).
+    pub fn fixed_state_hash(&self) -> Result<u64> {
+        Ok(self.world_snapshot()?.fixed_state_hash())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scene() -> EcsWorld {
+        let mut world = EcsWorld::new();
+        let ship = world
+            .spawn_world_object_with_id("ship", "ship", WorldTransform::new(1.0, 0.0, 0.0))
+            .unwrap();
+        let turret = world
+            .spawn_world_object("turret", WorldTransform::new(2.0, 0.0, 0.0))
+            .unwrap();
+        world.attach_child(ship.entity, turret.entity).unwrap();
+        world.insert_serializable_resource("score", 3_u32).unwrap();
+        world
+    }
+
+    #[test]
+    fn hash_is_pinned_across_platforms_and_runs() {
+        assert_eq!(StateHasher::new().hash, FNV_OFFSET_BASIS);
+        let mut hasher = StateHasher::new();
+        hasher.bytes(b"a");
+        assert_eq!(hasher.hash, 0xaf63_dc4c_8601_ec8c);
+
+        assert_eq!(scene().state_hash().unwrap(), 0xefd4_3635_7a6a_ddd8);
+        assert_eq!(
+            WorldSnapshot::default().state_hash(),
+            EcsWorld::new().state_hash().unwrap()
+        );
+    }
+
+    #[test]
+    fn every_part_of_the_state_changes_the_hash() {
+        let base = scene().world_snapshot().unwrap();
+        let hash = base.state_hash();
+
+        let mut tick = base.clone();
+        tick.tick += 1;
+        let mut moved = base.clone();
+        moved.objects[1].transform.y = 0.25;
+        let mut kind = base.clone();
+        kind.objects[0].kind = "boat".to_string();
+        let mut detached = base.clone();
+        detached.objects[1].parent = None;
+        let mut resource = base.clone();
+        resource
+            .resources
+            .insert("score".to_string(), serde_json::json!(4));
+        let mut allocator = base.clone();
+        allocator.entities.generations[0] += 1;
+        let mut collider = base.clone();
+        collider.objects[0].collider = Some(Collider::sphere(1.0));
+        let mut static_collider = collider.clone();
+        static_collider.objects[0].collider = Some(Collider::sphere(1.0).into_static());
+
+        assert_ne!(static_collider.state_hash(), collider.state_hash());
+
+        for changed in [
+            tick,
+            moved,
+            kind,
+            detached,
+            resource,
+            allocator,
+            collider,
+            static_collider,
+        ] {
+            assert_ne!(changed.state_hash(), hash);
+        }
+    }
+
+    #[test]
+    fn equal_floats_hash_equal() {
+        let mut positive = scene().world_snapshot().unwrap();
+        positive.objects[0].transform.z = 0.0;
+        let mut negative = positive.clone();
+        negative.objects[0].transform.z = -0.0;
+        assert_eq!(positive.state_hash(), negative.state_hash());
+
+        let mut upgraded = positive.clone();
+        upgraded.schema_version = 1;
+        assert_eq!(upgraded.state_hash(), positive.state_hash());
+    }
+
+    #[test]
+    fn fixed_point_hash_covers_the_same_state_in_fixed_point() {
+        let base = scene().world_snapshot().unwrap();
+        let hash = base.fixed_state_hash();
+        assert_ne!(hash, base.state_hash());
+        assert_eq!(scene().fixed_state_hash().unwrap(), hash);
+        assert_eq!(hash, 0xc67a_798b_abe5_1a42);
+
+        // Floats below one fixed-point step are indistinguishable, unlike in the float hash.
+        let mut nudged = base.clone();
+        nudged.objects[0].transform.z = 1e-12;
+        assert_eq!(nudged.fixed_state_hash(), hash);
+        assert_ne!(nudged.state_hash(), base.state_hash());
+
+        let mut moved = base.clone();
+        moved.objects[1].transform.y = 0.25;
+        assert_ne!(moved.fixed_state_hash(), hash);
+    }
+}
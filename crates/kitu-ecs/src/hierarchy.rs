@@ -0,0 +1,462 @@
+//! Parent/child relations between entities and world transform propagation.
+//!
+//! A child stores its [`LocalTransform`] relative to its parent; [`EcsWorld::propagate_transforms`]
+//! walks each tree from its root and rewrites the children's [`WorldTransform`]s. Roots keep their
+//! world transform as-is unless they also carry a `LocalTransform`, in which case it is taken as
+//! relative to the world origin.
+//!
+//! [`Parent`] and [`Children`] are kept consistent by the world; they are read-only for callers.
+
+use kitu_core::{KituError, Result};
+
+use crate::{EcsWorld, Entity, LocalTransform, WorldTransform};
+
+/// Component pointing at the entity's parent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parent(Entity);
+
+impl Parent {
+    /// Returns the parent entity.
+    pub const fn get(&self) -> Entity {
+        self.0
+    }
+}
+
+/// Component listing an entity's direct children in attach order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Children(Vec<Entity>);
+
+impl Children {
+    /// Returns the direct children in attach order.
+    pub fn as_slice(&self) -> &[Entity] {
+        &self.0
+    }
+}
+
+impl EcsWorld {
+    /// Makes `child` a child of `parent`, detaching it from any previous parent.
+    ///
+    /// When both entities have a [`WorldTransform`], the child's [`LocalTransform`] is recomputed
+    /// so its world transform stays where it is. Otherwise an existing `LocalTransform` is kept
+    /// and missing ones default to identity. Self-attachment, dead entities and attachments that
+    /// would create a cycle are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_ecs::{EcsWorld, WorldTransform};
+    ///
+    /// let mut world = EcsWorld::new();
+    /// let ship = world.spawn_world_object("ship", WorldTransform::new(10.0, 0.0, 0.0)).unwrap();
+    /// let turret = world.spawn_world_object("turret", WorldTransform::new(11.0, 0.0, 0.0)).unwrap();
+    /// world.attach_child(ship.entity, turret.entity).unwrap();
+    ///
+    /// world.move_world_object(&ship.id, WorldTransform::new(20.0, 0.0, 0.0)).unwrap();
+    /// world.propagate_transforms();
+    /// assert_eq!(world.world_object(&turret.id).unwrap().transform.x, 21.0);
+    /// ```
+    pub fn attach_child(&mut self, parent: Entity, child: Entity) -> Result<()> {
+        if parent == child {
+            return Err(KituError::InvalidInput("entity cannot be its own parent"));
+        }
+        if !self.contains_entity(parent) || !self.contains_entity(child) {
+            return Err(KituError::InvalidInput("unknown entity"));
+        }
+        if self.ancestors(parent).contains(&child) {
+            return Err(KituError::InvalidInput(
+                "attaching would create a hierarchy cycle",
+            ));
+        }
+        if self.parent_of(child) == Some(parent) {
+            return Ok(());
+        }
+
+        self.unlink_from_parent(child);
+        let local = match (
+            self.get_component::<WorldTransform>(parent),
+            self.get_component::<WorldTransform>(child),
+        ) {
+            (Some(parent_world), Some(child_world)) => child_world.relative_to(parent_world),
+            _ => self
+                .get_component::<LocalTransform>(child)
+                .copied()
+                .unwrap_or(LocalTransform::IDENTITY),
+        };
+        self.link_child(parent, child, local);
+        Ok(())
+    }
+
+    /// Detaches `child` from its parent, keeping its current world transform.
+    ///
+    /// The child becomes a root and its [`LocalTransform`] is removed. Returns `false` when the
+    /// entity had no parent.
+    pub fn detach_child(&mut self, child: Entity) -> bool {
+        if !self.unlink_from_parent(child) {
+            return false;
+        }
+        self.component_store.remove::<LocalTransform>(child);
+        true
+    }
+
+    /// Returns the parent of an entity, if it has one.
+    pub fn parent_of(&self, entity: Entity) -> Option<Entity> {
+        self.get_component::<Parent>(entity).map(Parent::get)
+    }
+
+    /// Returns the direct children of an entity in attach order.
+    pub fn children_of(&self, entity: Entity) -> &[Entity] {
+        self.get_component::<Children>(entity)
+            .map_or(&[], Children::as_slice)
+    }
+
+    /// Returns `entity` followed by all of its descendants in depth-first pre-order.
+    pub fn descendants(&self, entity: Entity) -> Vec<Entity> {
+        if !self.contains_entity(entity) {
+            return Vec::new();
+        }
+        let mut ordered = Vec::new();
+        let mut stack = vec![entity];
+        while let Some(next) = stack.pop() {
+            ordered.push(next);
+            stack.extend(self.children_of(next).iter().rev());
+        }
+        ordered
+    }
+
+    /// Recomputes world transforms from local transforms, parents before children.
+    ///
+    /// Only transforms whose value actually differs are written, so change detection reports
+    /// exactly the entities that moved. Children of parents without a `WorldTransform` are placed
+    /// relative to the world origin.
+    pub fn propagate_transforms(&mut self) {
+        self.flush_spatial_index();
+        let roots: Vec<Entity> = self
+            .entities
+            .iter()
+            .filter(|entity| self.get_component::<Parent>(*entity).is_none())
+            .filter(|entity| {
+                self.get_component::<LocalTransform>(*entity).is_some()
+                    || self.get_component::<Children>(*entity).is_some()
+            })
+            .collect();
+
+        let mut stack = Vec::new();
+        for root in roots {
+            let root_world = match self.get_component::<LocalTransform>(root).copied() {
+                Some(local) => {
+                    let world = WorldTransform::from(local);
+                    self.write_world_transform(root, world);
+                    world
+                }
+                None => self
+                    .get_component::<WorldTransform>(root)
+                    .copied()
+                    .unwrap_or(WorldTransform::new(0.0, 0.0, 0.0)),
+            };
+            stack.extend(
+                self.children_of(root)
+                    .iter()
+                    .map(|child| (*child, root_world)),
+            );
+
+            while let Some((entity, parent_world)) = stack.pop() {
+                let local = self
+                    .get_component::<LocalTransform>(entity)
+                    .copied()
+                    .unwrap_or(LocalTransform::IDENTITY);
+                let world = parent_world.mul_local(&local);
+                self.write_world_transform(entity, world);
+                stack.extend(self.children_of(entity).iter().map(|child| (*child, world)));
+            }
+        }
+    }
+
+    /// Despawns an entity together with all of its descendants.
+    ///
+    /// Returns the despawned entities root first, in depth-first pre-order, or an empty list when
+    /// the handle is stale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_ecs::EcsWorld;
+    ///
+    /// let mut world = EcsWorld::new();
+    /// let root = world.spawn_entity();
+    /// let child = world.spawn_entity();
+    /// let grandchild = world.spawn_entity();
+    /// world.attach_child(root, child).unwrap();
+    /// world.attach_child(child, grandchild).unwrap();
+    ///
+    /// assert_eq!(world.despawn_recursive(root), vec![root, child, grandchild]);
+    /// assert!(world.entities().is_empty());
+    /// ```
+    pub fn despawn_recursive(&mut self, entity: Entity) -> Vec<Entity> {
+        let subtree = self.descendants(entity);
+        for despawned in &subtree {
+            self.despawn_entity(*despawned);
+        }
+        subtree
+    }
+
+    /// Points the entity at a new world transform by rewriting its local transform when it is
+    /// driven by propagation. Returns whether a local transform was updated.
+    pub(crate) fn sync_local_to_world(&mut self, entity: Entity, world: &WorldTransform) -> bool {
+        if self.get_component::<LocalTransform>(entity).is_none() {
+            return false;
+        }
+        let local = match self.parent_of(entity) {
+            Some(parent) => match self.get_component::<WorldTransform>(parent) {
+                Some(parent_world) => world.relative_to(parent_world),
+                None => LocalTransform::from(*world),
+            },
+            None => LocalTransform::from(*world),
+        };
+        self.set_local_transform(entity, local);
+        true
+    }
+
+    /// Removes the entity from the hierarchy before it is despawned.
+    ///
+    /// Its children become roots that keep their last world transform.
+    pub(crate) fn unlink_hierarchy(&mut self, entity: Entity) {
+        self.unlink_from_parent(entity);
+        if let Some(children) = self.component_store.remove::<Children>(entity) {
+            for child in children.0 {
+                self.component_store.remove::<Parent>(child);
+                self.component_store.remove::<LocalTransform>(child);
+            }
+        }
+    }
+
+    /// Links a detached `child` under `parent` with the given local transform, appending it to
+    /// the parent's children. Callers check liveness and cycles.
+    pub(crate) fn link_child(&mut self, parent: Entity, child: Entity, local: LocalTransform) {
+        self.set_local_transform(child, local);
+        self.component_store.insert(child, Parent(parent));
+        match self.component_store.get_mut::<Children>(parent) {
+            Some(children) => children.0.push(child),
+            None => {
+                self.component_store.insert(parent, Children(vec![child]));
+            }
+        }
+    }
+
+    fn unlink_from_parent(&mut self, child: Entity) -> bool {
+        let Some(Parent(parent)) = self.component_store.remove::<Parent>(child) else {
+            return false;
+        };
+        let now_empty = match self.component_store.get_mut::<Children>(parent) {
+            Some(children) => {
+                children.0.retain(|entity| *entity != child);
+                children.0.is_empty()
+            }
+            None => false,
+        };
+        if now_empty {
+            self.component_store.remove::<Children>(parent);
+        }
+        true
+    }
+
+    fn ancestors(&self, entity: Entity) -> Vec<Entity> {
+        let mut ancestors = Vec::new();
+        let mut current = entity;
+        while let Some(parent) = self.parent_of(current) {
+            ancestors.push(parent);
+            current = parent;
+        }
+        ancestors
+    }
+
+    fn set_local_transform(&mut self, entity: Entity, local: LocalTransform) {
+        if self.get_component::<LocalTransform>(entity) != Some(&local) {
+            self.component_store.insert(entity, local);
+        }
+    }
+
+    fn write_world_transform(&mut self, entity: Entity, world: WorldTransform) {
+        if self.get_component::<WorldTransform>(entity) != Some(&world) {
+            self.component_store.insert(entity, world);
+            self.index_world_transform(entity, &world);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use kitu_core::Tick;
+
+    use crate::{Quat, Vec3};
+
+    use super::*;
+
+    fn assert_near(actual: Vec3, expected: Vec3) {
+        let distance = actual - expected;
+        assert!(
+            distance.x.abs() < 1e-5 && distance.y.abs() < 1e-5 && distance.z.abs() < 1e-5,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn propagation_applies_parent_rotation_and_scale() {
+        let mut world = EcsWorld::new();
+        let parent = world.spawn_entity();
+        let child = world.spawn_entity();
+        let grandchild = world.spawn_entity();
+        let turn = Quat::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), FRAC_PI_2);
+        world
+            .insert_component(
+                parent,
+                LocalTransform::from_translation(1.0, 0.0, 0.0)
+                    .with_rotation(turn)
+                    .with_scale(Vec3::new(2.0, 2.0, 2.0)),
+            )
+            .unwrap();
+        world.attach_child(parent, child).unwrap();
+        world.attach_child(child, grandchild).unwrap();
+        world
+            .insert_component(child, LocalTransform::from_translation(1.0, 0.0, 0.0))
+            .unwrap();
+        world
+            .insert_component(grandchild, LocalTransform::from_translation(0.0, 0.0, 1.0))
+            .unwrap();
+
+        world.propagate_transforms();
+
+        let child_world = world.get_component::<WorldTransform>(child).unwrap();
+        assert_near(child_world.translation(), Vec3::new(1.0, 0.0, -2.0));
+        assert_near(child_world.scale, Vec3::new(2.0, 2.0, 2.0));
+        let grandchild_world = world.get_component::<WorldTransform>(grandchild).unwrap();
+        assert_near(grandchild_world.translation(), Vec3::new(3.0, 0.0, -2.0));
+        assert_near(
+            grandchild_world.rotation.rotate(Vec3::new(1.0, 0.0, 0.0)),
+            Vec3::new(0.0, 0.0, -1.0),
+        );
+    }
+
+    #[test]
+    fn attach_keeps_world_transform_and_rejects_cycles() {
+        let mut world = EcsWorld::new();
+        let root = world
+            .spawn_world_object("root", WorldTransform::new(5.0, 0.0, 0.0))
+            .unwrap();
+        let leaf = world
+            .spawn_world_object("leaf", WorldTransform::new(7.0, 1.0, 0.0))
+            .unwrap();
+        world.attach_child(root.entity, leaf.entity).unwrap();
+        world.propagate_transforms();
+
+        assert_eq!(
+            world.world_object(&leaf.id).unwrap().transform,
+            WorldTransform::new(7.0, 1.0, 0.0)
+        );
+        assert!(world.attach_child(leaf.entity, root.entity).is_err());
+        assert!(world.attach_child(leaf.entity, leaf.entity).is_err());
+        assert_eq!(world.children_of(root.entity), &[leaf.entity]);
+        assert_eq!(world.parent_of(leaf.entity), Some(root.entity));
+    }
+
+    #[test]
+    fn reattach_moves_child_between_parents() {
+        let mut world = EcsWorld::new();
+        let first = world.spawn_entity();
+        let second = world.spawn_entity();
+        let child = world.spawn_entity();
+        world.attach_child(first, child).unwrap();
+        world.attach_child(second, child).unwrap();
+
+        assert!(world.children_of(first).is_empty());
+        assert!(world.get_component::<Children>(first).is_none());
+        assert_eq!(world.children_of(second), &[child]);
+
+        assert!(world.detach_child(child));
+        assert!(!world.detach_child(child));
+        assert!(world.get_component::<LocalTransform>(child).is_none());
+    }
+
+    #[test]
+    fn despawning_a_parent_orphans_children_in_place() {
+        let mut world = EcsWorld::new();
+        let parent = world
+            .spawn_world_object("parent", WorldTransform::new(1.0, 0.0, 0.0))
+            .unwrap();
+        let child = world
+            .spawn_world_object("child", WorldTransform::new(2.0, 0.0, 0.0))
+            .unwrap();
+        world.attach_child(parent.entity, child.entity).unwrap();
+
+        assert!(world.despawn_entity(parent.entity));
+        world.propagate_transforms();
+
+        assert_eq!(world.parent_of(child.entity), None);
+        assert_eq!(
+            world.world_object(&child.id).unwrap().transform,
+            WorldTransform::new(2.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn propagation_only_marks_moved_children_changed() {
+        let mut world = EcsWorld::new();
+        let parent = world
+            .spawn_world_object("parent", WorldTransform::new(0.0, 0.0, 0.0))
+            .unwrap();
+        let child = world
+            .spawn_world_object("child", WorldTransform::new(1.0, 0.0, 0.0))
+            .unwrap();
+        let bystander = world
+            .spawn_world_object("bystander", WorldTransform::new(9.0, 0.0, 0.0))
+            .unwrap();
+        world.attach_child(parent.entity, child.entity).unwrap();
+        world.propagate_transforms();
+
+        let next = Tick::start().next();
+        world.set_change_tick(next);
+        world.propagate_transforms();
+        assert!(world.changed_since::<WorldTransform>(next).is_empty());
+
+        world
+            .move_world_object(&parent.id, WorldTransform::new(0.0, 0.0, 3.0))
+            .unwrap();
+        world.propagate_transforms();
+        assert_eq!(
+            world.changed_since::<WorldTransform>(next),
+            vec![parent.entity, child.entity]
+        );
+        assert_eq!(
+            world.world_object(&child.id).unwrap().transform,
+            WorldTransform::new(1.0, 0.0, 3.0)
+        );
+        assert_eq!(world.world_object(&bystander.id).unwrap().transform.x, 9.0);
+    }
+
+    #[test]
+    fn moving_a_child_updates_its_local_transform() {
+        let mut world = EcsWorld::new();
+        let parent = world
+            .spawn_world_object("parent", WorldTransform::new(4.0, 0.0, 0.0))
+            .unwrap();
+        let child = world
+            .spawn_world_object("child", WorldTransform::new(5.0, 0.0, 0.0))
+            .unwrap();
+        world.attach_child(parent.entity, child.entity).unwrap();
+
+        world
+            .move_world_object(&child.id, WorldTransform::new(4.0, 0.0, 2.0))
+            .unwrap();
+        world.propagate_transforms();
+
+        assert_eq!(
+            world.get_component::<LocalTransform>(child.entity),
+            Some(&LocalTransform::from_translation(0.0, 0.0, 2.0))
+        );
+        assert_eq!(
+            world.world_object(&child.id).unwrap().transform,
+            WorldTransform::new(4.0, 0.0, 2.0)
+        );
+    }
+}
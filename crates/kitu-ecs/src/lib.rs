@@ -9,58 +9,70 @@
 //! The runtime (`kitu-runtime`) drives this crate each tick, and transports surface events that
 //! systems can consume. See `doc/crates-overview.md` for the ECS' place in the overall loop.
 
-use std::collections::{HashMap, VecDeque};
+mod collision;
+mod component;
+mod delta;
+mod entity;
+mod event;
+mod hash;
+mod hierarchy;
+mod resource;
+mod schedule;
+mod snapshot;
+mod spatial;
+mod transform;
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+};
 
 use kitu_core::{KituError, Result, Tick};
 
+pub use collision::{Collider, ColliderShape, Contact, FixedContact};
+pub use component::{Component, ComponentQuery, ComponentTicks};
+use component::{ComponentStore, SerializableComponents};
+pub use delta::{WorldDelta, WorldObjectChange};
+use entity::EntityAllocator;
+pub use entity::{Entity, EntityAllocatorState};
+use event::EventChannels;
+pub use event::{Event, EventCursor};
+pub use hierarchy::{Children, Parent};
+pub use resource::Resource;
+use resource::Resources;
+use schedule::{Schedule, StageAnchor};
+pub use schedule::{
+    SystemSpec, DEFAULT_STAGES, STAGE_POST_SIMULATION, STAGE_PRE_INPUT, STAGE_RENDER_EXTRACT,
+    STAGE_SIMULATION,
+};
+pub use snapshot::{WorldObject, WorldSnapshot, WORLD_SNAPSHOT_SCHEMA_VERSION};
+use spatial::SpatialIndex;
+pub use spatial::DEFAULT_SPATIAL_CELL_SIZE;
+pub use transform::{LocalTransform, Quat, Vec3, WorldTransform};
+
 /// Represents a system that can be scheduled for a tick.
 pub trait System: Send + Sync + 'static {
     /// Executes the system for the given tick.
     fn run(&mut self, world: &mut EcsWorld, tick: Tick) -> Result<()>;
 }
 
-/// Position for an object in the authoritative world state.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct WorldTransform {
-    /// X coordinate in world space.
-    pub x: f32,
-    /// Y coordinate in world space.
-    pub y: f32,
-    /// Z coordinate in world space.
-    pub z: f32,
-}
-
-impl WorldTransform {
-    /// Creates a transform from world-space coordinates.
-    pub const fn new(x: f32, y: f32, z: f32) -> Self {
-        Self { x, y, z }
-    }
-}
-
-/// Object tracked by the ECS-backed world state.
-#[derive(Debug, Clone, PartialEq)]
-pub struct WorldObject {
-    /// Stable object identifier assigned by the ECS world.
-    pub id: String,
-    /// Application-level object category.
-    pub kind: String,
-    /// Current object transform.
-    pub transform: WorldTransform,
-}
-
-/// Serializable-style snapshot of the ECS-backed world state.
-#[derive(Debug, Clone, Default, PartialEq)]
-pub struct WorldSnapshot {
-    /// Objects currently present in the world.
-    pub objects: Vec<WorldObject>,
-}
+/// Application-level category component attached to every world object entity.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ObjectKind(pub String);
 
 /// Minimal world representation for registering components and systems.
 pub struct EcsWorld {
     components: Vec<String>,
-    scheduled: VecDeque<Box<dyn System>>,
-    next_world_object_id: u64,
-    world_objects: Vec<WorldObject>,
+    schedule: Schedule,
+    schedule_running: bool,
+    entities: EntityAllocator,
+    component_store: ComponentStore,
+    serializable_components: SerializableComponents,
+    named_entities: HashMap<String, Entity>,
+    entity_names: HashMap<Entity, String>,
+    resources: Resources,
+    events: EventChannels,
+    spatial: SpatialIndex,
 }
 
 impl Default for EcsWorld {
@@ -74,9 +86,16 @@ impl EcsWorld {
     pub fn new() -> Self {
         Self {
             components: Vec::new(),
-            scheduled: VecDeque::new(),
-            next_world_object_id: 1,
-            world_objects: Vec::new(),
+            schedule: Schedule::with_default_stages(),
+            schedule_running: false,
+            entities: EntityAllocator::default(),
+            component_store: ComponentStore::default(),
+            serializable_components: SerializableComponents::default(),
+            named_entities: HashMap::new(),
+            entity_names: HashMap::new(),
+            resources: Resources::default(),
+            events: EventChannels::default(),
+            spatial: SpatialIndex::default(),
         }
     }
 
@@ -90,15 +109,91 @@ impl EcsWorld {
         Ok(())
     }
 
-    /// Schedules a system to run on the next tick.
-    pub fn schedule_system<S: System>(&mut self, system: S) {
-        self.scheduled.push_back(Box::new(system));
+    /// Appends a new stage after all existing stages.
+    pub fn add_stage(&mut self, name: impl Into<String>) -> Result<()> {
+        self.ensure_schedule_idle()?;
+        self.schedule.insert_stage(name.into(), StageAnchor::End)
+    }
+
+    /// Inserts a new stage immediately before an existing stage.
+    pub fn add_stage_before(&mut self, name: impl Into<String>, anchor: &str) -> Result<()> {
+        self.ensure_schedule_idle()?;
+        self.schedule
+            .insert_stage(name.into(), StageAnchor::Before(anchor))
     }
 
-    /// Executes all scheduled systems in FIFO order and clears the queue.
+    /// Inserts a new stage immediately after an existing stage.
+    pub fn add_stage_after(&mut self, name: impl Into<String>, anchor: &str) -> Result<()> {
+        self.ensure_schedule_idle()?;
+        self.schedule
+            .insert_stage(name.into(), StageAnchor::After(anchor))
+    }
+
+    /// Returns stage names in execution order.
+    pub fn stages(&self) -> Vec<String> {
+        self.schedule.stage_names()
+    }
+
+    /// Registers a persistent system that runs on every dispatch.
+    ///
+    /// Unknown stages, unknown or cross-stage ordering targets, duplicate labels, and ordering
+    /// cycles are rejected with [`KituError::InvalidSchedule`] and leave the schedule unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_core::Tick;
+    /// use kitu_ecs::{EcsWorld, RecordingSystem, SystemSpec, STAGE_SIMULATION};
+    ///
+    /// let mut world = EcsWorld::new();
+    /// world
+    ///     .add_system(SystemSpec::new(STAGE_SIMULATION).label("record"), RecordingSystem::default())
+    ///     .unwrap();
+    /// assert!(world
+    ///     .add_system(SystemSpec::new("late"), RecordingSystem::default())
+    ///     .is_err());
+    /// world.dispatch(Tick::start()).unwrap();
+    /// ```
+    pub fn add_system<S: System>(&mut self, spec: SystemSpec, system: S) -> Result<()> {
+        self.ensure_schedule_idle()?;
+        self.schedule.add_system(spec, Box::new(system))
+    }
+
+    /// Registers an unlabeled persistent system at the end of the simulation stage.
+    pub fn schedule_system<S: System>(&mut self, system: S) -> Result<()> {
+        self.add_system(SystemSpec::new(STAGE_SIMULATION), system)
+    }
+
+    /// Returns the labels of a stage's systems in execution order, or `None` for unknown stages.
+    pub fn system_order(&self, stage: &str) -> Option<Vec<Option<String>>> {
+        self.schedule.stage_labels(stage)
+    }
+
+    /// Executes every registered system, stage by stage, for the given tick.
+    ///
+    /// Systems stay registered after dispatch. The first system error aborts the remaining
+    /// systems for this tick and is returned to the caller. Event buffers rotate before any
+    /// system runs when `tick` differs from the previously dispatched tick, and component changes
+    /// made by the systems are stamped with `tick`.
     pub fn dispatch(&mut self, tick: Tick) -> Result<()> {
-        while let Some(mut system) = self.scheduled.pop_front() {
-            system.run(self, tick)?;
+        self.ensure_schedule_idle()?;
+        self.events.update(tick);
+        self.set_change_tick(tick);
+        self.flush_spatial_index();
+        let mut schedule = std::mem::take(&mut self.schedule);
+        self.schedule_running = true;
+        let result = schedule.run(self, tick);
+        self.schedule_running = false;
+        self.schedule = schedule;
+        self.flush_spatial_index();
+        result
+    }
+
+    fn ensure_schedule_idle(&self) -> Result<()> {
+        if self.schedule_running {
+            return Err(KituError::InvalidSchedule(
+                "schedule cannot change while systems are running".to_string(),
+            ));
         }
         Ok(())
     }
@@ -108,18 +203,405 @@ impl EcsWorld {
         self.components.clone()
     }
 
+    /// Spawns an entity without components.
+    ///
+    /// Slots of despawned entities are reused under a new generation.
+    pub fn spawn_entity(&mut self) -> Entity {
+        self.entities.allocate()
+    }
+
+    /// Removes an entity together with all of its components and its stable name.
+    ///
+    /// Children of the entity are detached and keep their last world transform; use
+    /// [`despawn_recursive`](Self::despawn_recursive) to remove them too. Returns `false` when the
+    /// handle is stale or was never issued.
+    pub fn despawn_entity(&mut self, entity: Entity) -> bool {
+        if !self.contains_entity(entity) {
+            return false;
+        }
+        self.unlink_hierarchy(entity);
+        self.entities.free(entity);
+        self.component_store.remove_entity(entity);
+        self.spatial.remove(entity);
+        if let Some(name) = self.entity_names.remove(&entity) {
+            self.named_entities.remove(&name);
+        }
+        true
+    }
+
+    /// Returns whether the handle refers to a currently alive entity.
+    pub fn contains_entity(&self, entity: Entity) -> bool {
+        self.entities.is_alive(entity)
+    }
+
+    /// Returns all alive entities in ascending slot order.
+    pub fn entities(&self) -> Vec<Entity> {
+        self.entities.iter().collect()
+    }
+
+    /// Assigns a unique stable name to an alive entity.
+    ///
+    /// Names survive for the lifetime of the entity and let callers keep their own ids (such as
+    /// `player:local`) instead of holding generational handles. Names shaped like a handle, e.g.
+    /// `1v0`, are rejected, since they would shadow the entity that handle refers to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_ecs::EcsWorld;
+    ///
+    /// let mut world = EcsWorld::new();
+    /// let entity = world.spawn_entity();
+    /// world.set_entity_name(entity, "player:local").unwrap();
+    /// assert_eq!(world.entity_by_name("player:local"), Some(entity));
+    /// assert_eq!(world.entity_name(entity), Some("player:local"));
+    /// ```
+    pub fn set_entity_name(&mut self, entity: Entity, name: impl Into<String>) -> Result<()> {
+        let name = name.into();
+        if name.is_empty() {
+            return Err(KituError::InvalidInput("entity name cannot be empty"));
+        }
+        if name.parse::<Entity>().is_ok() {
+            return Err(KituError::InvalidInput(
+                "entity name cannot look like an entity handle",
+            ));
+        }
+        if !self.contains_entity(entity) {
+            return Err(KituError::InvalidInput("unknown entity"));
+        }
+        if self.named_entities.contains_key(&name) {
+            return Err(KituError::InvalidInput("entity name already exists"));
+        }
+        if let Some(previous) = self.entity_names.insert(entity, name.clone()) {
+            self.named_entities.remove(&previous);
+        }
+        self.named_entities.insert(name, entity);
+        Ok(())
+    }
+
+    /// Returns the entity registered under a stable name.
+    pub fn entity_by_name(&self, name: &str) -> Option<Entity> {
+        self.named_entities.get(name).copied()
+    }
+
+    /// Returns the stable name of an entity, if it has one.
+    pub fn entity_name(&self, entity: Entity) -> Option<&str> {
+        self.entity_names.get(&entity).map(String::as_str)
+    }
+
+    /// Inserts or replaces a component on an alive entity, returning the previous value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_ecs::EcsWorld;
+    ///
+    /// struct Health(u32);
+    ///
+    /// let mut world = EcsWorld::new();
+    /// let entity = world.spawn_entity();
+    /// world.insert_component(entity, Health(10)).unwrap();
+    /// assert_eq!(world.get_component::<Health>(entity).map(|health| health.0), Some(10));
+    /// ```
+    pub fn insert_component<C: Component>(
+        &mut self,
+        entity: Entity,
+        component: C,
+    ) -> Result<Option<C>> {
+        if !self.contains_entity(entity) {
+            return Err(KituError::InvalidInput("unknown entity"));
+        }
+        if let Some(transform) = (&component as &dyn Any).downcast_ref::<WorldTransform>() {
+            self.index_world_transform(entity, transform);
+        }
+        Ok(self.component_store.insert(entity, component))
+    }
+
+    /// Returns a component of an entity, if present.
+    pub fn get_component<C: Component>(&self, entity: Entity) -> Option<&C> {
+        self.component_store.get(entity)
+    }
+
+    /// Returns a mutable component of an entity, if present.
+    pub fn get_component_mut<C: Component>(&mut self, entity: Entity) -> Option<&mut C> {
+        let component = self.component_store.get_mut(entity)?;
+        if TypeId::of::<C>() == TypeId::of::<WorldTransform>() {
+            self.spatial.mark_dirty(entity);
+        }
+        Some(component)
+    }
+
+    /// Removes a component from an entity and returns it.
+    pub fn remove_component<C: Component>(&mut self, entity: Entity) -> Option<C> {
+        let removed = self.component_store.remove(entity)?;
+        if TypeId::of::<C>() == TypeId::of::<WorldTransform>() {
+            self.spatial.remove(entity);
+        }
+        Some(removed)
+    }
+
+    /// Registers a component type whose values are serialized into
+    /// [`WorldSnapshot::components`] under `key`.
+    ///
+    /// Each key maps to one component type and each type to one key; registering the same pair
+    /// again is a no-op. Registrations survive [`restore_world_snapshot`](Self::restore_world_snapshot).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_ecs::{EcsWorld, WorldTransform};
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = EcsWorld::new();
+    /// world.register_serializable_component::<Health>("health").unwrap();
+    /// let object = world.spawn_world_object("enemy", WorldTransform::new(0.0, 0.0, 0.0)).unwrap();
+    /// world.insert_component(object.entity, Health(10)).unwrap();
+    ///
+    /// let saved = world.world_snapshot().unwrap();
+    /// world.remove_component::<Health>(object.entity);
+    /// world.restore_world_snapshot(&saved).unwrap();
+    /// assert_eq!(world.get_component::<Health>(object.entity).map(|health| health.0), Some(10));
+    /// ```
+    pub fn register_serializable_component<C>(&mut self, key: impl Into<String>) -> Result<()>
+    where
+        C: Component + serde::Serialize + serde::de::DeserializeOwned,
+    {
+        self.serializable_components.register::<C>(key.into())
+    }
+
+    /// Returns whether some entity holds a component that [`world_snapshot`](Self::world_snapshot)
+    /// does not capture, i.e. one that is neither built in nor registered through
+    /// [`register_serializable_component`](Self::register_serializable_component).
+    ///
+    /// Such components are lost by [`restore_world_snapshot`](Self::restore_world_snapshot).
+    pub fn has_unsnapshotted_components(&self) -> bool {
+        let built_in = [
+            TypeId::of::<ObjectKind>(),
+            TypeId::of::<WorldTransform>(),
+            TypeId::of::<LocalTransform>(),
+            TypeId::of::<Collider>(),
+            TypeId::of::<Parent>(),
+            TypeId::of::<Children>(),
+        ];
+        self.component_store.occupied_types().any(|type_id| {
+            !built_in.contains(&type_id) && !self.serializable_components.captures(type_id)
+        })
+    }
+
+    /// Returns the tick stamped on component inserts, mutable accesses and removals.
+    pub fn change_tick(&self) -> Tick {
+        self.component_store.change_tick()
+    }
+
+    /// Sets the tick stamped on later component changes.
+    ///
+    /// [`dispatch`](Self::dispatch) sets it to the dispatched tick. Hosts that mutate the world
+    /// between ticks should set it to the upcoming tick so those changes are picked up by that
+    /// tick's change queries. Removal records older than the previous tick are discarded.
+    pub fn set_change_tick(&mut self, tick: Tick) {
+        self.component_store.set_change_tick(tick);
+    }
+
+    /// Returns the added/changed ticks of an entity's component, if present.
+    pub fn component_ticks<C: Component>(&self, entity: Entity) -> Option<ComponentTicks> {
+        self.component_store.ticks::<C>(entity)
+    }
+
+    /// Returns entities whose `C` component was inserted at or after `since`, in slot order.
+    pub fn added_since<C: Component>(&self, since: Tick) -> Vec<Entity> {
+        self.component_store.added_since::<C>(since)
+    }
+
+    /// Returns entities whose `C` component was inserted or mutably accessed at or after `since`,
+    /// in slot order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_core::Tick;
+    /// use kitu_ecs::{EcsWorld, WorldTransform};
+    ///
+    /// let mut world = EcsWorld::new();
+    /// let still = world.spawn_world_object("rock", WorldTransform::new(0.0, 0.0, 0.0)).unwrap();
+    /// let moving = world.spawn_world_object("enemy", WorldTransform::new(0.0, 0.0, 0.0)).unwrap();
+    ///
+    /// let next = Tick::start().next();
+    /// world.set_change_tick(next);
+    /// world.move_world_object(&moving.id, WorldTransform::new(1.0, 0.0, 0.0)).unwrap();
+    ///
+    /// assert_eq!(world.changed_since::<WorldTransform>(next), vec![moving.entity]);
+    /// assert_eq!(
+    ///     world.changed_since::<WorldTransform>(Tick::start()),
+    ///     vec![still.entity, moving.entity]
+    /// );
+    /// ```
+    pub fn changed_since<C: Component>(&self, since: Tick) -> Vec<Entity> {
+        self.component_store.changed_since::<C>(since)
+    }
+
+    /// Returns entities that lost their `C` component (or were despawned) at or after `since`.
+    ///
+    /// Only removals from the current and previous change tick are retained.
+    pub fn removed_since<C: Component>(&self, since: Tick) -> Vec<Entity> {
+        self.component_store.removed_since::<C>(since)
+    }
+
+    /// Iterates entities that have every component in `Q`, in ascending slot order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_ecs::{EcsWorld, WorldTransform};
+    ///
+    /// struct Velocity(f32);
+    ///
+    /// let mut world = EcsWorld::new();
+    /// let moving = world
+    ///     .spawn_world_object("enemy", WorldTransform::new(0.0, 0.0, 0.0))
+    ///     .unwrap();
+    /// world
+    ///     .spawn_world_object("rock", WorldTransform::new(1.0, 0.0, 0.0))
+    ///     .unwrap();
+    /// world.insert_component(moving.entity, Velocity(2.0)).unwrap();
+    ///
+    /// let matches: Vec<_> = world.query::<(WorldTransform, Velocity)>().collect();
+    /// assert_eq!(matches.len(), 1);
+    /// assert_eq!(matches[0].0, moving.entity);
+    /// assert_eq!((matches[0].1).1 .0, 2.0);
+    /// ```
+    pub fn query<Q: ComponentQuery>(&self) -> impl Iterator<Item = (Entity, Q::Item<'_>)> + '_ {
+        self.entities
+            .iter()
+            .filter_map(move |entity| Q::fetch(self, entity).map(|item| (entity, item)))
+    }
+
+    /// Collects entities that have every component in `Q`, so callers can mutate while iterating.
+    pub fn query_entities<Q: ComponentQuery>(&self) -> Vec<Entity> {
+        self.query::<Q>().map(|(entity, _)| entity).collect()
+    }
+
+    /// Inserts or replaces the world-global resource of type `R`, returning the previous value.
+    ///
+    /// Resources are readable and writable from [`System::run`] through the world reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_ecs::EcsWorld;
+    ///
+    /// struct Gravity(f32);
+    ///
+    /// let mut world = EcsWorld::new();
+    /// world.insert_resource(Gravity(-9.8));
+    /// world.get_resource_mut::<Gravity>().unwrap().0 = -1.6;
+    /// assert_eq!(world.get_resource::<Gravity>().map(|gravity| gravity.0), Some(-1.6));
+    /// ```
+    pub fn insert_resource<R: Resource>(&mut self, resource: R) -> Option<R> {
+        self.resources.insert(resource)
+    }
+
+    /// Inserts a resource that is serialized into [`WorldSnapshot::resources`] under `key`.
+    ///
+    /// Each key maps to one resource type and each type to one key. Values that cannot be
+    /// serialized to JSON are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_ecs::EcsWorld;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Score(u32);
+    ///
+    /// let mut world = EcsWorld::new();
+    /// world.insert_serializable_resource("score", Score(3)).unwrap();
+    /// assert_eq!(world.world_snapshot().unwrap().resources["score"], serde_json::json!(3));
+    /// ```
+    pub fn insert_serializable_resource<R>(
+        &mut self,
+        key: impl Into<String>,
+        resource: R,
+    ) -> Result<Option<R>>
+    where
+        R: Resource + serde::Serialize + serde::de::DeserializeOwned,
+    {
+        self.resources.insert_serializable(key.into(), resource)
+    }
+
+    /// Returns the resource of type `R`, if present.
+    pub fn get_resource<R: Resource>(&self) -> Option<&R> {
+        self.resources.get()
+    }
+
+    /// Returns the mutable resource of type `R`, if present.
+    pub fn get_resource_mut<R: Resource>(&mut self) -> Option<&mut R> {
+        self.resources.get_mut()
+    }
+
+    /// Removes the resource of type `R` and its snapshot key, returning the value.
+    pub fn remove_resource<R: Resource>(&mut self) -> Option<R> {
+        self.resources.remove()
+    }
+
+    /// Returns whether a resource of type `R` is present.
+    pub fn contains_resource<R: Resource>(&self) -> bool {
+        self.get_resource::<R>().is_some()
+    }
+
+    /// Sends an event that later systems can read during this tick and the next one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_core::Tick;
+    /// use kitu_ecs::{EcsWorld, EventCursor};
+    ///
+    /// struct DamageDealt(u32);
+    ///
+    /// let mut world = EcsWorld::new();
+    /// let mut cursor = EventCursor::<DamageDealt>::default();
+    /// world.dispatch(Tick::start()).unwrap();
+    /// world.send_event(DamageDealt(3));
+    ///
+    /// let damage: Vec<u32> = world.read_events(&mut cursor).iter().map(|event| event.0).collect();
+    /// assert_eq!(damage, vec![3]);
+    /// assert!(world.read_events(&mut cursor).is_empty());
+    /// ```
+    pub fn send_event<E: Event>(&mut self, event: E) {
+        self.events.send(event);
+    }
+
+    /// Returns events of type `E` not yet seen by `cursor`, oldest first, and advances the cursor.
+    pub fn read_events<E: Event>(&self, cursor: &mut EventCursor<E>) -> Vec<&E> {
+        self.events.read(cursor)
+    }
+
+    /// Iterates every live event of type `E` (previous tick, then current tick) in send order.
+    pub fn iter_events<E: Event>(&self) -> impl Iterator<Item = &E> {
+        self.events.iter()
+    }
+
     /// Spawns an object into the authoritative world state.
+    ///
+    /// The returned object's `id` is its entity handle, e.g. `0v0`.
     pub fn spawn_world_object(
         &mut self,
         kind: impl Into<String>,
         transform: WorldTransform,
     ) -> Result<WorldObject> {
-        let id = format!("obj-{}", self.next_world_object_id);
-        self.next_world_object_id += 1;
-        self.insert_world_object(id, kind, transform)
+        let kind = validated_kind(kind)?;
+        let entity = self.spawn_entity();
+        self.insert_world_object_components(entity, kind, transform)
     }
 
     /// Spawns an object with a caller-owned stable identifier.
+    ///
+    /// The id follows the rules of [`set_entity_name`](Self::set_entity_name): it must be
+    /// non-empty, unused and not shaped like an entity handle.
     pub fn spawn_world_object_with_id(
         &mut self,
         id: impl Into<String>,
@@ -130,69 +612,203 @@ impl EcsWorld {
         if id.is_empty() {
             return Err(KituError::InvalidInput("world object id cannot be empty"));
         }
-        if self.world_object(&id).is_some() {
-            return Err(KituError::InvalidInput("world object id already exists"));
+        if id.parse::<Entity>().is_ok() {
+            return Err(KituError::InvalidInput(
+                "world object id cannot look like an entity handle",
+            ));
         }
-        if let Some(number) = id
-            .strip_prefix("obj-")
-            .and_then(|suffix| suffix.parse::<u64>().ok())
-        {
-            self.next_world_object_id = self.next_world_object_id.max(number + 1);
+        if self.named_entities.contains_key(&id) {
+            return Err(KituError::InvalidInput("world object id already exists"));
         }
-        self.insert_world_object(id, kind, transform)
+        let kind = validated_kind(kind)?;
+
+        let entity = self.spawn_entity();
+        self.set_entity_name(entity, id)?;
+        self.insert_world_object_components(entity, kind, transform)
     }
 
-    fn insert_world_object(
+    fn insert_world_object_components(
         &mut self,
-        id: String,
-        kind: impl Into<String>,
+        entity: Entity,
+        kind: String,
         transform: WorldTransform,
     ) -> Result<WorldObject> {
-        let kind = kind.into();
-        if kind.is_empty() {
-            return Err(KituError::InvalidInput("world object kind cannot be empty"));
-        }
-
-        let object = WorldObject {
-            id,
-            kind,
-            transform,
-        };
-        self.world_objects.push(object.clone());
-        Ok(object)
+        self.component_store.insert(entity, ObjectKind(kind));
+        self.component_store.insert(entity, transform);
+        self.index_world_transform(entity, &transform);
+        self.world_object_for_entity(entity)
+            .ok_or(KituError::InvalidInput("unknown world object"))
     }
 
-    /// Moves an existing world object to an absolute transform.
+    /// Moves an existing world object to an absolute world transform.
+    ///
+    /// For objects inside a hierarchy the local transform is rewritten to match, so the move
+    /// survives the next [`propagate_transforms`](Self::propagate_transforms); the object's
+    /// children follow on that pass.
     pub fn move_world_object(
         &mut self,
         id: &str,
         transform: WorldTransform,
     ) -> Result<WorldObject> {
-        let object = self
-            .world_objects
-            .iter_mut()
-            .find(|object| object.id == id)
+        let entity = self
+            .world_object_entity(id)
             .ok_or(KituError::InvalidInput("unknown world object"))?;
-        object.transform = transform;
-        Ok(object.clone())
+        if !self.set_world_transform(entity, transform) {
+            return Err(KituError::InvalidInput("world object has no transform"));
+        }
+        self.world_object_for_entity(entity)
+            .ok_or(KituError::InvalidInput("unknown world object"))
+    }
+
+    /// Overwrites an existing world transform, keeping the spatial index and any local transform
+    /// in sync. Returns `false` when the entity has no world transform.
+    pub(crate) fn set_world_transform(
+        &mut self,
+        entity: Entity,
+        transform: WorldTransform,
+    ) -> bool {
+        let Some(current) = self.component_store.get_mut::<WorldTransform>(entity) else {
+            return false;
+        };
+        *current = transform;
+        self.index_world_transform(entity, &transform);
+        self.sync_local_to_world(entity, &transform);
+        true
     }
 
     /// Returns a single object by id.
-    pub fn world_object(&self, id: &str) -> Option<&WorldObject> {
-        self.world_objects.iter().find(|object| object.id == id)
+    pub fn world_object(&self, id: &str) -> Option<WorldObject> {
+        self.world_object_for_entity(self.world_object_entity(id)?)
+    }
+
+    /// Resolves a world object id to its entity.
+    ///
+    /// Stable names are checked first; otherwise the id is parsed as an entity handle, and stale
+    /// handles resolve to `None`. Names can never look like handles, so the two never collide.
+    pub fn world_object_entity(&self, id: &str) -> Option<Entity> {
+        let entity = match self.entity_by_name(id) {
+            Some(entity) => entity,
+            None => id.parse().ok()?,
+        };
+        self.world_object_for_entity(entity).map(|_| entity)
+    }
+
+    /// Returns the world object view of an entity, or `None` when it is not a world object.
+    pub fn world_object_for_entity(&self, entity: Entity) -> Option<WorldObject> {
+        if !self.contains_entity(entity) {
+            return None;
+        }
+        let kind = self.component_store.get::<ObjectKind>(entity)?;
+        let transform = self.component_store.get::<WorldTransform>(entity)?;
+        Some(WorldObject {
+            entity,
+            id: self
+                .entity_name(entity)
+                .map_or_else(|| entity.to_string(), str::to_string),
+            named: self.entity_names.contains_key(&entity),
+            kind: kind.0.clone(),
+            transform: *transform,
+            parent: self.parent_of(entity),
+            local: self.component_store.get::<LocalTransform>(entity).copied(),
+            collider: self.component_store.get::<Collider>(entity).copied(),
+        })
+    }
+
+    /// Returns every world object in slot order, as listed in [`WorldSnapshot::objects`].
+    pub fn world_objects(&self) -> Vec<WorldObject> {
+        self.query_entities::<(ObjectKind, WorldTransform)>()
+            .into_iter()
+            .filter_map(|entity| self.world_object_for_entity(entity))
+            .collect()
+    }
+
+    /// Returns world objects whose transform or kind changed at or after `since`, in slot order.
+    pub fn world_objects_changed_since(&self, since: Tick) -> Vec<WorldObject> {
+        let mut entities = self.changed_since::<WorldTransform>(since);
+        entities.extend(self.changed_since::<ObjectKind>(since));
+        entities.sort();
+        entities.dedup();
+        entities
+            .into_iter()
+            .filter_map(|entity| self.world_object_for_entity(entity))
+            .collect()
+    }
+
+    /// Removes a world object and its descendants, returning their last states root first.
+    ///
+    /// Descendants that are not world objects are despawned but not returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_ecs::{EcsWorld, WorldTransform};
+    ///
+    /// let mut world = EcsWorld::new();
+    /// let enemy = world
+    ///     .spawn_world_object("enemy", WorldTransform::new(0.0, 0.0, 0.0))
+    ///     .unwrap();
+    /// let weapon = world
+    ///     .spawn_world_object("weapon", WorldTransform::new(0.5, 0.0, 0.0))
+    ///     .unwrap();
+    /// world.attach_child(enemy.entity, weapon.entity).unwrap();
+    ///
+    /// let removed = world.despawn_world_object(&enemy.id).unwrap();
+    /// let ids: Vec<_> = removed.iter().map(|object| object.id.as_str()).collect();
+    /// assert_eq!(ids, [enemy.id.as_str(), weapon.id.as_str()]);
+    /// assert_eq!(removed[1].parent, Some(enemy.entity));
+    /// assert!(world.world_snapshot().unwrap().objects.is_empty());
+    /// ```
+    pub fn despawn_world_object(&mut self, id: &str) -> Result<Vec<WorldObject>> {
+        let entity = self
+            .world_object_entity(id)
+            .ok_or(KituError::InvalidInput("unknown world object"))?;
+        let removed = self
+            .descendants(entity)
+            .into_iter()
+            .filter_map(|entity| self.world_object_for_entity(entity))
+            .collect();
+        self.despawn_recursive(entity);
+        Ok(removed)
     }
 
     /// Removes all authoritative world objects.
+    ///
+    /// Entities that were never spawned as world objects are left untouched.
     pub fn reset_world_objects(&mut self) {
-        self.world_objects.clear();
+        for entity in self.query_entities::<(ObjectKind,)>() {
+            self.despawn_entity(entity);
+        }
     }
 
     /// Returns a stable snapshot of the authoritative world state.
-    pub fn world_snapshot(&self) -> WorldSnapshot {
-        WorldSnapshot {
-            objects: self.world_objects.clone(),
-        }
+    ///
+    /// The snapshot tick is the world's [`change_tick`](Self::change_tick), which the runtime
+    /// keeps at its current tick.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KituError::Serialization`] when a snapshot-marked resource or component no longer
+    /// serializes.
+    pub fn world_snapshot(&self) -> Result<WorldSnapshot> {
+        Ok(WorldSnapshot {
+            schema_version: WORLD_SNAPSHOT_SCHEMA_VERSION,
+            tick: self.change_tick().get(),
+            objects: self.world_objects(),
+            resources: self.resources.snapshot()?,
+            components: self
+                .serializable_components
+                .snapshot(&self.component_store)?,
+            entities: self.entities.state(),
+        })
+    }
+}
+
+fn validated_kind(kind: impl Into<String>) -> Result<String> {
+    let kind = kind.into();
+    if kind.is_empty() {
+        return Err(KituError::InvalidInput("world object kind cannot be empty"));
     }
+    Ok(kind)
 }
 
 /// Example system that simply records that it has run.
@@ -212,6 +828,10 @@ impl System for RecordingSystem {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::{
+        collections::BTreeMap,
+        sync::{Arc, Mutex},
+    };
 
     #[test]
     fn component_registration_rejects_duplicates() {
@@ -225,14 +845,145 @@ mod tests {
         );
     }
 
+    struct OrderProbe {
+        name: &'static str,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl System for OrderProbe {
+        fn run(&mut self, _world: &mut EcsWorld, tick: Tick) -> Result<()> {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{}@{}", self.name, tick.get()));
+            Ok(())
+        }
+    }
+
+    fn probe(name: &'static str, log: &Arc<Mutex<Vec<String>>>) -> OrderProbe {
+        OrderProbe {
+            name,
+            log: Arc::clone(log),
+        }
+    }
+
     #[test]
     fn scheduled_systems_execute_in_order() {
         let mut world = EcsWorld::default();
-        let system = RecordingSystem::default();
-        world.schedule_system(RecordingSystem::default());
-        world.schedule_system(system);
-        let tick = Tick::start().advance_by(2);
-        world.dispatch(tick).unwrap();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        world.schedule_system(probe("first", &log)).unwrap();
+        world.schedule_system(probe("second", &log)).unwrap();
+
+        world.dispatch(Tick::start()).unwrap();
+        world.dispatch(Tick::start().next()).unwrap();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["first@0", "second@0", "first@1", "second@1"]
+        );
+    }
+
+    #[test]
+    fn stages_run_in_declared_order_with_system_constraints() {
+        let mut world = EcsWorld::default();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        world.add_stage_before("network", STAGE_PRE_INPUT).unwrap();
+        world
+            .add_system(
+                SystemSpec::new(STAGE_RENDER_EXTRACT).label("extract"),
+                probe("extract", &log),
+            )
+            .unwrap();
+        world
+            .add_system(
+                SystemSpec::new(STAGE_SIMULATION).label("physics"),
+                probe("physics", &log),
+            )
+            .unwrap();
+        world
+            .add_system(
+                SystemSpec::new(STAGE_SIMULATION)
+                    .label("movement")
+                    .before("physics"),
+                probe("movement", &log),
+            )
+            .unwrap();
+        world
+            .add_system(SystemSpec::new("network"), probe("network", &log))
+            .unwrap();
+
+        assert_eq!(
+            world.stages(),
+            vec![
+                "network",
+                STAGE_PRE_INPUT,
+                STAGE_SIMULATION,
+                STAGE_POST_SIMULATION,
+                STAGE_RENDER_EXTRACT
+            ]
+        );
+        assert_eq!(
+            world.system_order(STAGE_SIMULATION),
+            Some(vec![
+                Some("movement".to_string()),
+                Some("physics".to_string())
+            ])
+        );
+
+        world.dispatch(Tick::start()).unwrap();
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["network@0", "movement@0", "physics@0", "extract@0"]
+        );
+    }
+
+    #[test]
+    fn invalid_schedule_changes_are_rejected_at_registration() {
+        let mut world = EcsWorld::default();
+        assert!(matches!(
+            world.add_system(SystemSpec::new("missing"), RecordingSystem::default()),
+            Err(KituError::InvalidSchedule(_))
+        ));
+        assert!(world.add_stage_after("late", "missing").is_err());
+        assert!(world.add_stage(STAGE_SIMULATION).is_err());
+
+        world
+            .add_system(
+                SystemSpec::new(STAGE_SIMULATION).label("a"),
+                RecordingSystem::default(),
+            )
+            .unwrap();
+        world
+            .add_system(
+                SystemSpec::new(STAGE_SIMULATION).label("b").after("a"),
+                RecordingSystem::default(),
+            )
+            .unwrap();
+        assert!(world
+            .add_system(
+                SystemSpec::new(STAGE_SIMULATION)
+                    .label("c")
+                    .after("b")
+                    .before("a"),
+                RecordingSystem::default(),
+            )
+            .is_err());
+        assert!(world
+            .add_system(
+                SystemSpec::new(STAGE_POST_SIMULATION).after("a"),
+                RecordingSystem::default(),
+            )
+            .is_err());
+        assert!(world
+            .add_system(
+                SystemSpec::new(STAGE_POST_SIMULATION).label("a"),
+                RecordingSystem::default(),
+            )
+            .is_err());
+        assert_eq!(
+            world.system_order(STAGE_SIMULATION),
+            Some(vec![Some("a".to_string()), Some("b".to_string())])
+        );
     }
 
     #[test]
@@ -252,18 +1003,408 @@ mod tests {
         let spawned = world
             .spawn_world_object("enemy", WorldTransform::new(1.0, 2.0, 3.0))
             .unwrap();
-        assert_eq!(spawned.id, "obj-1");
+        assert_eq!(spawned.id, "0v0");
+        assert_eq!(spawned.entity, world.world_object_entity("0v0").unwrap());
 
         let moved = world
             .move_world_object(&spawned.id, WorldTransform::new(4.0, 5.0, 6.0))
             .unwrap();
         assert_eq!(moved.transform, WorldTransform::new(4.0, 5.0, 6.0));
 
-        let snapshot = world.world_snapshot();
+        let snapshot = world.world_snapshot().unwrap();
         assert_eq!(snapshot.objects, vec![moved]);
 
         world.reset_world_objects();
-        assert!(world.world_snapshot().objects.is_empty());
+        assert!(world.world_snapshot().unwrap().objects.is_empty());
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Health(u32);
+
+    #[derive(Debug, PartialEq)]
+    struct Velocity(f32);
+
+    #[test]
+    fn components_insert_get_and_remove_by_type() {
+        let mut world = EcsWorld::new();
+        let entity = world.spawn_entity();
+
+        assert_eq!(world.insert_component(entity, Health(10)).unwrap(), None);
+        assert_eq!(
+            world.insert_component(entity, Health(7)).unwrap(),
+            Some(Health(10))
+        );
+        world.get_component_mut::<Health>(entity).unwrap().0 -= 2;
+        assert_eq!(world.get_component::<Health>(entity), Some(&Health(5)));
+        assert_eq!(world.get_component::<Velocity>(entity), None);
+
+        assert_eq!(world.remove_component::<Health>(entity), Some(Health(5)));
+        assert_eq!(world.get_component::<Health>(entity), None);
+
+        assert!(world.despawn_entity(entity));
+        assert!(!world.contains_entity(entity));
+        assert!(world.insert_component(entity, Health(1)).is_err());
+    }
+
+    #[test]
+    fn queries_match_entities_with_every_component() {
+        let mut world = EcsWorld::new();
+        let a = world.spawn_entity();
+        let b = world.spawn_entity();
+        let c = world.spawn_entity();
+        world.insert_component(a, Health(1)).unwrap();
+        world.insert_component(a, Velocity(1.0)).unwrap();
+        world.insert_component(b, Health(2)).unwrap();
+        world.insert_component(c, Velocity(3.0)).unwrap();
+        world.insert_component(c, Health(3)).unwrap();
+
+        let matched = world
+            .query::<(Health, Velocity)>()
+            .map(|(entity, (health, velocity))| (entity, health.0, velocity.0))
+            .collect::<Vec<_>>();
+        assert_eq!(matched, vec![(a, 1, 1.0), (c, 3, 3.0)]);
+        assert_eq!(world.query_entities::<(Health,)>(), vec![a, b, c]);
+        assert_eq!(world.entities(), vec![a, b, c]);
+
+        world.despawn_entity(a);
+        assert_eq!(world.query_entities::<(Velocity,)>(), vec![c]);
+    }
+
+    #[test]
+    fn world_objects_are_entities_with_kind_and_transform_components() {
+        let mut world = EcsWorld::new();
+        let object = world
+            .spawn_world_object("enemy", WorldTransform::new(1.0, 0.0, 0.0))
+            .unwrap();
+        let entity = object.entity;
+
+        assert_eq!(
+            world.get_component::<ObjectKind>(entity),
+            Some(&ObjectKind("enemy".to_string()))
+        );
+        world.insert_component(entity, Health(3)).unwrap();
+        assert_eq!(
+            world.query_entities::<(WorldTransform, Health)>(),
+            vec![entity]
+        );
+
+        world.reset_world_objects();
+        assert!(!world.contains_entity(entity));
+        assert!(world.world_object_entity(&object.id).is_none());
+    }
+
+    #[test]
+    fn despawn_removes_one_world_object_and_frees_its_name() {
+        let mut world = EcsWorld::new();
+        let player = world
+            .spawn_world_object_with_id(
+                "player:local",
+                "player",
+                WorldTransform::new(0.0, 0.0, 0.0),
+            )
+            .unwrap();
+        let enemy = world
+            .spawn_world_object("enemy", WorldTransform::new(1.0, 0.0, 0.0))
+            .unwrap();
+
+        assert_eq!(
+            world.despawn_world_object("player:local").unwrap(),
+            vec![player.clone()]
+        );
+        assert!(world.despawn_world_object("player:local").is_err());
+        assert!(!world.contains_entity(player.entity));
+        assert_eq!(world.world_snapshot().unwrap().objects, vec![enemy]);
+
+        let plain = world.spawn_entity();
+        assert!(world.despawn_world_object(&plain.to_string()).is_err());
+        assert!(world.contains_entity(plain));
+
+        world
+            .spawn_world_object_with_id(
+                "player:local",
+                "player",
+                WorldTransform::new(0.0, 0.0, 0.0),
+            )
+            .unwrap();
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Score(u32);
+
+    struct ScoreSystem;
+
+    impl System for ScoreSystem {
+        fn run(&mut self, world: &mut EcsWorld, tick: Tick) -> Result<()> {
+            let gained = world
+                .get_resource::<Health>()
+                .map_or(1, |bonus| bonus.0 + 1);
+            world
+                .get_resource_mut::<Score>()
+                .ok_or(KituError::InvalidInput("score resource missing"))?
+                .0 += gained * (tick.get() as u32 + 1);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn resources_are_typed_singletons_shared_with_systems() {
+        let mut world = EcsWorld::new();
+        assert!(!world.contains_resource::<Score>());
+        assert_eq!(world.insert_resource(Score(0)), None);
+        assert_eq!(world.insert_resource(Health(1)), None);
+        world
+            .add_system(SystemSpec::new(STAGE_SIMULATION), ScoreSystem)
+            .unwrap();
+
+        world.dispatch(Tick::start()).unwrap();
+        world.dispatch(Tick::start().next()).unwrap();
+        assert_eq!(world.get_resource::<Score>(), Some(&Score(6)));
+
+        assert_eq!(world.insert_resource(Score(10)), Some(Score(6)));
+        assert_eq!(world.remove_resource::<Score>(), Some(Score(10)));
+        assert!(world.dispatch(Tick::start()).is_err());
+    }
+
+    #[test]
+    fn serializable_resources_are_included_in_snapshots() {
+        let mut world = EcsWorld::new();
+        world.insert_resource(Health(5));
+        world
+            .insert_serializable_resource("score", Score(7))
+            .unwrap();
+        world.get_resource_mut::<Score>().unwrap().0 += 1;
+
+        let snapshot = world.world_snapshot().unwrap();
+        assert_eq!(
+            snapshot.resources,
+            BTreeMap::from([("score".to_string(), serde_json::json!(8))])
+        );
+
+        assert!(world
+            .insert_serializable_resource("score", vec![1_u8])
+            .is_err());
+        assert!(world
+            .insert_serializable_resource("points", Score(0))
+            .is_err());
+        assert!(world.insert_serializable_resource("", Score(0)).is_err());
+
+        world.remove_resource::<Score>();
+        assert!(world.world_snapshot().unwrap().resources.is_empty());
+    }
+
+    #[test]
+    fn registered_components_are_included_in_snapshots() {
+        let mut world = EcsWorld::new();
+        let entity = world.spawn_entity();
+        world.insert_component(entity, Health(5)).unwrap();
+        assert!(world.has_unsnapshotted_components());
+        world.remove_component::<Health>(entity);
+        assert!(!world.has_unsnapshotted_components());
+
+        world
+            .register_serializable_component::<Score>("score")
+            .unwrap();
+        world
+            .register_serializable_component::<Score>("score")
+            .unwrap();
+        world.insert_component(entity, Score(7)).unwrap();
+        assert!(!world.has_unsnapshotted_components());
+        assert_eq!(
+            world.world_snapshot().unwrap().components,
+            BTreeMap::from([(
+                "score".to_string(),
+                BTreeMap::from([(entity, serde_json::json!(7))])
+            )])
+        );
+
+        assert!(world
+            .register_serializable_component::<Vec<u8>>("score")
+            .is_err());
+        assert!(world
+            .register_serializable_component::<Score>("points")
+            .is_err());
+        assert!(world
+            .register_serializable_component::<Vec<u8>>("")
+            .is_err());
+
+        world.despawn_entity(entity);
+        assert!(world.world_snapshot().unwrap().components.is_empty());
+    }
+
+    #[test]
+    fn snapshot_fails_when_a_resource_stops_serializing() {
+        let mut world = EcsWorld::new();
+        world
+            .insert_serializable_resource("cells", BTreeMap::<(i32, i32), u8>::new())
+            .unwrap();
+        world
+            .get_resource_mut::<BTreeMap<(i32, i32), u8>>()
+            .unwrap()
+            .insert((1, 2), 3);
+
+        assert!(matches!(
+            world.world_snapshot(),
+            Err(KituError::Serialization(message)) if message.contains("cells")
+        ));
+        assert!(world.state_hash().is_err());
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct DamageDealt(u32);
+
+    struct DamageWriter;
+
+    impl System for DamageWriter {
+        fn run(&mut self, world: &mut EcsWorld, tick: Tick) -> Result<()> {
+            world.send_event(DamageDealt(tick.get() as u32 * 10));
+            Ok(())
+        }
+    }
+
+    struct DamageReader {
+        cursor: EventCursor<DamageDealt>,
+        seen: Arc<Mutex<Vec<(u64, u32)>>>,
+    }
+
+    impl System for DamageReader {
+        fn run(&mut self, world: &mut EcsWorld, tick: Tick) -> Result<()> {
+            let events = world
+                .read_events(&mut self.cursor)
+                .into_iter()
+                .map(|event| (tick.get(), event.0))
+                .collect::<Vec<_>>();
+            self.seen.lock().unwrap().extend(events);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn events_reach_later_systems_this_tick_and_any_system_next_tick() {
+        let mut world = EcsWorld::new();
+        let early = Arc::new(Mutex::new(Vec::new()));
+        let late = Arc::new(Mutex::new(Vec::new()));
+        world
+            .add_system(
+                SystemSpec::new(STAGE_PRE_INPUT),
+                DamageReader {
+                    cursor: EventCursor::default(),
+                    seen: early.clone(),
+                },
+            )
+            .unwrap();
+        world
+            .add_system(SystemSpec::new(STAGE_SIMULATION), DamageWriter)
+            .unwrap();
+        world
+            .add_system(
+                SystemSpec::new(STAGE_POST_SIMULATION),
+                DamageReader {
+                    cursor: EventCursor::default(),
+                    seen: late.clone(),
+                },
+            )
+            .unwrap();
+
+        let mut tick = Tick::start();
+        for _ in 0..3 {
+            tick = tick.next();
+            world.dispatch(tick).unwrap();
+        }
+
+        assert_eq!(*late.lock().unwrap(), vec![(1, 10), (2, 20), (3, 30)]);
+        assert_eq!(*early.lock().unwrap(), vec![(2, 10), (3, 20)]);
+        assert_eq!(
+            world
+                .iter_events::<DamageDealt>()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![DamageDealt(20), DamageDealt(30)]
+        );
+    }
+
+    #[test]
+    fn event_buffers_rotate_once_per_tick_and_expire_after_gaps() {
+        let mut world = EcsWorld::new();
+        let tick = Tick::start();
+        world.send_event(DamageDealt(1));
+        world.dispatch(tick).unwrap();
+        world.dispatch(tick).unwrap();
+        world.send_event(DamageDealt(2));
+        world.dispatch(tick.next()).unwrap();
+        assert_eq!(
+            world
+                .iter_events::<DamageDealt>()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![DamageDealt(1), DamageDealt(2)]
+        );
+
+        world.dispatch(tick.next().next()).unwrap();
+        assert!(world.iter_events::<DamageDealt>().next().is_none());
+
+        world.send_event(DamageDealt(3));
+        world.dispatch(tick.advance_by(10)).unwrap();
+        assert!(world.iter_events::<DamageDealt>().next().is_none());
+    }
+
+    #[test]
+    fn change_detection_tracks_added_changed_and_removed_per_tick() {
+        let mut world = EcsWorld::new();
+        let tick0 = Tick::start();
+        let tick1 = tick0.next();
+        let a = world.spawn_entity();
+        let b = world.spawn_entity();
+        world.insert_component(a, Health(1)).unwrap();
+        world.insert_component(b, Health(2)).unwrap();
+
+        world.dispatch(tick1).unwrap();
+        world.insert_component(a, Velocity(1.0)).unwrap();
+        world.get_component_mut::<Health>(b).unwrap().0 += 1;
+        let _ = world.get_component::<Health>(a);
+
+        assert_eq!(world.added_since::<Health>(tick0), vec![a, b]);
+        assert_eq!(world.added_since::<Health>(tick1), Vec::<Entity>::new());
+        assert_eq!(world.changed_since::<Health>(tick1), vec![b]);
+        assert_eq!(world.added_since::<Velocity>(tick1), vec![a]);
+        assert_eq!(
+            world.component_ticks::<Health>(b),
+            Some(ComponentTicks {
+                added: tick0,
+                changed: tick1,
+            })
+        );
+
+        let tick2 = tick1.next();
+        world.set_change_tick(tick2);
+        world.remove_component::<Health>(a);
+        world.despawn_entity(b);
+        assert_eq!(world.removed_since::<Health>(tick2), vec![a, b]);
+        assert_eq!(world.removed_since::<Velocity>(tick2), Vec::<Entity>::new());
+        assert!(world.changed_since::<Health>(tick2).is_empty());
+
+        world.set_change_tick(tick2.next().next());
+        assert!(world.removed_since::<Health>(tick0).is_empty());
+    }
+
+    #[test]
+    fn world_object_changes_are_reported_since_a_tick() {
+        let mut world = EcsWorld::new();
+        let enemy = world
+            .spawn_world_object("enemy", WorldTransform::new(0.0, 0.0, 0.0))
+            .unwrap();
+        world
+            .spawn_world_object("rock", WorldTransform::new(1.0, 0.0, 0.0))
+            .unwrap();
+
+        let tick1 = Tick::start().next();
+        world.set_change_tick(tick1);
+        assert!(world.world_objects_changed_since(tick1).is_empty());
+
+        let moved = world
+            .move_world_object(&enemy.id, WorldTransform::new(2.0, 0.0, 0.0))
+            .unwrap();
+        assert_eq!(world.world_objects_changed_since(tick1), vec![moved]);
+        assert_eq!(world.world_objects_changed_since(Tick::start()).len(), 2);
     }
 
     #[test]
@@ -291,6 +1432,68 @@ mod tests {
         let generated = world
             .spawn_world_object("enemy", WorldTransform::new(0.0, 0.0, 0.0))
             .unwrap();
-        assert_eq!(generated.id, "obj-1");
+        assert_eq!(generated.id, generated.entity.to_string());
+        assert_eq!(
+            world.world_object("player:local").unwrap().entity,
+            player.entity
+        );
+    }
+
+    #[test]
+    fn stale_entity_handles_are_detected_after_slot_reuse() {
+        let mut world = EcsWorld::new();
+        let first = world.spawn_entity();
+        world.insert_component(first, Health(1)).unwrap();
+        world.set_entity_name(first, "boss").unwrap();
+        assert!(world.despawn_entity(first));
+
+        let reused = world.spawn_entity();
+        assert_eq!(reused.index(), first.index());
+        assert_eq!(reused.generation(), first.generation() + 1);
+        assert!(!world.contains_entity(first));
+        assert!(!world.despawn_entity(first));
+        assert!(world.insert_component(first, Health(2)).is_err());
+        assert_eq!(world.get_component::<Health>(reused), None);
+        assert_eq!(world.entity_by_name("boss"), None);
+
+        world.insert_component(reused, Health(3)).unwrap();
+        assert_eq!(world.get_component::<Health>(first), None);
+        assert_eq!(world.get_component::<Health>(reused), Some(&Health(3)));
+        assert_eq!(Entity::from_bits(reused.to_bits()), reused);
+        assert_eq!(reused.to_string(), "0v1");
+        assert!("0x1".parse::<Entity>().is_err());
+    }
+
+    #[test]
+    fn handle_shaped_ids_cannot_shadow_generated_objects() {
+        let mut world = EcsWorld::new();
+        let enemy = world
+            .spawn_world_object("enemy", WorldTransform::new(0.0, 0.0, 0.0))
+            .unwrap();
+        world.reset_world_objects();
+
+        assert!(world.world_object(&enemy.id).is_none());
+        assert!(world
+            .move_world_object(&enemy.id, WorldTransform::new(1.0, 0.0, 0.0))
+            .is_err());
+
+        let plain = world.spawn_entity();
+        assert_eq!(plain.index(), enemy.entity.index());
+        assert!(world.world_object_entity(&plain.to_string()).is_none());
+        assert!(world.world_object(&enemy.id).is_none());
+
+        assert!(matches!(
+            world.spawn_world_object_with_id("1v0", "marker", WorldTransform::new(0.0, 0.0, 0.0)),
+            Err(KituError::InvalidInput(_))
+        ));
+        assert!(world.set_entity_name(plain, "1v0").is_err());
+        let generated = world
+            .spawn_world_object("enemy", WorldTransform::new(0.0, 0.0, 0.0))
+            .unwrap();
+        assert_eq!(generated.id, "1v0");
+        assert_eq!(world.world_object("1v0").unwrap(), generated);
+
+        world.set_entity_name(plain, "1v0:spawner").unwrap();
+        assert_eq!(world.entity_by_name("1v0:spawner"), Some(plain));
     }
 }
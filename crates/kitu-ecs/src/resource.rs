@@ -0,0 +1,167 @@
+//! Typed singleton resources stored on the world.
+//!
+//! Resources hold world-global state such as score, RNG streams, or game configuration. Each type
+//! has at most one value. Resources inserted with a snapshot key are serialized into
+//! [`WorldSnapshot::resources`](crate::WorldSnapshot::resources).
+
+use std::{
+    any::{Any, TypeId},
+    collections::{BTreeMap, HashMap},
+};
+
+use kitu_core::{KituError, Result};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+/// Marker for world-global data stored once per type.
+///
+/// Every `Send + Sync + 'static` type is a resource; no registration is required before insert.
+pub trait Resource: Send + Sync + 'static {}
+
+impl<T: Send + Sync + 'static> Resource for T {}
+
+type SerializeFn = fn(&dyn Any) -> serde_json::Result<Value>;
+type DeserializeFn = fn(Value) -> serde_json::Result<Box<dyn Any + Send + Sync>>;
+
+struct SerializableResource {
+    type_id: TypeId,
+    serialize: SerializeFn,
+    deserialize: DeserializeFn,
+}
+
+/// Resource values decoded from a snapshot, ready to be swapped in.
+pub(crate) struct RestoredResources(Vec<(TypeId, Box<dyn Any + Send + Sync>)>);
+
+/// Per-type resource values owned by the world.
+#[derive(Default)]
+pub(crate) struct Resources {
+    values: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+    serializable: BTreeMap<String, SerializableResource>,
+}
+
+impl Resources {
+    pub(crate) fn insert<R: Resource>(&mut self, resource: R) -> Option<R> {
+        self.values
+            .insert(TypeId::of::<R>(), Box::new(resource))
+            .and_then(|previous| previous.downcast::<R>().ok())
+            .map(|previous| *previous)
+    }
+
+    pub(crate) fn insert_serializable<R>(&mut self, key: String, resource: R) -> Result<Option<R>>
+    where
+        R: Resource + Serialize + DeserializeOwned,
+    {
+        if key.is_empty() {
+            return Err(KituError::InvalidInput("resource key cannot be empty"));
+        }
+        match self.serializable.get(&key) {
+            Some(existing) if existing.type_id != TypeId::of::<R>() => {
+                return Err(KituError::InvalidInput(
+                    "resource key is already used by another type",
+                ));
+            }
+            _ => {}
+        }
+        if self
+            .serializable
+            .iter()
+            .any(|(other, entry)| *other != key && entry.type_id == TypeId::of::<R>())
+        {
+            return Err(KituError::InvalidInput(
+                "resource type is already registered under another key",
+            ));
+        }
+        serde_json::to_value(&resource)
+            .map_err(|_| KituError::InvalidInput("resource must serialize to JSON"))?;
+
+        self.serializable.insert(
+            key,
+            SerializableResource {
+                type_id: TypeId::of::<R>(),
+                serialize: serialize_resource::<R>,
+                deserialize: deserialize_resource::<R>,
+            },
+        );
+        Ok(self.insert(resource))
+    }
+
+    pub(crate) fn get<R: Resource>(&self) -> Option<&R> {
+        self.values.get(&TypeId::of::<R>())?.downcast_ref()
+    }
+
+    pub(crate) fn get_mut<R: Resource>(&mut self) -> Option<&mut R> {
+        self.values.get_mut(&TypeId::of::<R>())?.downcast_mut()
+    }
+
+    pub(crate) fn remove<R: Resource>(&mut self) -> Option<R> {
+        let type_id = TypeId::of::<R>();
+        self.serializable
+            .retain(|_, entry| entry.type_id != type_id);
+        self.values
+            .remove(&type_id)
+            .and_then(|value| value.downcast::<R>().ok())
+            .map(|value| *value)
+    }
+
+    /// Serializes every snapshot-marked resource, keyed by its snapshot key.
+    ///
+    /// Fails with [`KituError::Serialization`] naming the key when a value no longer serializes
+    /// (for example a map that gained non-string keys).
+    pub(crate) fn snapshot(&self) -> Result<BTreeMap<String, Value>> {
+        self.serializable
+            .iter()
+            .filter_map(|(key, entry)| {
+                let value = self.values.get(&entry.type_id)?;
+                Some(
+                    (entry.serialize)(value.as_ref())
+                        .map(|value| (key.clone(), value))
+                        .map_err(|error| {
+                            KituError::Serialization(format!("resource `{key}`: {error}"))
+                        }),
+                )
+            })
+            .collect()
+    }
+
+    /// Decodes snapshot values for registered keys without touching the stored resources.
+    ///
+    /// Every key must have been registered with
+    /// [`insert_serializable`](Self::insert_serializable) so its type is known.
+    pub(crate) fn decode_snapshot(
+        &self,
+        values: &BTreeMap<String, Value>,
+    ) -> Result<RestoredResources> {
+        values
+            .iter()
+            .map(|(key, value)| {
+                let entry = self.serializable.get(key).ok_or_else(|| {
+                    KituError::Serialization(format!("resource `{key}` is not registered"))
+                })?;
+                let decoded = (entry.deserialize)(value.clone()).map_err(|error| {
+                    KituError::Serialization(format!("resource `{key}`: {error}"))
+                })?;
+                Ok((entry.type_id, decoded))
+            })
+            .collect::<Result<_>>()
+            .map(RestoredResources)
+    }
+
+    pub(crate) fn apply_restored(&mut self, restored: RestoredResources) {
+        for (type_id, value) in restored.0 {
+            self.values.insert(type_id, value);
+        }
+    }
+}
+
+fn serialize_resource<R: Resource + Serialize>(value: &dyn Any) -> serde_json::Result<Value> {
+    match value.downcast_ref::<R>() {
+        Some(value) => serde_json::to_value(value),
+        None => Ok(Value::Null),
+    }
+}
+
+fn deserialize_resource<R: Resource + DeserializeOwned>(
+    value: Value,
+) -> serde_json::Result<Box<dyn Any + Send + Sync>> {
+    Ok(Box::new(serde_json::from_value::<R>(value)?))
+}
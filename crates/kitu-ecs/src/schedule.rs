@@ -0,0 +1,296 @@
+//! Persistent system schedule grouped into ordered, named stages.
+//!
+//! Systems are registered once and run every dispatch. Stages run in their declared order, and
+//! systems inside a stage run in registration order unless `before`/`after` constraints say
+//! otherwise. Invalid schedules are rejected at registration time so dispatch stays infallible
+//! with respect to ordering.
+
+use kitu_core::{KituError, Result, Tick};
+
+use crate::{EcsWorld, System};
+
+/// Stage for work that must happen before runtime inputs are applied.
+pub const STAGE_PRE_INPUT: &str = "pre-input";
+/// Stage for authoritative gameplay simulation.
+pub const STAGE_SIMULATION: &str = "simulation";
+/// Stage for follow-up work that reacts to simulation results.
+pub const STAGE_POST_SIMULATION: &str = "post-simulation";
+/// Stage for extracting presentation state from the authoritative world.
+pub const STAGE_RENDER_EXTRACT: &str = "render-extract";
+
+/// Stages created for every new [`EcsWorld`], in execution order.
+pub const DEFAULT_STAGES: [&str; 4] = [
+    STAGE_PRE_INPUT,
+    STAGE_SIMULATION,
+    STAGE_POST_SIMULATION,
+    STAGE_RENDER_EXTRACT,
+];
+
+/// Describes where and in which order a persistent system runs.
+///
+/// # Examples
+///
+/// ```
+/// use kitu_ecs::{SystemSpec, STAGE_SIMULATION};
+///
+/// let spec = SystemSpec::new(STAGE_SIMULATION)
+///     .label("movement")
+///     .after("input");
+/// assert_eq!(spec.stage(), STAGE_SIMULATION);
+/// assert_eq!(spec.system_label(), Some("movement"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemSpec {
+    stage: String,
+    label: Option<String>,
+    before: Vec<String>,
+    after: Vec<String>,
+}
+
+impl SystemSpec {
+    /// Creates a spec for an unlabeled system in the given stage.
+    pub fn new(stage: impl Into<String>) -> Self {
+        Self {
+            stage: stage.into(),
+            label: None,
+            before: Vec::new(),
+            after: Vec::new(),
+        }
+    }
+
+    /// Names the system so other systems can order themselves against it.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Requires this system to run before the labeled system in the same stage.
+    pub fn before(mut self, label: impl Into<String>) -> Self {
+        self.before.push(label.into());
+        self
+    }
+
+    /// Requires this system to run after the labeled system in the same stage.
+    pub fn after(mut self, label: impl Into<String>) -> Self {
+        self.after.push(label.into());
+        self
+    }
+
+    /// Returns the target stage name.
+    pub fn stage(&self) -> &str {
+        &self.stage
+    }
+
+    /// Returns the system label, if any.
+    pub fn system_label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+}
+
+struct ScheduledSystem {
+    label: Option<String>,
+    before: Vec<String>,
+    after: Vec<String>,
+    system: Box<dyn System>,
+}
+
+struct Stage {
+    name: String,
+    systems: Vec<ScheduledSystem>,
+    order: Vec<usize>,
+}
+
+impl Stage {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            systems: Vec::new(),
+            order: Vec::new(),
+        }
+    }
+}
+
+/// Ordered collection of stages owned by an [`EcsWorld`].
+#[derive(Default)]
+pub(crate) struct Schedule {
+    stages: Vec<Stage>,
+}
+
+impl Schedule {
+    pub(crate) fn with_default_stages() -> Self {
+        Self {
+            stages: DEFAULT_STAGES
+                .iter()
+                .map(|name| Stage::new((*name).to_string()))
+                .collect(),
+        }
+    }
+
+    pub(crate) fn stage_names(&self) -> Vec<String> {
+        self.stages.iter().map(|stage| stage.name.clone()).collect()
+    }
+
+    pub(crate) fn stage_labels(&self, stage: &str) -> Option<Vec<Option<String>>> {
+        let stage = self
+            .stages
+            .iter()
+            .find(|candidate| candidate.name == stage)?;
+        Some(
+            stage
+                .order
+                .iter()
+                .map(|index| stage.systems[*index].label.clone())
+                .collect(),
+        )
+    }
+
+    pub(crate) fn insert_stage(&mut self, name: String, anchor: StageAnchor<'_>) -> Result<()> {
+        if name.is_empty() {
+            return Err(KituError::InvalidSchedule(
+                "stage name cannot be empty".to_string(),
+            ));
+        }
+        if self.stage_index(&name).is_some() {
+            return Err(KituError::InvalidSchedule(format!(
+                "stage `{name}` already exists"
+            )));
+        }
+        let position = match anchor {
+            StageAnchor::End => self.stages.len(),
+            StageAnchor::Before(anchor) => self.required_stage_index(anchor)?,
+            StageAnchor::After(anchor) => self.required_stage_index(anchor)? + 1,
+        };
+        self.stages.insert(position, Stage::new(name));
+        Ok(())
+    }
+
+    pub(crate) fn add_system(&mut self, spec: SystemSpec, system: Box<dyn System>) -> Result<()> {
+        let stage_index = self.required_stage_index(&spec.stage)?;
+        if let Some(label) = &spec.label {
+            if label.is_empty() {
+                return Err(KituError::InvalidSchedule(
+                    "system label cannot be empty".to_string(),
+                ));
+            }
+            if let Some(existing) = self.stage_of_label(label) {
+                return Err(KituError::InvalidSchedule(format!(
+                    "system `{label}` is already registered in stage `{existing}`"
+                )));
+            }
+        }
+
+        let stage = &mut self.stages[stage_index];
+        for target in spec.before.iter().chain(&spec.after) {
+            if spec.label.as_deref() == Some(target.as_str()) {
+                return Err(KituError::InvalidSchedule(format!(
+                    "system `{target}` cannot be ordered against itself"
+                )));
+            }
+            if !stage
+                .systems
+                .iter()
+                .any(|system| system.label.as_deref() == Some(target.as_str()))
+            {
+                return Err(KituError::InvalidSchedule(format!(
+                    "ordering constraint references unknown system `{target}` in stage `{}`",
+                    stage.name
+                )));
+            }
+        }
+
+        stage.systems.push(ScheduledSystem {
+            label: spec.label,
+            before: spec.before,
+            after: spec.after,
+            system,
+        });
+        match sorted_order(&stage.systems) {
+            Some(order) => {
+                stage.order = order;
+                Ok(())
+            }
+            None => {
+                let rejected = stage.systems.pop().and_then(|system| system.label);
+                Err(KituError::InvalidSchedule(format!(
+                    "ordering cycle in stage `{}` while adding system `{}`",
+                    stage.name,
+                    rejected.as_deref().unwrap_or("<unlabeled>")
+                )))
+            }
+        }
+    }
+
+    pub(crate) fn run(&mut self, world: &mut EcsWorld, tick: Tick) -> Result<()> {
+        for stage in &mut self.stages {
+            for index in &stage.order {
+                stage.systems[*index].system.run(world, tick)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn stage_index(&self, name: &str) -> Option<usize> {
+        self.stages.iter().position(|stage| stage.name == name)
+    }
+
+    fn required_stage_index(&self, name: &str) -> Result<usize> {
+        self.stage_index(name)
+            .ok_or_else(|| KituError::InvalidSchedule(format!("unknown stage `{name}`")))
+    }
+
+    fn stage_of_label(&self, label: &str) -> Option<&str> {
+        self.stages
+            .iter()
+            .find(|stage| {
+                stage
+                    .systems
+                    .iter()
+                    .any(|system| system.label.as_deref() == Some(label))
+            })
+            .map(|stage| stage.name.as_str())
+    }
+}
+
+/// Placement of a new stage relative to existing ones.
+pub(crate) enum StageAnchor<'a> {
+    End,
+    Before(&'a str),
+    After(&'a str),
+}
+
+/// Topologically sorts systems by their constraints, preferring registration order among systems
+/// that are ready at the same time. Returns `None` when the constraints contain a cycle.
+fn sorted_order(systems: &[ScheduledSystem]) -> Option<Vec<usize>> {
+    let index_of = |label: &str| {
+        systems
+            .iter()
+            .position(|system| system.label.as_deref() == Some(label))
+    };
+
+    let mut successors = vec![Vec::new(); systems.len()];
+    let mut in_degree = vec![0_usize; systems.len()];
+    for (index, system) in systems.iter().enumerate() {
+        for target in &system.before {
+            let target = index_of(target)?;
+            successors[index].push(target);
+            in_degree[target] += 1;
+        }
+        for target in &system.after {
+            let target = index_of(target)?;
+            successors[target].push(index);
+            in_degree[index] += 1;
+        }
+    }
+
+    let mut order = Vec::with_capacity(systems.len());
+    let mut emitted = vec![false; systems.len()];
+    while order.len() < systems.len() {
+        let next = (0..systems.len()).find(|index| !emitted[*index] && in_degree[*index] == 0)?;
+        emitted[next] = true;
+        order.push(next);
+        for successor in &successors[next] {
+            in_degree[*successor] -= 1;
+        }
+    }
+    Some(order)
+}
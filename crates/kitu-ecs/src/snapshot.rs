@@ -0,0 +1,577 @@
+//! Serializable view of the authoritative world state.
+//!
+//! Snapshots encode to JSON for tools and humans and to MessagePack for compact storage or
+//! transport. Both encodings use named fields and carry [`WorldSnapshot::schema_version`], so
+//! readers can reject payloads written by a newer, incompatible layout.
+//!
+//! [`EcsWorld::restore_world_snapshot`] loads a snapshot back, including the hierarchy and the
+//! entity allocator, so a restored world keeps issuing the same handles as the one it was
+//! captured from.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use kitu_core::{KituError, Result, Tick};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    component::{ComponentStore, SerializableComponents},
+    entity::EntityAllocator,
+    Collider, EcsWorld, Entity, EntityAllocatorState, LocalTransform, ObjectKind, WorldTransform,
+};
+
+/// Layout version written into every [`WorldSnapshot`].
+///
+/// Bump this whenever a field is added, removed or changes meaning. Version 2 added the
+/// hierarchy fields of [`WorldObject`] and [`WorldSnapshot::entities`], version 3 added
+/// [`WorldObject::collider`], version 4 added [`WorldSnapshot::components`] and version 5 added
+/// [`WorldObject::named`]; older payloads are still accepted.
+pub const WORLD_SNAPSHOT_SCHEMA_VERSION: u32 = 5;
+
+/// Object tracked by the ECS-backed world state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorldObject {
+    /// Generational handle of the entity backing this object.
+    pub entity: Entity,
+    /// External identifier: the stable name when one was given, otherwise the entity handle.
+    pub id: String,
+    /// Whether `id` is a stable name rather than the entity handle.
+    ///
+    /// Snapshots older than version 5 lack the flag; restoring them treats every id that differs
+    /// from the handle as a name.
+    #[serde(default)]
+    pub named: bool,
+    /// Application-level object category.
+    pub kind: String,
+    /// Current object transform.
+    pub transform: WorldTransform,
+    /// Parent entity when the object is attached to a hierarchy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Entity>,
+    /// Transform relative to the parent, present for objects driven by propagation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local: Option<LocalTransform>,
+    /// Collision shape, present for objects that take part in collision detection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collider: Option<Collider>,
+}
+
+impl WorldObject {
+    /// Whether the object's id is a stable name, reading the flag for version 5 snapshots and
+    /// inferring it from the id for older ones.
+    fn is_named(&self, schema_version: u32) -> bool {
+        if schema_version >= 5 {
+            self.named
+        } else {
+            self.id != self.entity.to_string()
+        }
+    }
+}
+
+/// Snapshot of the ECS-backed world state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorldSnapshot {
+    /// Layout version of this snapshot, see [`WORLD_SNAPSHOT_SCHEMA_VERSION`].
+    pub schema_version: u32,
+    /// Tick the snapshot was taken at.
+    pub tick: u64,
+    /// Objects currently present in the world.
+    pub objects: Vec<WorldObject>,
+    /// Serializable resources keyed by the name they were inserted under.
+    #[serde(default)]
+    pub resources: BTreeMap<String, serde_json::Value>,
+    /// Values of serializable component types, keyed by the name they were registered under and
+    /// then by entity. Keys no entity holds are omitted.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub components: BTreeMap<String, BTreeMap<Entity, serde_json::Value>>,
+    /// Entity allocator state, so restored worlds reissue the same handles.
+    #[serde(default)]
+    pub entities: EntityAllocatorState,
+}
+
+impl Default for WorldSnapshot {
+    fn default() -> Self {
+        Self {
+            schema_version: WORLD_SNAPSHOT_SCHEMA_VERSION,
+            tick: 0,
+            objects: Vec::new(),
+            resources: BTreeMap::new(),
+            components: BTreeMap::new(),
+            entities: EntityAllocatorState::default(),
+        }
+    }
+}
+
+impl WorldSnapshot {
+    /// Encodes the snapshot as JSON.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_ecs::{EcsWorld, WorldSnapshot, WorldTransform};
+    ///
+    /// let mut world = EcsWorld::new();
+    /// world.spawn_world_object_with_id("player:local", "player", WorldTransform::new(1.0, 0.0, 2.0)).unwrap();
+    ///
+    /// let snapshot = world.world_snapshot().unwrap();
+    /// let json = snapshot.to_json().unwrap();
+    /// assert!(json.contains("\"schema_version\":5"));
+    /// assert_eq!(WorldSnapshot::from_json(&json).unwrap(), snapshot);
+    /// ```
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self).map_err(|error| KituError::Serialization(error.to_string()))
+    }
+
+    /// Decodes a JSON snapshot, rejecting unsupported schema versions.
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str::<Self>(json)
+            .map_err(|error| KituError::Serialization(error.to_string()))?
+            .checked_version()
+    }
+
+    /// Encodes the snapshot as MessagePack with named fields.
+    pub fn to_msgpack(&self) -> Result<Vec<u8>> {
+        rmp_serde::to_vec_named(self).map_err(|error| KituError::Serialization(error.to_string()))
+    }
+
+    /// Decodes a MessagePack snapshot, rejecting unsupported schema versions.
+    pub fn from_msgpack(bytes: &[u8]) -> Result<Self> {
+        rmp_serde::from_slice::<Self>(bytes)
+            .map_err(|error| KituError::Serialization(error.to_string()))?
+            .checked_version()
+    }
+
+    fn checked_version(self) -> Result<Self> {
+        self.check_version()?;
+        Ok(self)
+    }
+
+    fn check_version(&self) -> Result<()> {
+        if self.schema_version == 0 || self.schema_version > WORLD_SNAPSHOT_SCHEMA_VERSION {
+            return Err(KituError::Serialization(format!(
+                "unsupported world snapshot schema version {} (supported: 1..={})",
+                self.schema_version, WORLD_SNAPSHOT_SCHEMA_VERSION
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the allocator state to restore, deriving one from the objects for version 1
+    /// snapshots that predate [`WorldSnapshot::entities`].
+    fn allocator_state(&self) -> EntityAllocatorState {
+        if self.schema_version >= 2 || !self.entities.generations.is_empty() {
+            return self.entities.clone();
+        }
+        let slots = self
+            .objects
+            .iter()
+            .map(|object| object.entity.index() as usize + 1)
+            .max()
+            .unwrap_or(0);
+        let mut generations = vec![0; slots];
+        let mut alive = vec![false; slots];
+        for object in &self.objects {
+            generations[object.entity.index() as usize] = object.entity.generation();
+            alive[object.entity.index() as usize] = true;
+        }
+        let free = (0..slots as u32)
+            .rev()
+            .filter(|index| !alive[*index as usize])
+            .collect();
+        EntityAllocatorState { generations, free }
+    }
+}
+
+impl EcsWorld {
+    /// Replaces the world state with the contents of a snapshot.
+    ///
+    /// Objects come back with their kinds, ids, transforms, colliders and hierarchy links; the entity
+    /// allocator, the change tick and registered serializable resources are restored as well.
+    /// Components registered through
+    /// [`register_serializable_component`](Self::register_serializable_component) come back on
+    /// their entities; any other component is dropped, as are events. Events sent after the
+    /// restore count as sent during the tick before the snapshot's tick, so systems read them
+    /// when the snapshot's tick is dispatched. Resources without a snapshot entry keep their
+    /// current value. Every snapshot resource and component key must already be registered so
+    /// its type is known.
+    ///
+    /// The snapshot is validated before anything is touched, so on error the world is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_ecs::{EcsWorld, WorldTransform};
+    ///
+    /// let mut world = EcsWorld::new();
+    /// world.spawn_world_object_with_id("crate", "prop", WorldTransform::new(1.0, 0.0, 0.0)).unwrap();
+    /// let saved = world.world_snapshot().unwrap();
+    ///
+    /// world.reset_world_objects();
+    /// world.restore_world_snapshot(&saved).unwrap();
+    /// assert_eq!(world.world_snapshot().unwrap(), saved);
+    /// ```
+    pub fn restore_world_snapshot(&mut self, snapshot: &WorldSnapshot) -> Result<()> {
+        snapshot.check_version()?;
+        let allocator = EntityAllocator::from_state(&snapshot.allocator_state())?;
+        let parents = validated_objects(snapshot, &allocator)?;
+        let restored_resources = self.resources.decode_snapshot(&snapshot.resources)?;
+        let restored_components = self
+            .serializable_components
+            .decode_snapshot(&snapshot.components)?;
+        if restored_components
+            .entities()
+            .any(|entity| !allocator.is_alive(entity))
+        {
+            return Err(KituError::InvalidInput(
+                "snapshot component entity is not alive in the allocator state",
+            ));
+        }
+
+        self.component_store = ComponentStore::default();
+        self.component_store
+            .set_change_tick(Tick::start().advance_by(snapshot.tick));
+        self.named_entities.clear();
+        self.entity_names.clear();
+        // Events sent before the next dispatch belong to the tick that ended at the snapshot.
+        self.events.reset(
+            snapshot
+                .tick
+                .checked_sub(1)
+                .map(|tick| Tick::start().advance_by(tick)),
+        );
+        self.entities = allocator;
+
+        for object in &snapshot.objects {
+            if object.is_named(snapshot.schema_version) {
+                self.named_entities.insert(object.id.clone(), object.entity);
+                self.entity_names.insert(object.entity, object.id.clone());
+            }
+            self.component_store
+                .insert(object.entity, ObjectKind(object.kind.clone()));
+            self.component_store.insert(object.entity, object.transform);
+            if let Some(collider) = object.collider {
+                self.component_store.insert(object.entity, collider);
+            }
+            if !parents.contains_key(&object.entity) {
+                if let Some(local) = object.local {
+                    self.component_store.insert(object.entity, local);
+                }
+            }
+        }
+        for object in &snapshot.objects {
+            if let Some(parent) = parents.get(&object.entity) {
+                let local = object
+                    .local
+                    .unwrap_or_else(|| object.transform.relative_to(&parent.transform));
+                self.link_child(parent.entity, object.entity, local);
+            }
+        }
+        SerializableComponents::apply_restored(&mut self.component_store, restored_components);
+        self.rebuild_spatial_index();
+        self.resources.apply_restored(restored_resources);
+        Ok(())
+    }
+}
+
+/// Checks object ids, kinds and handles and resolves each object's parent, rejecting dangling
+/// parents and cycles.
+fn validated_objects<'a>(
+    snapshot: &'a WorldSnapshot,
+    allocator: &EntityAllocator,
+) -> Result<HashMap<Entity, &'a WorldObject>> {
+    let mut by_entity = HashMap::new();
+    let mut ids = HashSet::new();
+    for object in &snapshot.objects {
+        if object.id.is_empty() {
+            return Err(KituError::InvalidInput("world object id cannot be empty"));
+        }
+        if object.kind.is_empty() {
+            return Err(KituError::InvalidInput("world object kind cannot be empty"));
+        }
+        if object.is_named(snapshot.schema_version) {
+            if object.id.parse::<Entity>().is_ok() {
+                return Err(KituError::InvalidInput(
+                    "named world object id cannot look like an entity handle",
+                ));
+            }
+        } else if object.id != object.entity.to_string() {
+            return Err(KituError::InvalidInput(
+                "unnamed world object id must be its entity handle",
+            ));
+        }
+        if !allocator.is_alive(object.entity) {
+            return Err(KituError::InvalidInput(
+                "snapshot object entity is not alive in the allocator state",
+            ));
+        }
+        if by_entity.insert(object.entity, object).is_some() || !ids.insert(object.id.as_str()) {
+            return Err(KituError::InvalidInput(
+                "snapshot contains a world object twice",
+            ));
+        }
+    }
+
+    let mut parents = HashMap::new();
+    for object in &snapshot.objects {
+        let Some(parent) = object.parent else {
+            continue;
+        };
+        let parent = by_entity.get(&parent).ok_or(KituError::InvalidInput(
+            "snapshot object parent is not in the snapshot",
+        ))?;
+        parents.insert(object.entity, *parent);
+    }
+    for object in &snapshot.objects {
+        let mut current = object.entity;
+        for _ in 0..=snapshot.objects.len() {
+            match parents.get(&current) {
+                Some(parent) => current = parent.entity,
+                None => break,
+            }
+            if current == object.entity {
+                return Err(KituError::InvalidInput(
+                    "snapshot hierarchy contains a cycle",
+                ));
+            }
+        }
+    }
+    Ok(parents)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{EcsWorld, Quat, Vec3};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Health(u32);
+
+    fn sample_world() -> EcsWorld {
+        let mut world = EcsWorld::new();
+        world
+            .register_serializable_component::<Health>("health")
+            .unwrap();
+        let ship = world
+            .spawn_world_object_with_id("ship", "ship", WorldTransform::new(1.5, 0.0, -2.0))
+            .unwrap();
+        let turret = world
+            .spawn_world_object("turret", WorldTransform::new(2.0, 1.0, -2.0))
+            .unwrap();
+        world.attach_child(ship.entity, turret.entity).unwrap();
+        let mut rotated = world.world_object(&turret.id).unwrap().transform;
+        rotated.rotation = Quat::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), 0.5);
+        rotated.scale = Vec3::new(1.0, 2.0, 1.0);
+        world.move_world_object(&turret.id, rotated).unwrap();
+        world.insert_component(ship.entity, Health(12)).unwrap();
+        world
+            .insert_serializable_resource("score", vec![3_u32, 5])
+            .unwrap();
+        world
+    }
+
+    fn sample_snapshot() -> WorldSnapshot {
+        sample_world().world_snapshot().unwrap()
+    }
+
+    #[test]
+    fn json_and_msgpack_round_trip() {
+        let snapshot = sample_snapshot();
+
+        let json = snapshot.to_json().unwrap();
+        assert_eq!(WorldSnapshot::from_json(&json).unwrap(), snapshot);
+
+        let bytes = snapshot.to_msgpack().unwrap();
+        assert_eq!(WorldSnapshot::from_msgpack(&bytes).unwrap(), snapshot);
+        assert!(bytes.len() < json.len());
+    }
+
+    #[test]
+    fn json_layout_uses_named_fields_and_entity_strings() {
+        let value = serde_json::to_value(sample_snapshot()).unwrap();
+
+        assert_eq!(
+            value["schema_version"],
+            json!(WORLD_SNAPSHOT_SCHEMA_VERSION)
+        );
+        assert_eq!(value["objects"][0]["entity"], json!("0v0"));
+        assert_eq!(value["objects"][0]["id"], json!("ship"));
+        assert_eq!(value["objects"][0]["named"], json!(true));
+        assert_eq!(value["objects"][1]["id"], json!("1v0"));
+        assert_eq!(value["objects"][1]["named"], json!(false));
+        assert_eq!(value["objects"][0]["transform"]["x"], json!(1.5));
+        assert_eq!(
+            value["objects"][0]["transform"]["scale"],
+            json!({ "x": 1.0, "y": 1.0, "z": 1.0 })
+        );
+        assert_eq!(value["resources"]["score"], json!([3, 5]));
+        assert_eq!(value["components"], json!({ "health": { "0v0": 12 } }));
+    }
+
+    #[test]
+    fn decoding_rejects_unknown_versions_and_malformed_payloads() {
+        let mut snapshot = sample_snapshot();
+        snapshot.schema_version = WORLD_SNAPSHOT_SCHEMA_VERSION + 1;
+        let newer = snapshot.to_json().unwrap();
+        assert!(matches!(
+            WorldSnapshot::from_json(&newer),
+            Err(KituError::Serialization(message)) if message.contains("schema version")
+        ));
+
+        let missing_version = r#"{"tick":0,"objects":[]}"#;
+        assert!(WorldSnapshot::from_json(missing_version).is_err());
+        assert!(WorldSnapshot::from_msgpack(&[0xc1]).is_err());
+
+        let bad_entity = r#"{"schema_version":1,"tick":0,"objects":[{"entity":"zero","id":"a","kind":"k","transform":{"x":0,"y":0,"z":0,"rotation":{"x":0,"y":0,"z":0,"w":1},"scale":{"x":1,"y":1,"z":1}}}]}"#;
+        assert!(WorldSnapshot::from_json(bad_entity).is_err());
+    }
+
+    #[test]
+    fn restore_rebuilds_objects_hierarchy_resources_components_and_allocator() {
+        let snapshot = sample_snapshot();
+        let mut world = EcsWorld::new();
+        world
+            .insert_serializable_resource("score", Vec::<u32>::new())
+            .unwrap();
+        world
+            .register_serializable_component::<Health>("health")
+            .unwrap();
+        let stale = world
+            .spawn_world_object_with_id("stale", "prop", WorldTransform::new(9.0, 0.0, 0.0))
+            .unwrap();
+        world.insert_component(stale.entity, Health(1)).unwrap();
+
+        world.restore_world_snapshot(&snapshot).unwrap();
+
+        assert_eq!(world.world_snapshot().unwrap(), snapshot);
+        assert!(world.world_object("stale").is_none());
+        assert_eq!(world.get_resource::<Vec<u32>>(), Some(&vec![3, 5]));
+        let ship = world.world_object_entity("ship").unwrap();
+        assert_eq!(world.get_component::<Health>(ship), Some(&Health(12)));
+        let turret = world.children_of(ship)[0];
+        assert_eq!(world.get_component::<Health>(turret), None);
+        assert_eq!(
+            world.entities_within_radius(Vec3::new(2.0, 1.0, -2.0), 0.1),
+            vec![turret]
+        );
+
+        world
+            .move_world_object("ship", WorldTransform::new(0.0, 0.0, 0.0))
+            .unwrap();
+        world.propagate_transforms();
+        let moved = world.world_object_for_entity(turret).unwrap().transform;
+        assert!((moved.x - 0.5).abs() < 1e-5 && moved.z.abs() < 1e-5);
+
+        let mut original = sample_world();
+        assert_eq!(world.spawn_entity(), original.spawn_entity());
+    }
+
+    #[test]
+    fn restore_rejects_inconsistent_snapshots_without_changing_the_world() {
+        let mut world = EcsWorld::new();
+        world
+            .insert_serializable_resource("score", vec![1_u32])
+            .unwrap();
+        world
+            .register_serializable_component::<Health>("health")
+            .unwrap();
+        let before = world.world_snapshot().unwrap();
+
+        let mut dangling = sample_snapshot();
+        dangling.objects.remove(0);
+        let mut cycle = sample_snapshot();
+        cycle.objects[0].parent = Some(cycle.objects[1].entity);
+        let mut duplicate = sample_snapshot();
+        duplicate.objects[1].id = "ship".to_string();
+        let mut handle_shaped = sample_snapshot();
+        handle_shaped.objects[0].id = "5v0".to_string();
+        let mut unnamed = sample_snapshot();
+        unnamed.objects[0].named = false;
+        let mut renamed_handle = sample_snapshot();
+        renamed_handle.objects[1].named = true;
+        let mut stale = sample_snapshot();
+        stale.entities.free.push(0);
+        let mut bad_resource = sample_snapshot();
+        bad_resource
+            .resources
+            .insert("score".to_string(), json!("three"));
+        let mut unknown_resource = sample_snapshot();
+        unknown_resource
+            .resources
+            .insert("lives".to_string(), json!(3));
+        assert!(matches!(
+            world.restore_world_snapshot(&unknown_resource),
+            Err(KituError::Serialization(message)) if message.contains("`lives`")
+        ));
+        let mut bad_component = sample_snapshot();
+        bad_component
+            .components
+            .get_mut("health")
+            .unwrap()
+            .insert(bad_component.objects[0].entity, json!("full"));
+        let mut dead_component = sample_snapshot();
+        dead_component
+            .components
+            .get_mut("health")
+            .unwrap()
+            .insert("9v0".parse().unwrap(), json!(1));
+        let mut unknown_component = sample_snapshot();
+        unknown_component.components.insert(
+            "shield".to_string(),
+            BTreeMap::from([(unknown_component.objects[0].entity, json!(1))]),
+        );
+
+        for snapshot in [
+            dangling,
+            cycle,
+            duplicate,
+            handle_shaped,
+            unnamed,
+            renamed_handle,
+            stale,
+            bad_resource,
+            unknown_resource,
+            bad_component,
+            dead_component,
+            unknown_component,
+        ] {
+            assert!(world.restore_world_snapshot(&snapshot).is_err());
+            assert_eq!(world.world_snapshot().unwrap(), before);
+        }
+    }
+
+    #[test]
+    fn events_sent_after_a_restore_belong_to_the_tick_before_the_snapshot() {
+        let mut world = EcsWorld::new();
+        world.set_change_tick(Tick::start().advance_by(3));
+        let saved = world.world_snapshot().unwrap();
+        world.send_event(7_u32);
+        world.dispatch(Tick::start().advance_by(9)).unwrap();
+
+        world.restore_world_snapshot(&saved).unwrap();
+        assert!(world.iter_events::<u32>().next().is_none());
+        world.send_event(8_u32);
+        world.dispatch(Tick::start().advance_by(3)).unwrap();
+        assert_eq!(world.iter_events::<u32>().copied().collect::<Vec<_>>(), [8]);
+        world.dispatch(Tick::start().advance_by(4)).unwrap();
+        assert!(world.iter_events::<u32>().next().is_none());
+    }
+
+    #[test]
+    fn version_1_snapshots_restore_with_a_derived_allocator() {
+        let version_1 = r#"{"schema_version":1,"tick":4,"objects":[{"entity":"2v1","id":"player:local","kind":"player","transform":{"x":1,"y":0,"z":0,"rotation":{"x":0,"y":0,"z":0,"w":1},"scale":{"x":1,"y":1,"z":1}}}]}"#;
+        let mut world = EcsWorld::new();
+
+        world
+            .restore_world_snapshot(&WorldSnapshot::from_json(version_1).unwrap())
+            .unwrap();
+
+        let player = world.world_object("player:local").unwrap();
+        assert_eq!(player.entity.to_string(), "2v1");
+        assert_eq!(world.change_tick().get(), 4);
+        assert_eq!(world.spawn_entity().to_string(), "0v0");
+        assert_eq!(world.spawn_entity().to_string(), "1v0");
+        assert_eq!(world.spawn_entity().to_string(), "3v0");
+    }
+}
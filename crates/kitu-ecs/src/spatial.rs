@@ -0,0 +1,436 @@
+//! Uniform-grid spatial index over entity world positions.
+//!
+//! Every entity with a [`WorldTransform`] is bucketed by the grid cell containing its position.
+//! World-level writes (spawn, move, insert/remove of `WorldTransform`, despawn, propagation)
+//! update the index immediately. Writes through
+//! [`get_component_mut`](EcsWorld::get_component_mut) cannot be observed, so those entities are
+//! marked dirty and checked against their current transform by every query until the index is
+//! next flushed (at the start and end of [`dispatch`](EcsWorld::dispatch) and on propagation).
+//!
+//! Query results are deterministic: region queries return entities in slot order and nearest
+//! queries order by distance, breaking ties by entity.
+
+use std::collections::{BTreeSet, HashMap};
+
+use kitu_core::{KituError, Result};
+
+use crate::{EcsWorld, Entity, Vec3, WorldTransform};
+
+/// Default edge length of a spatial grid cell, in world units.
+pub const DEFAULT_SPATIAL_CELL_SIZE: f32 = 4.0;
+
+type Cell = (i32, i32, i32);
+
+pub(crate) struct SpatialIndex {
+    cell_size: f32,
+    cells: HashMap<Cell, Vec<Entity>>,
+    positions: HashMap<Entity, (Cell, Vec3)>,
+    dirty: BTreeSet<Entity>,
+}
+
+impl Default for SpatialIndex {
+    fn default() -> Self {
+        Self {
+            cell_size: DEFAULT_SPATIAL_CELL_SIZE,
+            cells: HashMap::new(),
+            positions: HashMap::new(),
+            dirty: BTreeSet::new(),
+        }
+    }
+}
+
+impl SpatialIndex {
+    pub(crate) fn update(&mut self, entity: Entity, position: Vec3) {
+        self.dirty.remove(&entity);
+        let cell = self.cell_of(position);
+        match self.positions.insert(entity, (cell, position)) {
+            Some((previous, _)) if previous == cell => {}
+            Some((previous, _)) => {
+                self.remove_from_cell(previous, entity);
+                self.cells.entry(cell).or_default().push(entity);
+            }
+            None => self.cells.entry(cell).or_default().push(entity),
+        }
+    }
+
+    pub(crate) fn remove(&mut self, entity: Entity) {
+        self.dirty.remove(&entity);
+        if let Some((cell, _)) = self.positions.remove(&entity) {
+            self.remove_from_cell(cell, entity);
+        }
+    }
+
+    pub(crate) fn mark_dirty(&mut self, entity: Entity) {
+        self.dirty.insert(entity);
+    }
+
+    pub(crate) fn take_dirty(&mut self) -> BTreeSet<Entity> {
+        std::mem::take(&mut self.dirty)
+    }
+
+    fn remove_from_cell(&mut self, cell: Cell, entity: Entity) {
+        if let Some(entities) = self.cells.get_mut(&cell) {
+            entities.retain(|indexed| *indexed != entity);
+            if entities.is_empty() {
+                self.cells.remove(&cell);
+            }
+        }
+    }
+
+    fn cell_of(&self, position: Vec3) -> Cell {
+        (
+            cell_coordinate(position.x, self.cell_size),
+            cell_coordinate(position.y, self.cell_size),
+            cell_coordinate(position.z, self.cell_size),
+        )
+    }
+
+    /// Visits clean indexed entities in cells overlapping the box.
+    fn visit_box(&self, min: Vec3, max: Vec3, mut visit: impl FnMut(Entity, Vec3)) {
+        let (low, high) = (self.cell_of(min), self.cell_of(max));
+        let span = |low: i32, high: i32| i64::from(high) - i64::from(low) + 1;
+        let box_cells = span(low.0, high.0)
+            .saturating_mul(span(low.1, high.1))
+            .saturating_mul(span(low.2, high.2));
+
+        if box_cells > self.cells.len() as i64 {
+            for (cell, entities) in &self.cells {
+                if (low.0..=high.0).contains(&cell.0)
+                    && (low.1..=high.1).contains(&cell.1)
+                    && (low.2..=high.2).contains(&cell.2)
+                {
+                    self.visit_entities(entities, &mut visit);
+                }
+            }
+            return;
+        }
+        for x in low.0..=high.0 {
+            for y in low.1..=high.1 {
+                for z in low.2..=high.2 {
+                    if let Some(entities) = self.cells.get(&(x, y, z)) {
+                        self.visit_entities(entities, &mut visit);
+                    }
+                }
+            }
+        }
+    }
+
+    fn visit_entities(&self, entities: &[Entity], visit: &mut impl FnMut(Entity, Vec3)) {
+        for entity in entities {
+            if self.dirty.contains(entity) {
+                continue;
+            }
+            if let Some((_, position)) = self.positions.get(entity) {
+                visit(*entity, *position);
+            }
+        }
+    }
+}
+
+fn cell_coordinate(value: f32, cell_size: f32) -> i32 {
+    // `as` saturates, so far-away and non-finite coordinates land in the outermost cells.
+    (value / cell_size).floor() as i32
+}
+
+fn distance_squared(a: Vec3, b: Vec3) -> f32 {
+    let delta = a - b;
+    delta.x * delta.x + delta.y * delta.y + delta.z * delta.z
+}
+
+fn inside_box(position: Vec3, min: Vec3, max: Vec3) -> bool {
+    (min.x..=max.x).contains(&position.x)
+        && (min.y..=max.y).contains(&position.y)
+        && (min.z..=max.z).contains(&position.z)
+}
+
+impl EcsWorld {
+    /// Returns the edge length of the spatial grid cells.
+    pub fn spatial_cell_size(&self) -> f32 {
+        self.spatial.cell_size
+    }
+
+    /// Rebuilds the spatial index with a new cell size.
+    ///
+    /// Cells should be around the typical query radius; the size must be finite and positive.
+    pub fn set_spatial_cell_size(&mut self, cell_size: f32) -> Result<()> {
+        if !cell_size.is_finite() || cell_size <= 0.0 {
+            return Err(KituError::InvalidInput(
+                "spatial cell size must be finite and positive",
+            ));
+        }
+        self.spatial.cell_size = cell_size;
+        self.rebuild_spatial_index();
+        Ok(())
+    }
+
+    /// Re-indexes every entity with a [`WorldTransform`] from scratch, keeping the cell size.
+    pub(crate) fn rebuild_spatial_index(&mut self) {
+        self.spatial = SpatialIndex {
+            cell_size: self.spatial.cell_size,
+            ..SpatialIndex::default()
+        };
+        let positions: Vec<(Entity, Vec3)> = self
+            .query::<(WorldTransform,)>()
+            .map(|(entity, (transform,))| (entity, transform.translation()))
+            .collect();
+        for (entity, position) in positions {
+            self.spatial.update(entity, position);
+        }
+    }
+
+    /// Returns entities whose world position lies within `radius` of `center`, in slot order.
+    ///
+    /// The boundary is inclusive. Negative or non-finite radii match nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_ecs::{EcsWorld, Vec3, WorldTransform};
+    ///
+    /// let mut world = EcsWorld::new();
+    /// let near = world.spawn_world_object("coin", WorldTransform::new(1.0, 0.0, 1.0)).unwrap();
+    /// world.spawn_world_object("coin", WorldTransform::new(30.0, 0.0, 0.0)).unwrap();
+    ///
+    /// assert_eq!(world.entities_within_radius(Vec3::ZERO, 2.0), vec![near.entity]);
+    /// ```
+    pub fn entities_within_radius(&self, center: Vec3, radius: f32) -> Vec<Entity> {
+        if !radius.is_finite() || radius < 0.0 {
+            return Vec::new();
+        }
+        let extent = Vec3::new(radius, radius, radius);
+        let radius_squared = radius * radius;
+        self.collect_in_region(center - extent, center + extent, |position| {
+            distance_squared(position, center) <= radius_squared
+        })
+    }
+
+    /// Returns entities whose world position lies inside the axis-aligned box, in slot order.
+    ///
+    /// Bounds are inclusive; a box with any `min` component above `max` matches nothing.
+    pub fn entities_in_box(&self, min: Vec3, max: Vec3) -> Vec<Entity> {
+        if min.x > max.x || min.y > max.y || min.z > max.z {
+            return Vec::new();
+        }
+        self.collect_in_region(min, max, |position| inside_box(position, min, max))
+    }
+
+    /// Returns up to `k` entities closest to `center`, nearest first.
+    ///
+    /// Equidistant entities are ordered by their handle so results are reproducible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_ecs::{EcsWorld, Vec3, WorldTransform};
+    ///
+    /// let mut world = EcsWorld::new();
+    /// let far = world.spawn_world_object("enemy", WorldTransform::new(9.0, 0.0, 0.0)).unwrap();
+    /// let near = world.spawn_world_object("enemy", WorldTransform::new(2.0, 0.0, 0.0)).unwrap();
+    /// world.spawn_world_object("enemy", WorldTransform::new(50.0, 0.0, 0.0)).unwrap();
+    ///
+    /// assert_eq!(world.nearest_entities(Vec3::ZERO, 2), vec![near.entity, far.entity]);
+    /// ```
+    pub fn nearest_entities(&self, center: Vec3, k: usize) -> Vec<Entity> {
+        if k == 0 || !(center.x.is_finite() && center.y.is_finite() && center.z.is_finite()) {
+            return Vec::new();
+        }
+        let dirty: Vec<(f32, Entity)> = self
+            .spatial
+            .dirty
+            .iter()
+            .filter_map(|entity| {
+                let position = self.get_component::<WorldTransform>(*entity)?.translation();
+                Some((distance_squared(position, center), *entity))
+            })
+            .collect();
+        let clean_indexed = self
+            .spatial
+            .positions
+            .keys()
+            .filter(|entity| !self.spatial.dirty.contains(entity))
+            .count();
+
+        let mut ring: i32 = 0;
+        loop {
+            // Entities outside the visited cube are more than `reach` away from `center`.
+            let reach = if ring == i32::MAX {
+                f32::INFINITY
+            } else {
+                ring as f32 * self.spatial.cell_size
+            };
+            let extent = Vec3::new(reach, reach, reach);
+            let mut candidates = dirty.clone();
+            let mut visited = 0;
+            self.spatial
+                .visit_box(center - extent, center + extent, |entity, position| {
+                    visited += 1;
+                    candidates.push((distance_squared(position, center), entity));
+                });
+            candidates.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+
+            let settled = candidates.len() >= k && candidates[k - 1].0 <= reach * reach;
+            if settled || visited >= clean_indexed {
+                candidates.truncate(k);
+                return candidates.into_iter().map(|(_, entity)| entity).collect();
+            }
+            ring = ring.saturating_mul(2).max(1);
+        }
+    }
+
+    /// Writes the entity's position into the spatial index.
+    pub(crate) fn index_world_transform(&mut self, entity: Entity, transform: &WorldTransform) {
+        self.spatial.update(entity, transform.translation());
+    }
+
+    /// Re-indexes entities whose transform may have changed through a mutable borrow.
+    pub(crate) fn flush_spatial_index(&mut self) {
+        for entity in self.spatial.take_dirty() {
+            match self.get_component::<WorldTransform>(entity).copied() {
+                Some(transform) => self.index_world_transform(entity, &transform),
+                None => self.spatial.remove(entity),
+            }
+        }
+    }
+
+    fn collect_in_region(
+        &self,
+        min: Vec3,
+        max: Vec3,
+        matches: impl Fn(Vec3) -> bool,
+    ) -> Vec<Entity> {
+        let mut entities = Vec::new();
+        self.spatial.visit_box(min, max, |entity, position| {
+            if matches(position) {
+                entities.push(entity);
+            }
+        });
+        for entity in &self.spatial.dirty {
+            if let Some(transform) = self.get_component::<WorldTransform>(*entity) {
+                if matches(transform.translation()) {
+                    entities.push(*entity);
+                }
+            }
+        }
+        entities.sort();
+        entities
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force_nearest(world: &EcsWorld, center: Vec3, k: usize) -> Vec<Entity> {
+        let mut all: Vec<(f32, Entity)> = world
+            .query::<(WorldTransform,)>()
+            .map(|(entity, (transform,))| {
+                (distance_squared(transform.translation(), center), entity)
+            })
+            .collect();
+        all.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        all.into_iter().take(k).map(|(_, entity)| entity).collect()
+    }
+
+    #[test]
+    fn index_follows_spawn_move_and_despawn() {
+        let mut world = EcsWorld::new();
+        let mover = world
+            .spawn_world_object("mover", WorldTransform::new(0.0, 0.0, 0.0))
+            .unwrap();
+        let rock = world
+            .spawn_world_object("rock", WorldTransform::new(3.0, 0.0, 0.0))
+            .unwrap();
+        assert_eq!(
+            world.entities_within_radius(Vec3::ZERO, 1.0),
+            vec![mover.entity]
+        );
+
+        world
+            .move_world_object(&mover.id, WorldTransform::new(40.0, 0.0, 40.0))
+            .unwrap();
+        assert!(world.entities_within_radius(Vec3::ZERO, 1.0).is_empty());
+        assert_eq!(
+            world.entities_in_box(Vec3::new(39.0, -1.0, 39.0), Vec3::new(41.0, 1.0, 41.0)),
+            vec![mover.entity]
+        );
+
+        world.despawn_world_object(&rock.id).unwrap();
+        assert!(world
+            .entities_in_box(Vec3::new(-10.0, -10.0, -10.0), Vec3::new(10.0, 10.0, 10.0))
+            .is_empty());
+        assert_eq!(world.nearest_entities(Vec3::ZERO, 5), vec![mover.entity]);
+    }
+
+    #[test]
+    fn mutable_borrows_are_visible_before_the_next_flush() {
+        let mut world = EcsWorld::new();
+        let entity = world.spawn_entity();
+        world
+            .insert_component(entity, WorldTransform::new(0.0, 0.0, 0.0))
+            .unwrap();
+        world.get_component_mut::<WorldTransform>(entity).unwrap().x = 100.0;
+
+        assert!(world.entities_within_radius(Vec3::ZERO, 1.0).is_empty());
+        let far = Vec3::new(100.0, 0.0, 0.0);
+        assert_eq!(world.entities_within_radius(far, 1.0), vec![entity]);
+        assert_eq!(world.nearest_entities(Vec3::ZERO, 1), vec![entity]);
+
+        world.flush_spatial_index();
+        assert_eq!(world.entities_within_radius(far, 1.0), vec![entity]);
+        world.remove_component::<WorldTransform>(entity);
+        assert!(world.entities_within_radius(far, 1.0).is_empty());
+    }
+
+    #[test]
+    fn nearest_matches_brute_force_across_cell_sizes() {
+        let mut world = EcsWorld::new();
+        for index in 0..60 {
+            let angle = index as f32 * 0.7;
+            let distance = (index * index) as f32 * 0.3;
+            world
+                .spawn_world_object(
+                    "marker",
+                    WorldTransform::new(angle.cos() * distance, 0.0, angle.sin() * distance),
+                )
+                .unwrap();
+        }
+        let center = Vec3::new(5.0, 0.0, -3.0);
+
+        for cell_size in [0.5, DEFAULT_SPATIAL_CELL_SIZE, 100.0] {
+            world.set_spatial_cell_size(cell_size).unwrap();
+            for k in [1, 7, 60, 100] {
+                assert_eq!(
+                    world.nearest_entities(center, k),
+                    brute_force_nearest(&world, center, k),
+                    "cell size {cell_size}, k {k}"
+                );
+            }
+        }
+        assert!(world.set_spatial_cell_size(0.0).is_err());
+    }
+
+    #[test]
+    fn region_queries_are_inclusive_and_reject_invalid_bounds() {
+        let mut world = EcsWorld::new();
+        let edge = world
+            .spawn_world_object("edge", WorldTransform::new(2.0, 0.0, 0.0))
+            .unwrap();
+
+        assert_eq!(
+            world.entities_within_radius(Vec3::ZERO, 2.0),
+            vec![edge.entity]
+        );
+        assert_eq!(
+            world.entities_in_box(Vec3::ZERO, Vec3::new(2.0, 0.0, 0.0)),
+            vec![edge.entity]
+        );
+        assert!(world.entities_within_radius(Vec3::ZERO, -1.0).is_empty());
+        assert!(world
+            .entities_within_radius(Vec3::ZERO, f32::NAN)
+            .is_empty());
+        assert!(world
+            .entities_in_box(Vec3::new(3.0, 0.0, 0.0), Vec3::ZERO)
+            .is_empty());
+        assert!(world.nearest_entities(Vec3::ZERO, 0).is_empty());
+    }
+}
@@ -0,0 +1,317 @@
+//! Transform types: world-space transforms, parent-relative local transforms, and the small
+//! amount of vector/quaternion math needed to compose them.
+//!
+//! Composition follows the usual translate-rotate-scale order, so a child at local `(1, 0, 0)`
+//! under a parent scaled by 2 and rotated 90° about Y ends up 2 units along the parent's rotated
+//! X axis. Non-uniform parent scale combined with child rotation cannot be represented without
+//! shear; as in most engines, the result keeps rotation and scale separate and drops the shear.
+
+use std::ops::{Add, Mul, Sub};
+
+use kitu_core::FixedVec3;
+use serde::{Deserialize, Serialize};
+
+/// Three-component vector used for translations and scales.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Vec3 {
+    /// X component.
+    pub x: f32,
+    /// Y component.
+    pub y: f32,
+    /// Z component.
+    pub z: f32,
+}
+
+impl Vec3 {
+    /// Zero vector.
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0);
+    /// Vector with every component set to one.
+    pub const ONE: Self = Self::new(1.0, 1.0, 1.0);
+
+    /// Creates a vector from components.
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns the cross product `self × other`.
+    pub fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Multiplies component-wise.
+    pub fn scale_by(self, other: Self) -> Self {
+        Self::new(self.x * other.x, self.y * other.y, self.z * other.z)
+    }
+
+    /// Divides component-wise, mapping division by zero to zero.
+    pub fn divide_by(self, other: Self) -> Self {
+        fn div(value: f32, by: f32) -> f32 {
+            if by == 0.0 {
+                0.0
+            } else {
+                value / by
+            }
+        }
+        Self::new(
+            div(self.x, other.x),
+            div(self.y, other.y),
+            div(self.z, other.z),
+        )
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Mul<f32> for Vec3 {
+    type Output = Self;
+
+    fn mul(self, factor: f32) -> Self {
+        Self::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl From<Vec3> for FixedVec3 {
+    /// Converts each component with [`Fixed::from_f32`](kitu_core::Fixed::from_f32).
+    fn from(vector: Vec3) -> Self {
+        Self::from_f32(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<FixedVec3> for Vec3 {
+    /// Converts each component with [`Fixed::to_f32`](kitu_core::Fixed::to_f32).
+    fn from(vector: FixedVec3) -> Self {
+        let [x, y, z] = vector.to_f32();
+        Self::new(x, y, z)
+    }
+}
+
+/// Rotation stored as a unit quaternion.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Quat {
+    /// X component of the vector part.
+    pub x: f32,
+    /// Y component of the vector part.
+    pub y: f32,
+    /// Z component of the vector part.
+    pub z: f32,
+    /// Scalar part.
+    pub w: f32,
+}
+
+impl Quat {
+    /// Rotation that leaves vectors unchanged.
+    pub const IDENTITY: Self = Self::from_xyzw(0.0, 0.0, 0.0, 1.0);
+
+    /// Creates a quaternion from raw components without normalizing.
+    pub const fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates a rotation of `angle` radians around `axis`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_ecs::{Quat, Vec3};
+    ///
+    /// let quarter_turn = Quat::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), std::f32::consts::FRAC_PI_2);
+    /// let rotated = quarter_turn.rotate(Vec3::new(1.0, 0.0, 0.0));
+    /// assert!((rotated.z + 1.0).abs() < 1e-6);
+    /// ```
+    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
+        let length = (axis.x * axis.x + axis.y * axis.y + axis.z * axis.z).sqrt();
+        if length == 0.0 {
+            return Self::IDENTITY;
+        }
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        let factor = sin / length;
+        Self::from_xyzw(axis.x * factor, axis.y * factor, axis.z * factor, cos)
+    }
+
+    /// Returns the inverse rotation of a unit quaternion.
+    pub fn conjugate(self) -> Self {
+        Self::from_xyzw(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns the quaternion scaled to unit length, or identity for a zero quaternion.
+    pub fn normalize(self) -> Self {
+        let length = (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt();
+        if length == 0.0 {
+            return Self::IDENTITY;
+        }
+        Self::from_xyzw(
+            self.x / length,
+            self.y / length,
+            self.z / length,
+            self.w / length,
+        )
+    }
+
+    /// Rotates a vector by this quaternion.
+    pub fn rotate(self, vector: Vec3) -> Vec3 {
+        let axis = Vec3::new(self.x, self.y, self.z);
+        let twice_cross = axis.cross(vector) * 2.0;
+        vector + twice_cross * self.w + axis.cross(twice_cross)
+    }
+}
+
+impl Mul for Quat {
+    type Output = Self;
+
+    /// Composes rotations so that `(a * b).rotate(v) == a.rotate(b.rotate(v))`.
+    fn mul(self, other: Self) -> Self {
+        Self::from_xyzw(
+            self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+            self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+        )
+    }
+}
+
+/// Position, rotation and scale for an object in the authoritative world state.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WorldTransform {
+    /// X coordinate in world space.
+    pub x: f32,
+    /// Y coordinate in world space.
+    pub y: f32,
+    /// Z coordinate in world space.
+    pub z: f32,
+    /// Rotation in world space.
+    pub rotation: Quat,
+    /// Scale in world space.
+    pub scale: Vec3,
+}
+
+impl WorldTransform {
+    /// Creates an unrotated, unscaled transform from world-space coordinates.
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self {
+            x,
+            y,
+            z,
+            rotation: Quat::IDENTITY,
+            scale: Vec3::ONE,
+        }
+    }
+
+    /// Returns the position as a vector.
+    pub const fn translation(&self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    /// Returns a copy positioned at `translation`, keeping rotation and scale.
+    pub const fn with_translation(mut self, translation: Vec3) -> Self {
+        self.x = translation.x;
+        self.y = translation.y;
+        self.z = translation.z;
+        self
+    }
+
+    /// Applies a parent-relative transform under this transform.
+    pub fn mul_local(&self, local: &LocalTransform) -> Self {
+        let translation =
+            self.translation() + self.rotation.rotate(local.translation.scale_by(self.scale));
+        Self {
+            rotation: (self.rotation * local.rotation).normalize(),
+            scale: self.scale.scale_by(local.scale),
+            ..Self::new(0.0, 0.0, 0.0)
+        }
+        .with_translation(translation)
+    }
+
+    /// Expresses this world transform relative to `parent`.
+    pub fn relative_to(&self, parent: &WorldTransform) -> LocalTransform {
+        let inverse = parent.rotation.conjugate();
+        LocalTransform {
+            translation: inverse
+                .rotate(self.translation() - parent.translation())
+                .divide_by(parent.scale),
+            rotation: (inverse * self.rotation).normalize(),
+            scale: self.scale.divide_by(parent.scale),
+        }
+    }
+}
+
+impl From<LocalTransform> for WorldTransform {
+    fn from(local: LocalTransform) -> Self {
+        Self {
+            rotation: local.rotation,
+            scale: local.scale,
+            ..Self::new(0.0, 0.0, 0.0)
+        }
+        .with_translation(local.translation)
+    }
+}
+
+/// Transform relative to the entity's parent, or to the world for root entities.
+///
+/// Entities with a `LocalTransform` get their [`WorldTransform`] recomputed by
+/// [`EcsWorld::propagate_transforms`](crate::EcsWorld::propagate_transforms).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LocalTransform {
+    /// Offset from the parent origin, in the parent's rotated and scaled space.
+    pub translation: Vec3,
+    /// Rotation relative to the parent.
+    pub rotation: Quat,
+    /// Scale relative to the parent.
+    pub scale: Vec3,
+}
+
+impl LocalTransform {
+    /// Identity transform: no offset, rotation or scaling.
+    pub const IDENTITY: Self = Self {
+        translation: Vec3::ZERO,
+        rotation: Quat::IDENTITY,
+        scale: Vec3::ONE,
+    };
+
+    /// Creates an unrotated, unscaled local transform at the given offset.
+    pub const fn from_translation(x: f32, y: f32, z: f32) -> Self {
+        Self {
+            translation: Vec3::new(x, y, z),
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Returns a copy with the given rotation.
+    pub const fn with_rotation(mut self, rotation: Quat) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Returns a copy with the given scale.
+    pub const fn with_scale(mut self, scale: Vec3) -> Self {
+        self.scale = scale;
+        self
+    }
+}
+
+impl From<WorldTransform> for LocalTransform {
+    fn from(world: WorldTransform) -> Self {
+        Self {
+            translation: world.translation(),
+            rotation: world.rotation,
+            scale: world.scale,
+        }
+    }
+}
@@ -124,6 +124,13 @@ pub fn admin_world_move(id: String, x: f32, y: f32, z: f32) -> Result<JsValue, J
     to_js_message(&message)
 }
 
+#[wasm_bindgen]
+pub fn admin_world_despawn(id: String) -> Result<JsValue, JsValue> {
+    let mut message = OscMessage::new("/admin/world/despawn");
+    message.push_arg(OscArg::Str(id));
+    to_js_message(&message)
+}
+
 #[wasm_bindgen]
 pub fn admin_world_reset() -> Result<JsValue, JsValue> {
     to_js_message(&OscMessage::new("/admin/world/reset"))
@@ -159,7 +166,7 @@ mod tests {
     #[test]
     fn admin_builders_create_backend_compatible_shapes() {
         let mut message = OscMessage::new("/admin/world/move");
-        message.push_arg(OscArg::Str("obj-1".to_string()));
+        message.push_arg(OscArg::Str("0v0".to_string()));
         message.push_arg(OscArg::Float(4.0));
         message.push_arg(OscArg::Float(5.0));
         message.push_arg(OscArg::Float(6.0));
@@ -0,0 +1,175 @@
+//! Per-tick collision detection between world objects.
+//!
+//! Once enabled, every tick runs
+//! [`EcsWorld::detect_contacts`](kitu_ecs::EcsWorld::detect_contacts) after movement and
+//! transform propagation. Each [`Contact`] is sent as an ECS event, so systems read the contacts
+//! of tick `N` in tick `N + 1`; rollback frames keep the previous tick's contacts, so a
+//! resimulated tick reads the same ones. Collision response and the `/game/collision` output
+//! are opt-in through [`CollisionSettings`]. Under [`NumericMode::FixedPoint`](crate::NumericMode)
+//! the `_fixed` variants of detection and response run on the runtime's fixed-point positions
+//! instead; contacts are rounded to `f32` only for the ECS events and outputs.
+
+use kitu_core::{KituError, Result, Tick};
+use kitu_ecs::{Collider, Contact, WorldObject};
+use kitu_osc_ir::{OscArg, OscBundle, OscMessage};
+use kitu_transport::Transport;
+
+use crate::Runtime;
+
+/// Collision handling run by the runtime every tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollisionSettings {
+    /// Pushes dynamic colliders out of the static colliders they overlap, so players cannot walk
+    /// through walls.
+    pub response: bool,
+    /// Stages a `/game/collision` output for every contact.
+    pub emit_output: bool,
+}
+
+impl Default for CollisionSettings {
+    fn default() -> Self {
+        Self {
+            response: true,
+            emit_output: false,
+        }
+    }
+}
+
+impl<T: Transport> Runtime<T> {
+    /// Runs collision detection at the end of every tick's simulation.
+    ///
+    /// Contacts are computed after ECS dispatch, the move slice and transform propagation, then
+    /// resolved if [`CollisionSettings::response`] is set. With
+    /// [`CollisionSettings::emit_output`] each contact is also staged as
+    /// `/game/collision [a, b, tick, normal x, normal y, normal z, depth]`, where `a` and `b` are
+    /// object ids and the normal points from `a` toward `b` in world axes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_runtime::{build_runtime, Collider, CollisionSettings, Vec3};
+    /// use kitu_transport::LocalChannel;
+    ///
+    /// let mut runtime = build_runtime(LocalChannel::connected());
+    /// runtime.enable_collisions(CollisionSettings::default());
+    /// let wall = runtime.spawn_world_object("wall", 0.0, 0.0, 0.0).unwrap();
+    /// let player = runtime.spawn_world_object("player", 0.75, 0.0, 0.0).unwrap();
+    /// runtime
+    ///     .set_world_object_collider(&wall.id, Collider::aabb(Vec3::ONE).into_static())
+    ///     .unwrap();
+    /// runtime
+    ///     .set_world_object_collider(&player.id, Collider::sphere(0.5))
+    ///     .unwrap();
+    ///
+    /// runtime.tick_once().unwrap();
+    /// let pushed = runtime.inspect_world_state().unwrap().objects[1].transform;
+    /// assert_eq!(pushed.x, 1.5);
+    /// ```
+    pub fn enable_collisions(&mut self, settings: CollisionSettings) {
+        self.collisions = Some(settings);
+    }
+
+    /// Stops collision detection; colliders stay attached.
+    pub fn disable_collisions(&mut self) {
+        self.collisions = None;
+    }
+
+    /// Returns the collision settings, or `None` while collision detection is off.
+    pub fn collision_settings(&self) -> Option<CollisionSettings> {
+        self.collisions
+    }
+
+    /// Attaches or replaces the collider of a world object.
+    ///
+    /// Colliders are part of [`WorldSnapshot`](kitu_ecs::WorldSnapshot), so they survive
+    /// restores and rollback.
+    pub fn set_world_object_collider(
+        &mut self,
+        id: &str,
+        collider: Collider,
+    ) -> Result<WorldObject> {
+        let entity = self
+            .world
+            .world_object_entity(id)
+            .ok_or(KituError::InvalidInput("unknown world object"))?;
+        self.world.set_collider(entity, collider)?;
+        self.rollback.clear();
+        self.world
+            .world_object_for_entity(entity)
+            .ok_or(KituError::InvalidInput("unknown world object"))
+    }
+
+    /// Removes the collider of a world object, returning it.
+    pub fn remove_world_object_collider(&mut self, id: &str) -> Result<Option<Collider>> {
+        let entity = self
+            .world
+            .world_object_entity(id)
+            .ok_or(KituError::InvalidInput("unknown world object"))?;
+        let removed = self.world.remove_component::<Collider>(entity);
+        self.rollback.clear();
+        Ok(removed)
+    }
+
+    /// Detects, resolves and publishes this tick's contacts as ECS events.
+    pub(crate) fn process_collisions(&mut self) -> Result<Vec<Contact>> {
+        let Some(settings) = self.collisions else {
+            return Ok(Vec::new());
+        };
+        let contacts = if self.fixed_point() {
+            self.process_fixed_collisions(settings)?
+        } else {
+            let contacts = self.world.detect_contacts();
+            if settings.response && self.world.resolve_contacts(&contacts) > 0 {
+                self.world.propagate_transforms();
+            }
+            contacts
+        };
+        for contact in &contacts {
+            self.world.send_event(*contact);
+        }
+        Ok(contacts)
+    }
+
+    fn process_fixed_collisions(&mut self, settings: CollisionSettings) -> Result<Vec<Contact>> {
+        let mut positions = self.fixed_entity_positions();
+        let contacts = self.world.detect_contacts_fixed(&positions);
+        if settings.response && self.world.resolve_contacts_fixed(&contacts, &mut positions) > 0 {
+            self.store_fixed_entity_positions(positions)?;
+            self.world.propagate_transforms();
+        }
+        Ok(contacts.into_iter().map(Contact::from).collect())
+    }
+
+    pub(crate) fn stage_collision_outputs(&mut self, contacts: &[Contact]) -> Result<()> {
+        if !self.collisions.is_some_and(|settings| settings.emit_output) {
+            return Ok(());
+        }
+        for contact in contacts {
+            let output = self.collision_message(self.tick, contact)?;
+            self.queue_output(output);
+        }
+        Ok(())
+    }
+
+    fn collision_message(&self, tick: Tick, contact: &Contact) -> Result<OscBundle> {
+        let id = |entity| {
+            self.world
+                .world_object_for_entity(entity)
+                .map_or_else(|| entity.to_string(), |object| object.id)
+        };
+        let mut message = OscMessage::new("/game/collision");
+        message.push_arg(OscArg::Str(id(contact.a)));
+        message.push_arg(OscArg::Str(id(contact.b)));
+        let tick_i64 = i64::try_from(tick.get())
+            .map_err(|_| KituError::InvalidInput("tick is too large to encode"))?;
+        message.push_arg(OscArg::Int64(tick_i64));
+        message.push_arg(OscArg::Float(contact.normal.x));
+        message.push_arg(OscArg::Float(contact.normal.y));
+        message.push_arg(OscArg::Float(contact.normal.z));
+        message.push_arg(OscArg::Float(contact.depth));
+
+        let mut bundle = OscBundle::new();
+        bundle.push(message);
+        Ok(bundle)
+    }
+}
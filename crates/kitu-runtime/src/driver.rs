@@ -0,0 +1,358 @@
+//! Real-time tick driver running a runtime on its own thread.
+//!
+//! [`RuntimeDriver`] moves a [`Runtime`] onto a dedicated thread and calls
+//! [`Runtime::update`] once per frame of [`RuntimeConfig::tick_rate_hz`](crate::RuntimeConfig).
+//! Wake-ups follow absolute deadlines, so oversleeping one frame shortens the next wait instead of
+//! accumulating drift, and each update is given the wall-clock time actually elapsed. Hosts feed
+//! inputs and control commands through a [`DriverCommand`] channel and receive outputs as
+//! [`DriverEvent`]s on per-subscriber channels routed by [`InterestFilter`].
+
+use std::{
+    sync::mpsc::{self, Receiver, RecvTimeoutError, Sender},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use kitu_core::{KituError, Result, Tick};
+use kitu_osc_ir::OscBundle;
+use kitu_transport::Transport;
+
+use crate::{InterestFilter, Runtime, MAX_STEP_TICKS};
+
+/// Closure run on the driver thread with exclusive access to the runtime.
+pub type RuntimeTask<T> = Box<dyn FnOnce(&mut Runtime<T>) + Send>;
+
+/// Input or control command for a [`RuntimeDriver`], applied between updates in send order.
+pub enum DriverCommand<T: Transport> {
+    /// Enqueues an input bundle for the next tick.
+    Input(OscBundle),
+    /// Stops time-driven ticking; see [`Runtime::pause`].
+    Pause,
+    /// Resumes time-driven ticking; see [`Runtime::resume`].
+    Resume,
+    /// Runs exactly this many ticks, at most [`MAX_STEP_TICKS`], also while paused; see
+    /// [`Runtime::step`].
+    Step(u64),
+    /// Sets the time scale applied to elapsed time; see [`Runtime::set_time_scale`].
+    SetTimeScale(f32),
+    /// Runs a closure against the runtime, e.g. to read a snapshot or spawn objects.
+    Run(RuntimeTask<T>),
+    /// Sends the outputs that pass the filter to the given channel from now on.
+    Subscribe(InterestFilter, Sender<DriverEvent>),
+    /// Stops the driver thread.
+    Shutdown,
+}
+
+/// Something that happened on the driver thread, delivered to every subscriber.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DriverEvent {
+    /// Output bundles that passed the subscriber's interest filter, emitted up to `tick`.
+    Outputs { tick: Tick, bundles: Vec<OscBundle> },
+    /// A command or update failed at `tick`; the driver keeps running.
+    Error { tick: Tick, message: String },
+}
+
+struct Subscriber {
+    client: String,
+    sender: Sender<DriverEvent>,
+}
+
+/// Handle to a runtime ticking in real time on a background thread.
+///
+/// Dropping the handle stops the thread; [`shutdown`](Self::shutdown) also returns the runtime.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use kitu_osc_ir::{OscArg, OscBundle, OscMessage};
+/// use kitu_runtime::{build_runtime, DriverEvent, InterestFilter, RuntimeDriver};
+/// use kitu_transport::LocalChannel;
+///
+/// let driver = RuntimeDriver::spawn(build_runtime(LocalChannel::connected()));
+/// let outputs = driver.subscribe(InterestFilter::default()).unwrap();
+///
+/// let mut message = OscMessage::new("/input/move");
+/// message.push_arg(OscArg::Str("player:local".to_string()));
+/// message.push_arg(OscArg::Float(1.0));
+/// message.push_arg(OscArg::Float(0.0));
+/// let mut input = OscBundle::new();
+/// input.push(message);
+/// driver.send_input(input).unwrap();
+///
+/// let DriverEvent::Outputs { bundles, .. } = outputs.recv_timeout(Duration::from_secs(5)).unwrap()
+/// else {
+///     panic!("expected outputs");
+/// };
+/// assert_eq!(bundles[0].messages[0].address, "/render/player/transform");
+///
+/// let runtime = driver.shutdown().unwrap();
+/// assert!(runtime.current_tick().get() > 0);
+/// ```
+pub struct RuntimeDriver<T: Transport + Send + 'static> {
+    commands: Sender<DriverCommand<T>>,
+    thread: Option<JoinHandle<Runtime<T>>>,
+}
+
+impl<T: Transport + Send + 'static> RuntimeDriver<T> {
+    /// Moves `runtime` onto a new thread and starts ticking it in real time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operating system cannot create the thread.
+    pub fn spawn(runtime: Runtime<T>) -> Self {
+        let (commands, receiver) = mpsc::channel();
+        let thread = thread::Builder::new()
+            .name("kitu-runtime-driver".to_string())
+            .spawn(move || {
+                Driver {
+                    runtime,
+                    subscribers: Vec::new(),
+                    next_subscriber_id: 1,
+                }
+                .run(receiver)
+            })
+            .expect("spawn runtime driver thread");
+        Self {
+            commands,
+            thread: Some(thread),
+        }
+    }
+
+    /// Returns a sender for inputs and control commands that can be moved to other threads.
+    pub fn commands(&self) -> Sender<DriverCommand<T>> {
+        self.commands.clone()
+    }
+
+    /// Enqueues an input bundle for the next tick.
+    pub fn send_input(&self, input: OscBundle) -> Result<()> {
+        self.send(DriverCommand::Input(input))
+    }
+
+    /// Stops time-driven ticking until [`resume`](Self::resume).
+    pub fn pause(&self) -> Result<()> {
+        self.send(DriverCommand::Pause)
+    }
+
+    /// Resumes time-driven ticking.
+    pub fn resume(&self) -> Result<()> {
+        self.send(DriverCommand::Resume)
+    }
+
+    /// Runs exactly `count` ticks, also while paused.
+    ///
+    /// Counts above [`MAX_STEP_TICKS`] fail with [`KituError::InvalidInput`] without being sent.
+    pub fn step(&self, count: u64) -> Result<()> {
+        if count > MAX_STEP_TICKS {
+            return Err(KituError::InvalidInput("step count exceeds MAX_STEP_TICKS"));
+        }
+        self.send(DriverCommand::Step(count))
+    }
+
+    /// Sets the factor applied to elapsed time; invalid scales are reported as
+    /// [`DriverEvent::Error`].
+    pub fn set_time_scale(&self, scale: f32) -> Result<()> {
+        self.send(DriverCommand::SetTimeScale(scale))
+    }
+
+    /// Runs `task` on the driver thread between updates and returns its result.
+    ///
+    /// Blocks until the driver thread gets to it. Fails if the driver has stopped.
+    pub fn with_runtime<R: Send + 'static>(
+        &self,
+        task: impl FnOnce(&mut Runtime<T>) -> R + Send + 'static,
+    ) -> Result<R> {
+        let (reply, result) = mpsc::channel();
+        self.send(DriverCommand::Run(Box::new(move |runtime| {
+            let _ = reply.send(task(runtime));
+        })))?;
+        result.recv().map_err(|_| stopped())
+    }
+
+    /// Subscribes to the outputs that pass `filter`.
+    ///
+    /// The subscriber is registered with [`Runtime::set_client_interest`] under a generated
+    /// `driver-{n}` client id. Once its receiver is dropped it is unregistered the next time an
+    /// output is routed to it. Errors are sent to every subscriber. Fails if the filter is invalid
+    /// or the driver has stopped.
+    pub fn subscribe(&self, filter: InterestFilter) -> Result<Receiver<DriverEvent>> {
+        filter.validate()?;
+        let (sender, receiver) = mpsc::channel();
+        self.send(DriverCommand::Subscribe(filter, sender))?;
+        Ok(receiver)
+    }
+
+    /// Stops the driver thread and returns the runtime in its current state.
+    ///
+    /// Fails if the driver thread panicked.
+    pub fn shutdown(mut self) -> Result<Runtime<T>> {
+        let _ = self.commands.send(DriverCommand::Shutdown);
+        self.thread
+            .take()
+            .expect("driver thread is joined only once")
+            .join()
+            .map_err(|_| KituError::InvalidInput("runtime driver thread panicked"))
+    }
+
+    fn send(&self, command: DriverCommand<T>) -> Result<()> {
+        self.commands.send(command).map_err(|_| stopped())
+    }
+}
+
+impl<T: Transport + Send + 'static> Drop for RuntimeDriver<T> {
+    fn drop(&mut self) {
+        if let Some(thread) = self.thread.take() {
+            let _ = self.commands.send(DriverCommand::Shutdown);
+            let _ = thread.join();
+        }
+    }
+}
+
+fn stopped() -> KituError {
+    KituError::InvalidInput("runtime driver has stopped")
+}
+
+/// Runtime and subscribers owned by the driver thread.
+struct Driver<T: Transport> {
+    runtime: Runtime<T>,
+    subscribers: Vec<Subscriber>,
+    next_subscriber_id: u64,
+}
+
+impl<T: Transport> Driver<T> {
+    fn run(mut self, commands: Receiver<DriverCommand<T>>) -> Runtime<T> {
+        let frame = self.runtime.config().frame_time();
+        let mut last_update = Instant::now();
+        let mut deadline = last_update + frame;
+        loop {
+            let now = Instant::now();
+            if now >= deadline {
+                let elapsed = now - last_update;
+                last_update = now;
+                if let Err(error) = self.runtime.update(elapsed.as_secs_f32()) {
+                    self.publish_error(&error);
+                }
+                self.publish_outputs();
+                deadline = next_deadline(deadline, now, frame);
+                continue;
+            }
+            match commands.recv_timeout(deadline - now) {
+                Ok(command) => {
+                    if !self.apply(command) {
+                        break;
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+        self.runtime
+    }
+
+    /// Applies one command; returns `false` once the driver should stop.
+    fn apply(&mut self, command: DriverCommand<T>) -> bool {
+        let runtime = &mut self.runtime;
+        let result = match command {
+            DriverCommand::Input(input) => {
+                runtime.enqueue_input(input);
+                Ok(())
+            }
+            DriverCommand::Pause => {
+                runtime.pause();
+                Ok(())
+            }
+            DriverCommand::Resume => {
+                runtime.resume();
+                Ok(())
+            }
+            DriverCommand::Step(count) => runtime.step(count),
+            DriverCommand::SetTimeScale(scale) => runtime.set_time_scale(scale),
+            DriverCommand::Run(task) => {
+                task(runtime);
+                Ok(())
+            }
+            DriverCommand::Subscribe(filter, sender) => {
+                let client = format!("driver-{}", self.next_subscriber_id);
+                self.next_subscriber_id += 1;
+                let result = runtime.set_client_interest(client.clone(), filter);
+                if result.is_ok() {
+                    self.subscribers.push(Subscriber { client, sender });
+                }
+                result
+            }
+            DriverCommand::Shutdown => return false,
+        };
+        if let Err(error) = result {
+            self.publish_error(&error);
+        }
+        self.publish_outputs();
+        true
+    }
+
+    /// Routes pending outputs to the subscribers, dropping those that hung up.
+    fn publish_outputs(&mut self) {
+        let tick = self.runtime.current_tick();
+        let mut routed = self.runtime.drain_client_outputs();
+        let runtime = &mut self.runtime;
+        self.subscribers.retain(|subscriber| {
+            let bundles = routed.remove(&subscriber.client).unwrap_or_default();
+            let alive = bundles.is_empty()
+                || subscriber
+                    .sender
+                    .send(DriverEvent::Outputs { tick, bundles })
+                    .is_ok();
+            if !alive {
+                runtime.remove_client_interest(&subscriber.client);
+            }
+            alive
+        });
+    }
+
+    fn publish_error(&mut self, error: &KituError) {
+        let tick = self.runtime.current_tick();
+        let message = error.to_string();
+        for subscriber in &self.subscribers {
+            let _ = subscriber.sender.send(DriverEvent::Error {
+                tick,
+                message: message.clone(),
+            });
+        }
+    }
+}
+
+/// Schedules the next wake-up one frame after the previous deadline.
+///
+/// A driver that fell more than a frame behind restarts the schedule from `now`; the time it
+/// missed still reaches [`Runtime::update`] through the elapsed wall-clock time.
+fn next_deadline(deadline: Instant, now: Instant, frame: Duration) -> Instant {
+    let next = deadline + frame;
+    if next <= now {
+        now + frame
+    } else {
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deadlines_absorb_late_wakeups_without_drifting() {
+        let frame = Duration::from_millis(10);
+        let start = Instant::now();
+
+        // Waking 3ms late keeps the original grid: the next deadline is still start + 20ms.
+        let late = next_deadline(
+            start + frame,
+            start + frame + Duration::from_millis(3),
+            frame,
+        );
+        assert_eq!(late, start + frame * 2);
+
+        // Falling several frames behind restarts the grid instead of bursting through it.
+        let behind = start + frame * 5;
+        assert_eq!(next_deadline(start + frame, behind, frame), behind + frame);
+    }
+}
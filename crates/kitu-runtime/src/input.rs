@@ -0,0 +1,365 @@
+//! Registry of typed input handlers keyed by OSC address pattern.
+//!
+//! Every committed input message is routed to the first handler whose pattern matches its
+//! address. Handlers parse and validate the message into a typed value before the tick runs.
+//! Messages that fail are reported as [`InputRejection`]s; the [`InputRejectionPolicy`] decides
+//! whether the rest of the batch still runs. Parsed values are sent as ECS events right before
+//! dispatch, so systems read them with an [`EventCursor`](kitu_ecs::EventCursor) in the same
+//! tick.
+//!
+//! The runtime registers the `/input/move` slice first, so it always owns that address.
+
+use kitu_core::{KituError, Result, Tick};
+use kitu_ecs::{EcsWorld, Event};
+use kitu_osc_ir::{OscArg, OscBundle, OscMessage};
+use kitu_transport::Transport;
+
+use crate::{parse_move_input, Runtime};
+
+/// What a tick does when some of its committed input messages fail to parse.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InputRejectionPolicy {
+    /// Fails the tick and drops the whole committed batch, leaving the world untouched.
+    #[default]
+    RejectBatch,
+    /// Drops only the failing messages and runs the tick with the rest.
+    RejectMessage,
+}
+
+/// Diagnostic for one committed input message that failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputRejection {
+    /// Tick whose committed batch held the message.
+    pub tick: Tick,
+    /// Position of the message in the batch, counting every message of every bundle in order.
+    pub index: usize,
+    /// OSC address of the message.
+    pub address: String,
+    /// Why the handler rejected it.
+    pub reason: String,
+}
+
+/// A message whose handler returned an error.
+pub(crate) struct RejectedMessage {
+    index: usize,
+    address: String,
+    error: KituError,
+}
+
+type SendEvent = Box<dyn FnOnce(&mut EcsWorld)>;
+type ParseFn = Box<dyn Fn(&OscMessage) -> Result<ParsedInput> + Send + Sync>;
+
+/// One validated input message.
+pub(crate) enum ParsedInput {
+    /// `/input/move [entity_id, x, y]`, applied by the runtime after dispatch.
+    Move(String, f32, f32),
+    /// A project input, delivered as an ECS event before dispatch.
+    Event(SendEvent),
+}
+
+/// All inputs of one committed batch, in message order.
+#[derive(Default)]
+pub(crate) struct ParsedInputs {
+    pub(crate) moves: Vec<(String, f32, f32)>,
+    pub(crate) events: Vec<SendEvent>,
+    pub(crate) rejected: Vec<RejectedMessage>,
+}
+
+struct InputHandlerEntry {
+    pattern: String,
+    parse: ParseFn,
+}
+
+pub(crate) struct InputHandlers {
+    entries: Vec<InputHandlerEntry>,
+}
+
+impl Default for InputHandlers {
+    fn default() -> Self {
+        let mut handlers = Self {
+            entries: Vec::new(),
+        };
+        handlers.entries.push(InputHandlerEntry {
+            pattern: "/input/move".to_string(),
+            parse: Box::new(|message| {
+                let (entity_id, x, y) = parse_move_input(message)?;
+                Ok(ParsedInput::Move(entity_id, x, y))
+            }),
+        });
+        handlers
+    }
+}
+
+impl InputHandlers {
+    fn register(&mut self, pattern: &str, parse: ParseFn) -> Result<()> {
+        validate_pattern(pattern)?;
+        if self.entries.iter().any(|entry| entry.pattern == pattern) {
+            return Err(KituError::InvalidInput(
+                "input handler pattern is already registered",
+            ));
+        }
+        self.entries.push(InputHandlerEntry {
+            pattern: pattern.to_string(),
+            parse,
+        });
+        Ok(())
+    }
+
+    fn handler_for(&self, address: &str) -> Option<&InputHandlerEntry> {
+        self.entries
+            .iter()
+            .find(|entry| address_matches(&entry.pattern, address))
+    }
+
+    /// Parses every handled message in `bundles`, collecting failures instead of stopping.
+    pub(crate) fn parse_batch(&self, bundles: &[OscBundle]) -> ParsedInputs {
+        let mut parsed = ParsedInputs::default();
+        let messages = bundles.iter().flat_map(|bundle| &bundle.messages);
+        for (index, message) in messages.enumerate() {
+            let Some(entry) = self.handler_for(&message.address) else {
+                continue;
+            };
+            match (entry.parse)(message) {
+                Ok(ParsedInput::Move(entity_id, x, y)) => parsed.moves.push((entity_id, x, y)),
+                Ok(ParsedInput::Event(send)) => parsed.events.push(send),
+                Err(error) => parsed.rejected.push(RejectedMessage {
+                    index,
+                    address: message.address.clone(),
+                    error,
+                }),
+            }
+        }
+        parsed
+    }
+}
+
+impl<T: Transport> Runtime<T> {
+    /// Registers a handler that parses inputs whose address matches `pattern` into events `E`.
+    ///
+    /// Patterns are OSC-style addresses compared segment by segment; inside a segment `*`
+    /// matches any run of characters and `?` matches one character. A message goes to the
+    /// first registered handler that matches, and `/input/move` always stays with the built-in
+    /// move slice. Addresses without a handler are ignored.
+    ///
+    /// Each tick parses its whole committed batch before anything runs, then sends every parsed
+    /// value with [`EcsWorld::send_event`] right before dispatch, in message order.
+    ///
+    /// Fails if `pattern` does not start with `/` or is already registered.
+    ///
+    /// What happens to messages a handler rejects depends on the
+    /// [`InputRejectionPolicy`]; see [`set_input_rejection_policy`](Self::set_input_rejection_policy).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_core::KituError;
+    /// use kitu_osc_ir::{OscArg, OscBundle, OscMessage};
+    /// use kitu_runtime::build_runtime;
+    /// use kitu_transport::LocalChannel;
+    ///
+    /// struct Fire {
+    ///     weapon: String,
+    /// }
+    ///
+    /// let mut runtime = build_runtime(LocalChannel::connected());
+    /// runtime
+    ///     .register_input_handler("/input/fire/*", |message| match message.args.first() {
+    ///         Some(OscArg::Str(weapon)) => Ok(Fire { weapon: weapon.clone() }),
+    ///         _ => Err(KituError::InvalidInput("/input/fire expects a weapon")),
+    ///     })
+    ///     .unwrap();
+    ///
+    /// let mut message = OscMessage::new("/input/fire/primary");
+    /// message.push_arg(OscArg::Str("laser".to_string()));
+    /// let mut input = OscBundle::new();
+    /// input.push(message);
+    /// runtime.enqueue_input(input);
+    /// runtime.tick_once().unwrap();
+    ///
+    /// let fired: Vec<&str> = runtime
+    ///     .world_mut()
+    ///     .iter_events::<Fire>()
+    ///     .map(|fire| fire.weapon.as_str())
+    ///     .collect();
+    /// assert_eq!(fired, ["laser"]);
+    /// ```
+    pub fn register_input_handler<E, F>(&mut self, pattern: &str, parse: F) -> Result<()>
+    where
+        E: Event,
+        F: Fn(&OscMessage) -> Result<E> + Send + Sync + 'static,
+    {
+        self.input_handlers.register(
+            pattern,
+            Box::new(move |message| {
+                let event = parse(message)?;
+                Ok(ParsedInput::Event(Box::new(move |world: &mut EcsWorld| {
+                    world.send_event(event)
+                })))
+            }),
+        )
+    }
+
+    /// Chooses whether a malformed input fails its whole tick or only itself.
+    ///
+    /// Under either policy every failing message is recorded for
+    /// [`drain_input_rejections`](Self::drain_input_rejections) and staged as a
+    /// `/debug/input/rejected [tick, index, address, reason]` output. With
+    /// [`InputRejectionPolicy::RejectBatch`], the default, the tick then fails and its batch is
+    /// dropped; with [`InputRejectionPolicy::RejectMessage`] the tick runs the valid messages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_osc_ir::{OscArg, OscBundle, OscMessage};
+    /// use kitu_runtime::{build_runtime, InputRejectionPolicy};
+    /// use kitu_transport::LocalChannel;
+    ///
+    /// let mut runtime = build_runtime(LocalChannel::connected());
+    /// runtime.set_input_rejection_policy(InputRejectionPolicy::RejectMessage);
+    ///
+    /// let mut valid = OscMessage::new("/input/move");
+    /// valid.push_arg(OscArg::Str("player:a".to_string()));
+    /// valid.push_arg(OscArg::Float(1.0));
+    /// valid.push_arg(OscArg::Float(0.0));
+    /// let mut input = OscBundle::new();
+    /// input.push(OscMessage::new("/input/move"));
+    /// input.push(valid);
+    /// runtime.enqueue_input(input);
+    /// runtime.tick_once().unwrap();
+    ///
+    /// assert_eq!(runtime.inspect_world_state().unwrap().objects[0].id, "player:a");
+    /// let rejections = runtime.drain_input_rejections();
+    /// assert_eq!((rejections[0].index, rejections[0].address.as_str()), (0, "/input/move"));
+    /// ```
+    pub fn set_input_rejection_policy(&mut self, policy: InputRejectionPolicy) {
+        self.input_rejection_policy = policy;
+    }
+
+    /// Returns the active [`InputRejectionPolicy`].
+    pub fn input_rejection_policy(&self) -> InputRejectionPolicy {
+        self.input_rejection_policy
+    }
+
+    /// Drains recorded input rejections in the order they happened.
+    pub fn drain_input_rejections(&mut self) -> Vec<InputRejection> {
+        std::mem::take(&mut self.input_rejections)
+    }
+
+    /// Parses a batch for `tick`, reporting rejected messages and applying the policy.
+    ///
+    /// `bundles` are reported as if they followed `first_index` messages already in the tick's
+    /// batch, so a late bundle appended to a recorded batch gets its position in that batch.
+    pub(crate) fn parse_inputs(
+        &mut self,
+        bundles: &[OscBundle],
+        tick: Tick,
+        first_index: usize,
+    ) -> Result<ParsedInputs> {
+        let mut parsed = self.input_handlers.parse_batch(bundles);
+        let rejected = std::mem::take(&mut parsed.rejected);
+        let mut first_error = None;
+        for message in rejected {
+            let rejection = InputRejection {
+                tick,
+                index: first_index + message.index,
+                address: message.address,
+                reason: message.error.to_string(),
+            };
+            let output = input_rejected_message(&rejection)?;
+            self.queue_output(output);
+            self.input_rejections.push(rejection);
+            first_error.get_or_insert(message.error);
+        }
+        match first_error {
+            Some(error) if self.input_rejection_policy == InputRejectionPolicy::RejectBatch => {
+                Err(error)
+            }
+            _ => Ok(parsed),
+        }
+    }
+}
+
+fn input_rejected_message(rejection: &InputRejection) -> Result<OscBundle> {
+    let mut message = OscMessage::new("/debug/input/rejected");
+    let tick = i64::try_from(rejection.tick.get())
+        .map_err(|_| KituError::InvalidInput("tick is too large to encode"))?;
+    message.push_arg(OscArg::Int64(tick));
+    message.push_arg(OscArg::Int(
+        i32::try_from(rejection.index).unwrap_or(i32::MAX),
+    ));
+    message.push_arg(OscArg::Str(rejection.address.clone()));
+    message.push_arg(OscArg::Str(rejection.reason.clone()));
+
+    let mut bundle = OscBundle::new();
+    bundle.push(message);
+    Ok(bundle)
+}
+
+fn validate_pattern(pattern: &str) -> Result<()> {
+    if !pattern.starts_with('/') {
+        return Err(KituError::InvalidInput(
+            "input address pattern must start with `/`",
+        ));
+    }
+    Ok(())
+}
+
+fn address_matches(pattern: &str, address: &str) -> bool {
+    let mut patterns = pattern.split('/');
+    let mut segments = address.split('/');
+    loop {
+        match (patterns.next(), segments.next()) {
+            (None, None) => return true,
+            (Some(pattern), Some(segment)) if segment_matches(pattern, segment) => {}
+            _ => return false,
+        }
+    }
+}
+
+fn segment_matches(pattern: &str, segment: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let segment: Vec<char> = segment.chars().collect();
+    // Classic wildcard matching with backtracking to the most recent `*`.
+    let (mut p, mut s) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while s < segment.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, s));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == segment[s] => {
+                p += 1;
+                s += 1;
+            }
+            _ => match star {
+                Some((star_p, star_s)) => {
+                    p = star_p + 1;
+                    s = star_s + 1;
+                    star = Some((star_p, star_s + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn patterns_match_whole_segments_with_wildcards() {
+        assert!(address_matches("/input/jump", "/input/jump"));
+        assert!(address_matches("/input/*", "/input/fire"));
+        assert!(address_matches("/input/fire/*", "/input/fire/"));
+        assert!(address_matches("/input/f?re", "/input/fire"));
+        assert!(address_matches("/input/*-start", "/input/crouch-start"));
+
+        assert!(!address_matches("/input/*", "/input/fire/primary"));
+        assert!(!address_matches("/input/jump", "/input/jumps"));
+        assert!(!address_matches("/input/f?re", "/input/fre"));
+        assert!(!address_matches("/input/*/x", "/input/fire"));
+    }
+}
@@ -0,0 +1,381 @@
+//! Per-client interest management for runtime outputs.
+//!
+//! Hosts register each connected client with an [`InterestFilter`] and route output bundles with
+//! [`Runtime::drain_client_outputs`] or [`Runtime::interested_clients`] instead of broadcasting
+//! every bundle to everyone.
+//!
+//! A message is *about* every world object named by one of its string arguments, such as the id
+//! in `/render/player/transform [id, tick, x, y, z]` or both ids of `/game/collision`. Kind and
+//! radius constraints apply to those objects: the message is visible when at least one of them
+//! passes. Messages that name no live object, including despawn notices for objects already
+//! removed, are only subject to the address prefixes.
+//!
+//! Hosts that also send world state narrow it with [`InterestFilter::visible_snapshot`], which
+//! applies the same kind and radius constraints to the snapshot's objects.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use kitu_core::{KituError, Result};
+use kitu_ecs::{Entity, WorldObject, WorldSnapshot};
+use kitu_osc_ir::{OscArg, OscBundle, OscMessage};
+use kitu_transport::Transport;
+
+use crate::Runtime;
+
+/// What one client wants to receive from the runtime output path.
+///
+/// The default filter accepts every message.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InterestFilter {
+    /// OSC address prefixes to accept, such as `/render/`; empty accepts every address.
+    pub address_prefixes: Vec<String>,
+    /// Object kinds to accept, such as `player`; empty accepts every kind.
+    pub kinds: Vec<String>,
+    /// Only accept objects within a radius of another object.
+    pub radius: Option<InterestRadius>,
+}
+
+/// Area of interest around one world object, usually the client's own player.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterestRadius {
+    /// Id of the world object at the center of the area.
+    pub center: String,
+    /// Largest distance, in world units, at which other objects are still visible.
+    pub radius: f32,
+}
+
+impl InterestFilter {
+    /// Adds an accepted OSC address prefix.
+    pub fn with_address_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.address_prefixes.push(prefix.into());
+        self
+    }
+
+    /// Adds an accepted object kind.
+    pub fn with_kind(mut self, kind: impl Into<String>) -> Self {
+        self.kinds.push(kind.into());
+        self
+    }
+
+    /// Limits objects to those within `radius` of the world object `center`.
+    pub fn within_radius(mut self, center: impl Into<String>, radius: f32) -> Self {
+        self.radius = Some(InterestRadius {
+            center: center.into(),
+            radius,
+        });
+        self
+    }
+
+    /// Checks that the radius, if any, is finite and non-negative.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(area) = &self.radius {
+            if !(area.radius.is_finite() && area.radius >= 0.0) {
+                return Err(KituError::InvalidInput(
+                    "interest radius must be finite and non-negative",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `snapshot` without the objects this filter does not accept, along with their
+    /// component values.
+    ///
+    /// Objects are checked against the kinds and the radius around the center object as found in
+    /// the snapshot; address prefixes do not apply. Resources and the allocator state are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_runtime::{build_runtime, InterestFilter};
+    /// use kitu_transport::LocalChannel;
+    ///
+    /// let mut runtime = build_runtime(LocalChannel::connected());
+    /// runtime.spawn_world_object("npc", 1.0, 0.0, 0.0).unwrap();
+    /// runtime.spawn_world_object("wall", 2.0, 0.0, 0.0).unwrap();
+    ///
+    /// let snapshot = runtime.inspect_world_state().unwrap();
+    /// let visible = InterestFilter::default().with_kind("npc").visible_snapshot(&snapshot);
+    /// assert_eq!(visible.objects.len(), 1);
+    /// assert_eq!(visible.objects[0].kind, "npc");
+    /// ```
+    pub fn visible_snapshot(&self, snapshot: &WorldSnapshot) -> WorldSnapshot {
+        let center = self.radius.as_ref().and_then(|area| {
+            snapshot
+                .objects
+                .iter()
+                .find(|object| object.id == area.center)
+        });
+        let objects: Vec<WorldObject> = snapshot
+            .objects
+            .iter()
+            .filter(|object| self.accepts_object(object, center))
+            .cloned()
+            .collect();
+        let mut visible = WorldSnapshot {
+            objects,
+            ..snapshot.clone()
+        };
+        let entities: BTreeSet<Entity> =
+            visible.objects.iter().map(|object| object.entity).collect();
+        for values in visible.components.values_mut() {
+            values.retain(|entity, _| entities.contains(entity));
+        }
+        visible.components.retain(|_, values| !values.is_empty());
+        visible
+    }
+
+    fn accepts_address(&self, address: &str) -> bool {
+        self.address_prefixes.is_empty()
+            || self
+                .address_prefixes
+                .iter()
+                .any(|prefix| address.starts_with(prefix.as_str()))
+    }
+
+    fn accepts_object(&self, object: &WorldObject, center: Option<&WorldObject>) -> bool {
+        if !self.kinds.is_empty() && !self.kinds.contains(&object.kind) {
+            return false;
+        }
+        let Some(area) = &self.radius else {
+            return true;
+        };
+        if object.id == area.center {
+            return true;
+        }
+        // Until the center object exists, only messages about the center itself get through.
+        let Some(center) = center else {
+            return false;
+        };
+        let (a, b) = (object.transform, center.transform);
+        let (dx, dy, dz) = (a.x - b.x, a.y - b.y, a.z - b.z);
+        dx * dx + dy * dy + dz * dz <= area.radius * area.radius
+    }
+}
+
+impl<T: Transport> Runtime<T> {
+    /// Registers or replaces the interest filter of `client`.
+    ///
+    /// Fails if the filter's radius is negative or not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_osc_ir::{OscArg, OscBundle, OscMessage};
+    /// use kitu_runtime::{build_runtime, InterestFilter};
+    /// use kitu_transport::LocalChannel;
+    ///
+    /// let mut runtime = build_runtime(LocalChannel::connected());
+    /// runtime
+    ///     .set_client_interest(
+    ///         "local",
+    ///         InterestFilter::default().within_radius("player:local", 10.0),
+    ///     )
+    ///     .unwrap();
+    /// runtime.set_client_interest("spectator", InterestFilter::default()).unwrap();
+    ///
+    /// let mut input = OscBundle::new();
+    /// for (id, x) in [("player:local", 1.0), ("ally", 3.0), ("rival", 40.0)] {
+    ///     let mut message = OscMessage::new("/input/move");
+    ///     message.push_arg(OscArg::Str(id.to_string()));
+    ///     message.push_arg(OscArg::Float(x));
+    ///     message.push_arg(OscArg::Float(0.0));
+    ///     input.push(message);
+    /// }
+    /// runtime.enqueue_input(input);
+    /// runtime.tick_once().unwrap();
+    ///
+    /// let routed = runtime.drain_client_outputs();
+    /// assert_eq!(routed["spectator"].len(), 3);
+    /// assert_eq!(routed["local"].len(), 2);
+    /// ```
+    pub fn set_client_interest(
+        &mut self,
+        client: impl Into<String>,
+        filter: InterestFilter,
+    ) -> Result<()> {
+        filter.validate()?;
+        self.interests.insert(client.into(), filter);
+        Ok(())
+    }
+
+    /// Unregisters `client`, returning its filter.
+    pub fn remove_client_interest(&mut self, client: &str) -> Option<InterestFilter> {
+        self.interests.remove(client)
+    }
+
+    /// Returns the interest filter of `client`, if it is registered.
+    pub fn client_interest(&self, client: &str) -> Option<&InterestFilter> {
+        self.interests.get(client)
+    }
+
+    /// Returns the registered clients that can see `message`, in client id order.
+    ///
+    /// Visibility is evaluated against the current world, so hosts should route outputs right
+    /// after draining them.
+    pub fn interested_clients(&self, message: &OscMessage) -> Vec<String> {
+        let subjects = self.message_subjects(message);
+        self.interests
+            .iter()
+            .filter(|(_, filter)| self.is_visible(filter, message, &subjects))
+            .map(|(client, _)| client.clone())
+            .collect()
+    }
+
+    /// Drains all emitted output bundles and routes them to the registered clients.
+    ///
+    /// Each client receives, in FIFO order, the bundles that contain at least one message it can
+    /// see, trimmed to those messages. Every registered client has an entry, even when nothing
+    /// reached it; outputs are dropped if no client is registered.
+    pub fn drain_client_outputs(&mut self) -> BTreeMap<String, Vec<OscBundle>> {
+        let bundles = self.drain_output_buffer();
+        let mut routed: BTreeMap<String, Vec<OscBundle>> = self
+            .interests
+            .keys()
+            .map(|client| (client.clone(), Vec::new()))
+            .collect();
+        for bundle in bundles {
+            let mut per_client: BTreeMap<&str, OscBundle> = BTreeMap::new();
+            for message in &bundle.messages {
+                let subjects = self.message_subjects(message);
+                for (client, filter) in &self.interests {
+                    if self.is_visible(filter, message, &subjects) {
+                        per_client
+                            .entry(client.as_str())
+                            .or_default()
+                            .push(message.clone());
+                    }
+                }
+            }
+            for (client, trimmed) in per_client {
+                if let Some(outputs) = routed.get_mut(client) {
+                    outputs.push(trimmed);
+                }
+            }
+        }
+        routed
+    }
+
+    fn message_subjects(&self, message: &OscMessage) -> Vec<WorldObject> {
+        message
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                OscArg::Str(id) => self.world.world_object(id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn is_visible(
+        &self,
+        filter: &InterestFilter,
+        message: &OscMessage,
+        subjects: &[WorldObject],
+    ) -> bool {
+        if !filter.accepts_address(&message.address) {
+            return false;
+        }
+        if subjects.is_empty() {
+            return true;
+        }
+        let center = filter
+            .radius
+            .as_ref()
+            .and_then(|area| self.world.world_object(&area.center));
+        subjects
+            .iter()
+            .any(|object| filter.accepts_object(object, center.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kitu_ecs::WorldTransform;
+
+    fn object(id: &str, kind: &str, x: f32) -> WorldObject {
+        WorldObject {
+            entity: Entity::from_bits(0),
+            id: id.to_string(),
+            named: true,
+            kind: kind.to_string(),
+            transform: WorldTransform::new(x, 0.0, 0.0),
+            parent: None,
+            local: None,
+            collider: None,
+        }
+    }
+
+    #[test]
+    fn filters_combine_prefixes_kinds_and_radius() {
+        let filter = InterestFilter::default()
+            .with_address_prefix("/render/")
+            .with_kind("player")
+            .within_radius("me", 5.0);
+        let me = object("me", "player", 0.0);
+
+        assert!(filter.accepts_address("/render/player/transform"));
+        assert!(!filter.accepts_address("/game/collision"));
+        assert!(filter.accepts_object(&object("ally", "player", 5.0), Some(&me)));
+        assert!(!filter.accepts_object(&object("rival", "player", 5.5), Some(&me)));
+        assert!(!filter.accepts_object(&object("crate", "prop", 1.0), Some(&me)));
+        assert!(filter.accepts_object(&me, None));
+        assert!(!filter.accepts_object(&object("ally", "player", 1.0), None));
+    }
+
+    #[test]
+    fn visible_snapshots_keep_accepted_objects_and_their_components() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Health(u32);
+
+        let mut runtime = crate::build_runtime(kitu_transport::LocalChannel::connected());
+        runtime
+            .world_mut()
+            .register_serializable_component::<Health>("health")
+            .unwrap();
+        let mut spawned = Vec::new();
+        for (kind, x) in [
+            ("player", 0.0),
+            ("player", 4.0),
+            ("player", 9.0),
+            ("prop", 1.0),
+        ] {
+            let object = runtime.spawn_world_object(kind, x, 0.0, 0.0).unwrap();
+            runtime
+                .world_mut()
+                .insert_component(object.entity, Health(1))
+                .unwrap();
+            spawned.push(object);
+        }
+        let snapshot = runtime.inspect_world_state().unwrap();
+
+        let filter = InterestFilter::default()
+            .with_address_prefix("/game/")
+            .with_kind("player")
+            .within_radius(spawned[0].id.clone(), 5.0);
+        let visible = filter.visible_snapshot(&snapshot);
+        assert_eq!(visible.objects, spawned[..2]);
+        assert_eq!(
+            visible.components["health"].keys().collect::<Vec<_>>(),
+            [&spawned[0].entity, &spawned[1].entity]
+        );
+        assert_eq!(visible.resources, snapshot.resources);
+        assert_eq!(
+            InterestFilter::default().visible_snapshot(&snapshot),
+            snapshot
+        );
+    }
+
+    #[test]
+    fn radius_must_be_finite_and_non_negative() {
+        for radius in [-1.0, f32::NAN, f32::INFINITY] {
+            let filter = InterestFilter::default().within_radius("me", radius);
+            assert!(filter.validate().is_err());
+        }
+        assert!(InterestFilter::default()
+            .within_radius("me", 0.0)
+            .validate()
+            .is_ok());
+    }
+}
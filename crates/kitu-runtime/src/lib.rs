@@ -10,25 +10,48 @@
 //! (`kitu-osc-ir`), and future data or scripting layers. See `doc/crates-overview.md` for how the
 //! runtime coordinates the workspace crates.
 
+mod collision;
+mod driver;
+mod input;
+mod interest;
+mod movement;
+mod numeric;
+mod rng;
+mod rollback;
+
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::{BTreeMap, HashMap, VecDeque},
     time::Duration,
 };
 
+pub use collision::CollisionSettings;
+pub use driver::{DriverCommand, DriverEvent, RuntimeDriver, RuntimeTask};
+use input::{InputHandlers, ParsedInputs};
+pub use input::{InputRejection, InputRejectionPolicy};
+pub use interest::{InterestFilter, InterestRadius};
 use kitu_app_actions::{
     kitu_general_catalog, load_project_actions_from_toml, ActionValue, AppActionCatalog,
     AppActionError, AppActionResult,
 };
+pub use kitu_core::{DeterministicRng, Fixed, FixedVec3, RngStreams};
 use kitu_core::{KituError, Result, Tick};
-use kitu_ecs::EcsWorld;
-pub use kitu_ecs::{WorldObject, WorldSnapshot, WorldTransform};
+pub use kitu_ecs::{
+    Collider, ColliderShape, Contact, Entity, LocalTransform, Quat, Vec3, WorldDelta, WorldObject,
+    WorldObjectChange, WorldSnapshot, WorldTransform,
+};
+use kitu_ecs::{EcsWorld, ObjectKind};
 use kitu_osc_ir::{OscArg, OscBundle, OscMessage};
 use kitu_transport::{Transport, TransportEvent};
+pub use movement::{KinematicBody, KinematicSettings, KINEMATIC_BODIES_KEY};
+pub use numeric::{NumericMode, FIXED_POSITIONS_KEY};
+pub use rng::RNG_STREAMS_KEY;
+use rollback::{RollbackFrame, RollbackHistory};
 
 #[derive(Default)]
 struct AuthoritativeInputQueue {
     committed_batch: VecDeque<OscBundle>,
     pending_queue: VecDeque<OscBundle>,
+    scheduled: BTreeMap<Tick, Vec<OscBundle>>,
 }
 
 impl AuthoritativeInputQueue {
@@ -36,9 +59,16 @@ impl AuthoritativeInputQueue {
         self.pending_queue.push_back(input);
     }
 
-    fn commit_next_tick_batch(&mut self) {
+    fn schedule(&mut self, tick: Tick, input: OscBundle) {
+        self.scheduled.entry(tick).or_default().push(input);
+    }
+
+    fn commit_next_tick_batch(&mut self, tick: Tick) {
         self.committed_batch.clear();
         self.committed_batch.append(&mut self.pending_queue);
+        if let Some(scheduled) = self.scheduled.remove(&tick) {
+            self.committed_batch.extend(scheduled);
+        }
     }
 
     fn drain_committed(&mut self) -> Vec<OscBundle> {
@@ -49,9 +79,26 @@ impl AuthoritativeInputQueue {
         self.committed_batch.iter().cloned().collect()
     }
 
+    fn discard_pending_moves_for(&mut self, entity_id: &str) {
+        let scheduled = self.scheduled.values_mut().flatten();
+        for bundle in self.pending_queue.iter_mut().chain(scheduled) {
+            bundle.messages.retain(|message| {
+                message.address != "/input/move"
+                    || !matches!(message.args.first(), Some(OscArg::Str(id)) if id == entity_id)
+            });
+        }
+        self.pending_queue
+            .retain(|bundle| !bundle.messages.is_empty());
+        self.scheduled.retain(|_, bundles| {
+            bundles.retain(|bundle| !bundle.messages.is_empty());
+            !bundles.is_empty()
+        });
+    }
+
     fn clear(&mut self) {
         self.committed_batch.clear();
         self.pending_queue.clear();
+        self.scheduled.clear();
     }
 }
 
@@ -61,23 +108,6 @@ struct OutputBuffer {
     visible: VecDeque<OscBundle>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
-struct PlayerTransform {
-    x: f32,
-    y: f32,
-    z: f32,
-}
-
-impl Default for PlayerTransform {
-    fn default() -> Self {
-        Self {
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
-        }
-    }
-}
-
 impl OutputBuffer {
     fn stage(&mut self, output: OscBundle) {
         self.staged.push_back(output);
@@ -108,12 +138,26 @@ pub struct RuntimeConfig {
     /// Target tick rate in Hertz. Each tick advances the world by
     /// `1.0 / tick_rate_hz` seconds.
     pub tick_rate_hz: u32,
+    /// Most ticks a single [`Runtime::update`] may run; `0` removes the limit.
+    ///
+    /// The budget keeps one long hitch from turning into hundreds of catch-up ticks, each of
+    /// which makes the next frame slower still.
+    pub max_ticks_per_update: u32,
+    /// What happens to accumulated time the tick budget could not cover.
+    pub excess_time: ExcessTimePolicy,
 }
 
 impl RuntimeConfig {
     /// Creates a configuration with a default 60Hz tick rate.
+    ///
+    /// Updates are not capped, so every accumulated frame runs; set
+    /// [`max_ticks_per_update`](Self::max_ticks_per_update) to bound catch-up after a hitch.
     pub const fn default_60hz() -> Self {
-        Self { tick_rate_hz: 60 }
+        Self {
+            tick_rate_hz: 60,
+            max_ticks_per_update: 0,
+            excess_time: ExcessTimePolicy::Drop,
+        }
     }
 
     /// Duration for a single frame.
@@ -122,18 +166,74 @@ impl RuntimeConfig {
     }
 }
 
+/// Handling of accumulated time left over once [`RuntimeConfig::max_ticks_per_update`] is hit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExcessTimePolicy {
+    /// Discards every whole frame beyond the budget. The simulation falls behind wall-clock
+    /// time once and then runs in real time again.
+    #[default]
+    Drop,
+    /// Keeps all of it, so later updates catch up at the budget's pace until the backlog clears.
+    Carry,
+    /// Keeps at most one budget's worth and discards the rest, so a sustained overload plays
+    /// back in slow motion while a short hitch is still caught up over the next update.
+    SlowDown,
+}
+
+/// Outcome of one [`Runtime::update`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UpdateReport {
+    /// Ticks executed by this update.
+    pub ticks: u32,
+    /// Whole frames still owed when the tick budget stopped the update; `0` when it did not.
+    pub overrun_ticks: u64,
+    /// Accumulated time discarded by the [`ExcessTimePolicy`].
+    pub dropped: Duration,
+    /// Accumulated time left for later updates.
+    pub backlog: Duration,
+    /// Whether a tick of this update spawned or despawned a world object or changed its
+    /// transform or kind, as seen by ECS change detection.
+    pub objects_changed: bool,
+}
+
+impl UpdateReport {
+    /// Returns whether the tick budget cut this update short.
+    pub fn overran(&self) -> bool {
+        self.overrun_ticks > 0
+    }
+}
+
+/// Most ticks one [`Runtime::step`] may run: ten seconds at 60Hz.
+///
+/// Steps run synchronously, so an unbounded count from an admin action or a host binding could
+/// stall the caller for arbitrarily long.
+pub const MAX_STEP_TICKS: u64 = 600;
+
 /// Central orchestrator tying together ECS and message transport.
 pub struct Runtime<T: Transport> {
     config: RuntimeConfig,
     tick: Tick,
     accumulator: Duration,
+    paused: bool,
+    time_scale: f32,
     transport: T,
     world: EcsWorld,
     inputs: AuthoritativeInputQueue,
     committed_input_tick: Option<Tick>,
     outputs: OutputBuffer,
-    player_transforms: HashMap<String, PlayerTransform>,
     app_actions: AppActionCatalog,
+    state_hash_interval: u64,
+    state_hashes: Vec<(Tick, u64)>,
+    rollback: RollbackHistory,
+    input_handlers: InputHandlers,
+    input_rejection_policy: InputRejectionPolicy,
+    input_rejections: Vec<InputRejection>,
+    kinematic: Option<KinematicSettings>,
+    collisions: Option<CollisionSettings>,
+    /// Contacts of the last simulated tick, which systems read as events in the next one.
+    last_contacts: Vec<Contact>,
+    numeric: NumericMode,
+    interests: BTreeMap<String, InterestFilter>,
 }
 
 /// Result of executing an app action through the runtime.
@@ -154,7 +254,10 @@ impl<T: Transport> Runtime<T> {
     /// use kitu_runtime::{Runtime, RuntimeConfig};
     /// use kitu_transport::LocalChannel;
     ///
-    /// let config = RuntimeConfig { tick_rate_hz: 30 };
+    /// let config = RuntimeConfig {
+    ///     tick_rate_hz: 30,
+    ///     ..RuntimeConfig::default_60hz()
+    /// };
     /// let runtime = Runtime::new(config, LocalChannel::connected());
     /// assert_eq!(runtime.config().tick_rate_hz, 30);
     /// ```
@@ -163,13 +266,25 @@ impl<T: Transport> Runtime<T> {
             config,
             tick: Tick::start(),
             accumulator: Duration::ZERO,
+            paused: false,
+            time_scale: 1.0,
             transport,
             world: EcsWorld::default(),
             inputs: AuthoritativeInputQueue::default(),
             committed_input_tick: None,
             outputs: OutputBuffer::default(),
-            player_transforms: HashMap::new(),
             app_actions: kitu_general_catalog(),
+            state_hash_interval: 0,
+            state_hashes: Vec::new(),
+            rollback: RollbackHistory::default(),
+            input_handlers: InputHandlers::default(),
+            input_rejection_policy: InputRejectionPolicy::default(),
+            input_rejections: Vec::new(),
+            kinematic: None,
+            collisions: None,
+            last_contacts: Vec::new(),
+            numeric: NumericMode::Float,
+            interests: BTreeMap::new(),
         }
     }
 
@@ -189,36 +304,248 @@ impl<T: Transport> Runtime<T> {
         let object = self
             .world
             .spawn_world_object(kind, WorldTransform::new(x, y, z))?;
-        self.enqueue_player_move(&object.id, x, z);
+        self.rollback.clear();
         Ok(object)
     }
 
     /// Moves an existing object in the authoritative runtime/ECS world state.
+    ///
+    /// Only the position changes; rotation and scale are kept. Children follow on the next tick's
+    /// transform propagation.
     pub fn move_world_object(&mut self, id: &str, x: f32, y: f32, z: f32) -> Result<WorldObject> {
-        let previous = self
+        let current = self
             .world
             .world_object(id)
-            .ok_or(KituError::InvalidInput("unknown world object"))?
-            .clone();
+            .ok_or(KituError::InvalidInput("unknown world object"))?;
         let moved = self
             .world
-            .move_world_object(id, WorldTransform::new(x, y, z))?;
-        self.enqueue_player_move(id, x - previous.transform.x, z - previous.transform.z);
+            .move_world_object(id, current.transform.with_translation(Vec3::new(x, y, z)))?;
+        self.rollback.clear();
         Ok(moved)
     }
 
+    /// Removes an object and its descendants from the authoritative runtime/ECS world state.
+    ///
+    /// Pending move inputs for the removed objects are discarded so they cannot respawn them,
+    /// and a `/render/<kind>/despawn [id, tick]` output is staged for each of them, root first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_runtime::build_runtime;
+    /// use kitu_transport::LocalChannel;
+    ///
+    /// let mut runtime = build_runtime(LocalChannel::connected());
+    /// let object = runtime.spawn_world_object("enemy", 1.0, 0.0, 2.0).unwrap();
+    /// runtime.despawn_world_object(&object.id).unwrap();
+    /// assert!(runtime.inspect_world_state().unwrap().objects.is_empty());
+    /// ```
+    pub fn despawn_world_object(&mut self, id: &str) -> Result<Vec<WorldObject>> {
+        let removed = self.world.despawn_world_object(id)?;
+        self.rollback.clear();
+        let ids: Vec<String> = removed.iter().map(|object| object.id.clone()).collect();
+        self.forget_kinematic_bodies(&ids);
+        self.forget_fixed_positions(&ids);
+        for object in &removed {
+            self.inputs.discard_pending_moves_for(&object.id);
+            let output = render_despawn_message(self.tick, object)?;
+            self.queue_output(output);
+        }
+        Ok(removed)
+    }
+
     /// Clears all objects from the authoritative runtime/ECS world state.
     pub fn reset_world_objects(&mut self) {
+        let ids: Vec<String> = self
+            .world
+            .world_objects()
+            .into_iter()
+            .map(|object| object.id)
+            .collect();
+        self.forget_kinematic_bodies(&ids);
+        self.forget_fixed_positions(&ids);
         self.world.reset_world_objects();
-        self.player_transforms.clear();
+        self.rollback.clear();
+        self.inputs.clear();
+        self.committed_input_tick = None;
+        self.outputs.clear();
+    }
+
+    /// Replaces the authoritative world state with a snapshot and continues from its tick.
+    ///
+    /// Pending inputs, buffered outputs and leftover frame time are dropped. A
+    /// `/render/<kind>/despawn [id, tick]` output is staged for every object that is not part of
+    /// the snapshot, and the next tick emits transforms for all restored objects. Like every
+    /// snapshot resource, random streams ([`RNG_STREAMS_KEY`]) must already be registered, so the
+    /// runtime must have been [seeded](Self::seed_rng); an unregistered key fails with
+    /// [`KituError::Serialization`] naming it. On error the runtime is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_runtime::build_runtime;
+    /// use kitu_transport::LocalChannel;
+    ///
+    /// let mut runtime = build_runtime(LocalChannel::connected());
+    /// runtime.spawn_world_object("crate", 1.0, 0.0, 0.0).unwrap();
+    /// runtime.tick_once().unwrap();
+    /// let saved = runtime.inspect_world_state().unwrap();
+    ///
+    /// let mut loaded = build_runtime(LocalChannel::connected());
+    /// loaded.restore_world_state(&saved).unwrap();
+    /// assert_eq!(loaded.current_tick(), runtime.current_tick());
+    /// assert_eq!(loaded.inspect_world_state().unwrap(), saved);
+    /// ```
+    pub fn restore_world_state(&mut self, snapshot: &WorldSnapshot) -> Result<()> {
+        let previous = self.world.world_objects();
+        self.world.restore_world_snapshot(snapshot)?;
+        self.rollback.clear();
+        self.last_contacts.clear();
+        self.tick = Tick::start().advance_by(snapshot.tick);
+        self.accumulator = Duration::ZERO;
         self.inputs.clear();
         self.committed_input_tick = None;
         self.outputs.clear();
+        for object in previous {
+            if self.world.world_object(&object.id).is_none() {
+                let output = render_despawn_message(self.tick, &object)?;
+                self.queue_output(output);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the authoritative runtime/ECS world snapshot, stamped with the current tick.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KituError::Serialization`] when a snapshot-marked resource no longer serializes.
+    pub fn inspect_world_state(&self) -> Result<WorldSnapshot> {
+        let mut snapshot = self.world.world_snapshot()?;
+        snapshot.tick = self.tick.get();
+        Ok(snapshot)
+    }
+
+    /// Returns the changes since `base`, a snapshot previously taken from this runtime.
+    ///
+    /// Hosts keep the last snapshot each client has seen and stream these deltas, keyed by
+    /// [`WorldDelta::base_tick`] and [`WorldDelta::tick`], instead of whole worlds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_runtime::build_runtime;
+    /// use kitu_transport::LocalChannel;
+    ///
+    /// let mut runtime = build_runtime(LocalChannel::connected());
+    /// let base = runtime.inspect_world_state().unwrap();
+    /// runtime.spawn_world_object("coin", 1.0, 0.0, 0.0).unwrap();
+    /// runtime.tick_once().unwrap();
+    ///
+    /// let delta = runtime.world_delta_since(&base).unwrap();
+    /// assert_eq!((delta.base_tick, delta.tick), (0, 1));
+    /// assert_eq!(base.apply_delta(&delta).unwrap(), runtime.inspect_world_state().unwrap());
+    /// ```
+    pub fn world_delta_since(&self, base: &WorldSnapshot) -> Result<WorldDelta> {
+        Ok(base.diff(&self.inspect_world_state()?))
+    }
+
+    /// Returns a platform-independent hash of the authoritative state at the current tick.
+    ///
+    /// Two runs that hash equal at a tick hold identical world objects, resources, entity
+    /// allocator state and tick; see [`WorldSnapshot::state_hash`]. Under
+    /// [`NumericMode::FixedPoint`] this is [`WorldSnapshot::fixed_state_hash`] instead.
+    ///
+    /// # Errors
+    ///
+    /// Fails like [`inspect_world_state`](Self::inspect_world_state).
+    pub fn state_hash(&self) -> Result<u64> {
+        Ok(self.hash_snapshot(&self.inspect_world_state()?))
+    }
+
+    fn hash_snapshot(&self, snapshot: &WorldSnapshot) -> u64 {
+        if self.fixed_point() {
+            snapshot.fixed_state_hash()
+        } else {
+            snapshot.state_hash()
+        }
+    }
+
+    /// Records the state hash after every tick that is a multiple of `interval`; `0` disables
+    /// recording.
+    ///
+    /// Recorded hashes accumulate until [`drain_state_hashes`](Self::drain_state_hashes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_runtime::build_runtime;
+    /// use kitu_transport::LocalChannel;
+    ///
+    /// let mut runtime = build_runtime(LocalChannel::connected());
+    /// runtime.record_state_hash_every(2);
+    /// runtime.run_for_ticks(5).unwrap();
+    ///
+    /// let ticks: Vec<u64> = runtime
+    ///     .drain_state_hashes()
+    ///     .into_iter()
+    ///     .map(|(tick, _)| tick.get())
+    ///     .collect();
+    /// assert_eq!(ticks, [2, 4]);
+    /// ```
+    pub fn record_state_hash_every(&mut self, interval: u64) {
+        self.state_hash_interval = interval;
+    }
+
+    /// Drains recorded `(tick, state hash)` pairs in tick order.
+    pub fn drain_state_hashes(&mut self) -> Vec<(Tick, u64)> {
+        std::mem::take(&mut self.state_hashes)
+    }
+
+    /// Returns world objects within `radius` of `center`, in entity order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_runtime::{build_runtime, Vec3};
+    /// use kitu_transport::LocalChannel;
+    ///
+    /// let mut runtime = build_runtime(LocalChannel::connected());
+    /// let coin = runtime.spawn_world_object("coin", 1.0, 0.0, 0.0).unwrap();
+    /// runtime.spawn_world_object("coin", 20.0, 0.0, 0.0).unwrap();
+    /// assert_eq!(runtime.world_objects_within_radius(Vec3::ZERO, 2.0), vec![coin]);
+    /// ```
+    pub fn world_objects_within_radius(&self, center: Vec3, radius: f32) -> Vec<WorldObject> {
+        self.world_objects_for(self.world.entities_within_radius(center, radius))
+    }
+
+    /// Returns world objects inside the inclusive axis-aligned box, in entity order.
+    pub fn world_objects_in_box(&self, min: Vec3, max: Vec3) -> Vec<WorldObject> {
+        self.world_objects_for(self.world.entities_in_box(min, max))
+    }
+
+    /// Returns up to `k` world objects nearest to `center`, nearest first.
+    pub fn nearest_world_objects(&self, center: Vec3, k: usize) -> Vec<WorldObject> {
+        // Entities that are not world objects can occupy nearest slots, so widen until enough
+        // objects are found or the index is exhausted.
+        let mut wanted = k;
+        loop {
+            let entities = self.world.nearest_entities(center, wanted);
+            let exhausted = entities.len() < wanted;
+            let mut objects = self.world_objects_for(entities);
+            if objects.len() >= k || exhausted {
+                objects.truncate(k);
+                return objects;
+            }
+            wanted = wanted.saturating_mul(2);
+        }
     }
 
-    /// Returns the authoritative runtime/ECS world snapshot.
-    pub fn inspect_world_state(&self) -> WorldSnapshot {
-        self.world.world_snapshot()
+    fn world_objects_for(&self, entities: Vec<Entity>) -> Vec<WorldObject> {
+        entities
+            .into_iter()
+            .filter_map(|entity| self.world.world_object_for_entity(entity))
+            .collect()
     }
 
     /// Returns the merged Kitu general and project app action catalog.
@@ -296,16 +623,6 @@ impl<T: Transport> Runtime<T> {
         drained
     }
 
-    fn enqueue_player_move(&mut self, entity_id: &str, x: f32, z: f32) {
-        let mut message = OscMessage::new("/input/move");
-        message.push_arg(OscArg::Str(entity_id.to_string()));
-        message.push_arg(OscArg::Float(x));
-        message.push_arg(OscArg::Float(z));
-        let mut bundle = OscBundle::new();
-        bundle.push(message);
-        self.enqueue_input(bundle);
-    }
-
     fn apply_app_action_message(&mut self, message: &OscMessage) -> AppActionResult<()> {
         match message.address.as_str() {
             "/admin/world/spawn" => {
@@ -342,9 +659,42 @@ impl<T: Transport> Runtime<T> {
                 self.move_world_object(id, x, y, z)
                     .map_err(app_action_runtime_error)?;
             }
+            "/admin/world/despawn" => {
+                let id =
+                    action_string_arg(message, 0).ok_or_else(|| AppActionError::InvalidInput {
+                        name: "id".to_string(),
+                        message: "object id is required".to_string(),
+                    })?;
+                self.despawn_world_object(id)
+                    .map_err(app_action_runtime_error)?;
+            }
             "/admin/world/reset" => {
                 self.reset_world_objects();
             }
+            "/admin/sim/pause" => self.pause(),
+            "/admin/sim/resume" => self.resume(),
+            "/admin/sim/step" => {
+                let ticks = match message.args.first() {
+                    Some(OscArg::Int(value)) => u64::try_from(*value).ok(),
+                    Some(OscArg::Int64(value)) => u64::try_from(*value).ok(),
+                    _ => None,
+                }
+                .filter(|ticks| *ticks <= MAX_STEP_TICKS)
+                .ok_or_else(|| AppActionError::InvalidInput {
+                    name: "ticks".to_string(),
+                    message: format!("ticks must be an integer from 0 to {MAX_STEP_TICKS}"),
+                })?;
+                self.step(ticks).map_err(app_action_runtime_error)?;
+            }
+            "/admin/sim/time-scale" => {
+                let scale =
+                    action_numeric_arg(message, 0).ok_or_else(|| AppActionError::InvalidInput {
+                        name: "scale".to_string(),
+                        message: "scale must be numeric".to_string(),
+                    })?;
+                self.set_time_scale(scale)
+                    .map_err(app_action_runtime_error)?;
+            }
             _ => {
                 let mut bundle = OscBundle::new();
                 bundle.push(message.clone());
@@ -356,8 +706,35 @@ impl<T: Transport> Runtime<T> {
 
     /// Processes as many fixed ticks as `dt` allows.
     ///
-    /// Returns how many ticks were executed.
-    pub fn update(&mut self, dt: f32) -> Result<u32> {
+    /// `dt` is multiplied by the [time scale](Self::set_time_scale) before it is accumulated, so
+    /// ticks keep their fixed length at any speed. While [paused](Self::pause) no time is
+    /// accumulated and no tick runs, but transport events are still polled into the pending
+    /// input queue.
+    ///
+    /// At most [`RuntimeConfig::max_ticks_per_update`] ticks run; time beyond that budget is
+    /// handled by [`RuntimeConfig::excess_time`]. The report holds the tick count, any overrun
+    /// and whether the ticks changed a world object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_runtime::{ExcessTimePolicy, Runtime, RuntimeConfig};
+    /// use kitu_transport::LocalChannel;
+    ///
+    /// let config = RuntimeConfig {
+    ///     tick_rate_hz: 10,
+    ///     max_ticks_per_update: 3,
+    ///     excess_time: ExcessTimePolicy::Drop,
+    /// };
+    /// let mut runtime = Runtime::new(config, LocalChannel::connected());
+    ///
+    /// let report = runtime.update(1.05).unwrap();
+    /// assert_eq!(report.ticks, 3);
+    /// assert_eq!(report.overrun_ticks, 7);
+    /// assert!(report.overran());
+    /// assert_eq!(runtime.update(0.06).unwrap().ticks, 1);
+    /// ```
+    pub fn update(&mut self, dt: f32) -> Result<UpdateReport> {
         if !dt.is_finite() {
             return Err(KituError::InvalidInput("dt must be finite"));
         }
@@ -366,7 +743,15 @@ impl<T: Transport> Runtime<T> {
             return Err(KituError::InvalidInput("dt must be non-negative"));
         }
 
-        let dt_secs = f64::from(dt);
+        if self.paused {
+            self.poll_transport();
+            return Ok(UpdateReport {
+                backlog: self.accumulator,
+                ..UpdateReport::default()
+            });
+        }
+
+        let dt_secs = f64::from(dt) * f64::from(self.time_scale);
         if dt_secs >= Duration::MAX.as_secs_f64() {
             return Err(KituError::InvalidInput("dt is too large"));
         }
@@ -377,7 +762,10 @@ impl<T: Transport> Runtime<T> {
             ));
         }
 
-        self.accumulator += Duration::from_secs_f64(dt_secs);
+        self.accumulator = self
+            .accumulator
+            .checked_add(Duration::from_secs_f64(dt_secs))
+            .ok_or(KituError::InvalidInput("accumulated time is too large"))?;
         let frame_time = self.config.frame_time();
         if frame_time.is_zero() {
             return Err(KituError::InvalidInput(
@@ -385,21 +773,134 @@ impl<T: Transport> Runtime<T> {
             ));
         }
 
-        let mut executed = 0;
+        let budget = self.config.max_ticks_per_update;
+        let mut report = UpdateReport::default();
 
         while self.accumulator >= frame_time {
+            if budget != 0 && report.ticks >= budget {
+                self.handle_excess_time(frame_time, &mut report);
+                break;
+            }
+            let tick = self.tick;
             self.tick_once()?;
             self.accumulator -= frame_time;
-            executed += 1;
+            report.ticks += 1;
+            report.objects_changed |= self.world_objects_changed_since(tick);
+        }
+
+        report.backlog = self.accumulator;
+        Ok(report)
+    }
+
+    /// Returns whether a world object was spawned, despawned, moved or re-kinded at or after
+    /// `since`. Despawns are only retained for the current and previous change tick.
+    fn world_objects_changed_since(&self, since: Tick) -> bool {
+        !self.world.changed_since::<WorldTransform>(since).is_empty()
+            || !self.world.changed_since::<ObjectKind>(since).is_empty()
+            || !self.world.removed_since::<ObjectKind>(since).is_empty()
+    }
+
+    fn handle_excess_time(&mut self, frame_time: Duration, report: &mut UpdateReport) {
+        let frame_nanos = frame_time.as_nanos();
+        let owed = self.accumulator.as_nanos() / frame_nanos;
+        report.overrun_ticks = u64::try_from(owed).unwrap_or(u64::MAX);
+
+        let kept = match self.config.excess_time {
+            ExcessTimePolicy::Drop => {
+                // The remainder is below one frame, so it always fits in u64 nanoseconds.
+                Duration::from_nanos((self.accumulator.as_nanos() % frame_nanos) as u64)
+            }
+            ExcessTimePolicy::Carry => self.accumulator,
+            ExcessTimePolicy::SlowDown => self
+                .accumulator
+                .min(frame_time * self.config.max_ticks_per_update),
+        };
+        report.dropped = self.accumulator - kept;
+        self.accumulator = kept;
+    }
+
+    /// Stops [`update`](Self::update) from advancing ticks until [`resume`](Self::resume).
+    ///
+    /// Leftover frame time is kept. Inputs, transport polling, app actions and direct world
+    /// edits keep working; queued inputs are committed by the next tick that runs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_runtime::build_runtime;
+    /// use kitu_transport::LocalChannel;
+    ///
+    /// let mut runtime = build_runtime(LocalChannel::connected());
+    /// runtime.pause();
+    /// assert_eq!(runtime.update(1.0).unwrap().ticks, 0);
+    ///
+    /// runtime.step(3).unwrap();
+    /// assert_eq!(runtime.current_tick().get(), 3);
+    ///
+    /// runtime.resume();
+    /// assert_eq!(runtime.update(1.0 / 60.0).unwrap().ticks, 1);
+    /// ```
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Lets [`update`](Self::update) advance ticks again.
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Returns whether the runtime is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Runs exactly `count` ticks, whether or not the runtime is paused.
+    ///
+    /// Leftover frame time is neither consumed nor changed. Counts above [`MAX_STEP_TICKS`] fail
+    /// with [`KituError::InvalidInput`] before any tick runs.
+    pub fn step(&mut self, count: u64) -> Result<()> {
+        if count > MAX_STEP_TICKS {
+            return Err(KituError::InvalidInput("step count exceeds MAX_STEP_TICKS"));
+        }
+        self.run_for_ticks(count)
+    }
+
+    /// Sets the factor applied to `dt` by [`update`](Self::update); `1.0` is real time.
+    ///
+    /// The scale must be finite and greater than zero; use [`pause`](Self::pause) to stop time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_runtime::build_runtime;
+    /// use kitu_transport::LocalChannel;
+    ///
+    /// let mut runtime = build_runtime(LocalChannel::connected());
+    /// runtime.set_time_scale(4.0).unwrap();
+    /// assert_eq!(runtime.update(0.02).unwrap().ticks, 4);
+    /// assert!(runtime.set_time_scale(0.0).is_err());
+    /// ```
+    pub fn set_time_scale(&mut self, scale: f32) -> Result<()> {
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err(KituError::InvalidInput(
+                "time scale must be finite and greater than zero",
+            ));
         }
+        self.time_scale = scale;
+        Ok(())
+    }
 
-        Ok(executed)
+    /// Returns the factor applied to `dt` by [`update`](Self::update).
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
     }
 
     /// Processes a single tick of the runtime loop.
     ///
-    /// This dispatches all scheduled ECS systems for the current tick, emits
-    /// staged outputs, polls transport events, and increments the tick counter.
+    /// This runs the persistent ECS schedule stage by stage for the current tick,
+    /// applies move input, propagates hierarchy transforms, stages render output for
+    /// world objects changed this tick,
+    /// emits staged outputs, polls transport events, and increments the tick counter.
     /// Inputs received while polling are queued for the next tick.
     ///
     /// # Examples
@@ -415,11 +916,12 @@ impl<T: Transport> Runtime<T> {
     /// ```
     pub fn tick_once(&mut self) -> Result<()> {
         if self.committed_input_tick != Some(self.tick) {
-            self.inputs.commit_next_tick_batch();
+            self.inputs.commit_next_tick_batch(self.tick);
             self.committed_input_tick = Some(self.tick);
         }
 
-        let parsed_moves = match self.collect_move_inputs() {
+        let committed = self.inputs.committed_snapshot();
+        let parsed = match self.parse_inputs(&committed, self.tick, 0) {
             Ok(parsed) => parsed,
             Err(error) => {
                 self.inputs.drain_committed();
@@ -427,70 +929,91 @@ impl<T: Transport> Runtime<T> {
                 return Err(error);
             }
         };
-        self.world.dispatch(self.tick)?;
-        self.apply_player_move_slice(parsed_moves)?;
+        if self.rollback.is_enabled() {
+            self.rollback.record(RollbackFrame {
+                tick: self.tick,
+                snapshot: self.world.world_snapshot()?,
+                inputs: committed,
+                contacts: self.last_contacts.clone(),
+            });
+        }
+        let contacts = self.simulate_tick(parsed)?;
+        self.last_contacts.clone_from(&contacts);
+        self.stage_collision_outputs(&contacts)?;
+        self.extract_render_outputs()?;
 
         self.outputs.emit_staged();
+        self.poll_transport();
+
+        self.tick = self.tick.next();
+        self.world.set_change_tick(self.tick);
+        if self.state_hash_interval != 0 && self.tick.get().is_multiple_of(self.state_hash_interval)
+        {
+            let hash = self.state_hash()?;
+            self.state_hashes.push((self.tick, hash));
+        }
+        Ok(())
+    }
 
+    fn poll_transport(&mut self) {
         while let Some(event) = self.transport.poll_event() {
             if let TransportEvent::Message(bundle) = event {
                 self.inputs.enqueue_pending(bundle);
             }
         }
-
-        self.tick = self.tick.next();
-        Ok(())
     }
 
-    fn collect_move_inputs(&self) -> Result<Vec<(String, f32, f32)>> {
-        let committed = self.inputs.committed_snapshot();
-        let mut parsed_moves = Vec::new();
-
-        for bundle in committed {
-            for message in bundle.messages {
-                if message.address != "/input/move" {
-                    continue;
-                }
-
-                let (entity_id, x, y) = parse_move_input(&message)?;
-                parsed_moves.push((entity_id, x, y));
-            }
+    /// Runs the deterministic part of a tick: input events, ECS dispatch, the move slice,
+    /// propagation and collisions. Returns the tick's contacts.
+    fn simulate_tick(&mut self, parsed: ParsedInputs) -> Result<Vec<Contact>> {
+        for send in parsed.events {
+            send(&mut self.world);
         }
-
-        Ok(parsed_moves)
+        self.world.dispatch(self.tick)?;
+        match self.kinematic {
+            Some(settings) => self.apply_kinematic_moves(settings, parsed.moves)?,
+            None => self.apply_player_move_slice(parsed.moves)?,
+        }
+        self.world.propagate_transforms();
+        self.process_collisions()
     }
 
     fn apply_player_move_slice(&mut self, parsed_moves: Vec<(String, f32, f32)>) -> Result<()> {
         for (entity_id, x, y) in parsed_moves {
-            let transform = {
-                let transform = self.player_transforms.entry(entity_id.clone()).or_default();
-                transform.x += x;
-                transform.y += y;
-                transform.clone()
-            };
-            self.sync_player_world_object(&entity_id, &transform)?;
-            let output = render_player_transform_message(self.tick, &entity_id, &transform)?;
-            self.queue_output(output);
+            match self.world.world_object(&entity_id) {
+                Some(object) => {
+                    // Zero deltas are skipped so they do not count as a change.
+                    if x == 0.0 && y == 0.0 {
+                        continue;
+                    }
+                    if self.fixed_point() {
+                        let position = self.fixed_translation(&entity_id, &object.transform)
+                            + FixedVec3::from_f32(x, 0.0, y);
+                        self.place_fixed(&entity_id, object.transform, position)?;
+                        continue;
+                    }
+                    let mut transform = object.transform;
+                    transform.x += x;
+                    transform.z += y;
+                    self.world.move_world_object(&entity_id, transform)?;
+                }
+                None => {
+                    self.world.spawn_world_object_with_id(
+                        &entity_id,
+                        "player",
+                        WorldTransform::new(x, 0.0, y),
+                    )?;
+                }
+            }
         }
 
         Ok(())
     }
 
-    fn sync_player_world_object(
-        &mut self,
-        entity_id: &str,
-        transform: &PlayerTransform,
-    ) -> Result<()> {
-        let world_transform = WorldTransform::new(transform.x, 0.0, transform.y);
-        match self.world.world_object(entity_id) {
-            Some(object) if object.kind == "player" => {
-                self.world.move_world_object(entity_id, world_transform)?;
-            }
-            Some(_) => {}
-            None => {
-                self.world
-                    .spawn_world_object_with_id(entity_id, "player", world_transform)?;
-            }
+    fn extract_render_outputs(&mut self) -> Result<()> {
+        for object in self.world.world_objects_changed_since(self.tick) {
+            let output = render_transform_message("transform", self.tick, &object)?;
+            self.queue_output(output);
         }
         Ok(())
     }
@@ -576,25 +1099,63 @@ fn parse_move_component(arg: &OscArg, error_message: &'static str) -> Result<f32
     }
 }
 
-fn render_player_transform_message(
+/// Address segment used by render outputs for kinds that are not a plain address segment.
+const RENDER_OBJECT_FAMILY: &str = "object";
+
+/// Returns whether `kind` can name its own `/render/{kind}/*` address family.
+fn has_render_family(kind: &str) -> bool {
+    !kind.is_empty()
+        && kind != RENDER_OBJECT_FAMILY
+        && kind.bytes().all(|byte| {
+            byte.is_ascii_lowercase() || byte.is_ascii_digit() || matches!(byte, b'_' | b'-')
+        })
+}
+
+/// Builds `/render/{kind}/{event}` for `object`, or `/render/object/{event}` with the kind as a
+/// trailing argument when the kind is not a plain address segment.
+fn render_object_message(
+    event: &str,
     tick: Tick,
-    entity_id: &str,
-    transform: &PlayerTransform,
+    object: &WorldObject,
+    args: impl IntoIterator<Item = OscArg>,
 ) -> Result<OscBundle> {
-    let mut message = OscMessage::new("/render/player/transform");
-    message.push_arg(OscArg::Str(entity_id.to_string()));
+    let family = has_render_family(&object.kind);
+    let address = if family {
+        format!("/render/{}/{event}", object.kind)
+    } else {
+        format!("/render/{RENDER_OBJECT_FAMILY}/{event}")
+    };
+    let mut message = OscMessage::new(address);
+    message.push_arg(OscArg::Str(object.id.clone()));
     let tick_i64 = i64::try_from(tick.get())
         .map_err(|_| KituError::InvalidInput("tick is too large to encode"))?;
     message.push_arg(OscArg::Int64(tick_i64));
-    message.push_arg(OscArg::Float(transform.x));
-    message.push_arg(OscArg::Float(transform.y));
-    message.push_arg(OscArg::Float(transform.z));
+    for arg in args {
+        message.push_arg(arg);
+    }
+    if !family {
+        message.push_arg(OscArg::Str(object.kind.clone()));
+    }
 
     let mut bundle = OscBundle::new();
     bundle.push(message);
     Ok(bundle)
 }
 
+fn render_transform_message(event: &str, tick: Tick, object: &WorldObject) -> Result<OscBundle> {
+    // Render clients use a ground-plane layout: world x/z map to render x/y.
+    let position = [
+        OscArg::Float(object.transform.x),
+        OscArg::Float(object.transform.z),
+        OscArg::Float(0.0),
+    ];
+    render_object_message(event, tick, object, position)
+}
+
+fn render_despawn_message(tick: Tick, object: &WorldObject) -> Result<OscBundle> {
+    render_object_message("despawn", tick, object, [])
+}
+
 /// Convenience helper for building a runtime with default configuration.
 ///
 /// # Examples
@@ -633,7 +1194,10 @@ mod tests {
 
     #[test]
     fn frame_time_matches_tick_rate() {
-        let config = RuntimeConfig { tick_rate_hz: 120 };
+        let config = RuntimeConfig {
+            tick_rate_hz: 120,
+            ..RuntimeConfig::default_60hz()
+        };
         let frame = config.frame_time();
         let expected = 1.0 / 120.0;
         let actual = frame.as_secs_f64();
@@ -644,48 +1208,134 @@ mod tests {
     fn update_uses_fixed_timestep_accumulator() {
         let mut runtime = build_runtime(LocalChannel::default());
 
-        let executed = runtime.update(0.010).unwrap();
+        let executed = runtime.update(0.010).unwrap().ticks;
         assert_eq!(executed, 0);
         assert_eq!(runtime.current_tick().get(), 0);
 
-        let executed = runtime.update(0.010).unwrap();
+        let executed = runtime.update(0.010).unwrap().ticks;
         assert_eq!(executed, 1);
         assert_eq!(runtime.current_tick().get(), 1);
     }
 
     #[test]
     fn update_consumes_multiple_ticks_when_dt_is_large_enough() {
-        let mut runtime = Runtime::new(RuntimeConfig { tick_rate_hz: 10 }, LocalChannel::default());
+        let mut runtime = Runtime::new(
+            RuntimeConfig {
+                tick_rate_hz: 10,
+                ..RuntimeConfig::default_60hz()
+            },
+            LocalChannel::default(),
+        );
 
-        let executed = runtime.update(0.250).unwrap();
+        let executed = runtime.update(0.250).unwrap().ticks;
         assert_eq!(executed, 2);
         assert_eq!(runtime.current_tick().get(), 2);
     }
 
     #[test]
-    fn update_rejects_non_finite_dt() {
-        let mut runtime = build_runtime(LocalChannel::default());
-        assert!(runtime.update(f32::NAN).is_err());
-        assert!(runtime.update(f32::INFINITY).is_err());
-    }
+    fn update_reports_whether_its_ticks_changed_world_objects() {
+        let mut runtime = Runtime::new(
+            RuntimeConfig {
+                tick_rate_hz: 10,
+                ..RuntimeConfig::default_60hz()
+            },
+            LocalChannel::default(),
+        );
+        let enemy = runtime.spawn_world_object("enemy", 0.0, 0.0, 0.0).unwrap();
+        assert!(runtime.update(0.1).unwrap().objects_changed);
+        assert!(!runtime.update(0.35).unwrap().objects_changed);
 
-    #[test]
-    fn update_rejects_oversized_dt() {
-        let mut runtime = build_runtime(LocalChannel::default());
-        assert!(runtime.update(f32::MAX).is_err());
+        runtime.enqueue_input(move_input("player:local", 1.0));
+        let report = runtime.update(0.3).unwrap();
+        assert_eq!(report.ticks, 3);
+        assert!(report.objects_changed);
+        assert!(!runtime.update(0.3).unwrap().objects_changed);
 
-        let boundary = Duration::MAX.as_secs_f64() as f32;
-        assert!(runtime.update(boundary).is_err());
+        // The despawn lands in the first of three ticks and is still reported.
+        runtime.despawn_world_object(&enemy.id).unwrap();
+        let report = runtime.update(0.3).unwrap();
+        assert_eq!(report.ticks, 3);
+        assert!(report.objects_changed);
     }
 
     #[test]
-    fn update_rejects_invalid_tick_rate() {
-        let mut runtime = Runtime::new(RuntimeConfig { tick_rate_hz: 0 }, LocalChannel::default());
+    fn update_budget_applies_the_excess_time_policy() {
+        let budgeted = |excess_time| {
+            Runtime::new(
+                RuntimeConfig {
+                    tick_rate_hz: 10,
+                    max_ticks_per_update: 2,
+                    excess_time,
+                },
+                LocalChannel::default(),
+            )
+        };
+
+        let mut carry = budgeted(ExcessTimePolicy::Carry);
+        let report = carry.update(0.55).unwrap();
+        assert_eq!((report.ticks, report.overrun_ticks), (2, 3));
+        assert_eq!(report.dropped, Duration::ZERO);
+        let ticks: Vec<u32> = (0..3).map(|_| carry.update(0.0).unwrap().ticks).collect();
+        assert_eq!(ticks, [2, 1, 0]);
+        assert_eq!(carry.current_tick().get(), 5);
+
+        let mut slow = budgeted(ExcessTimePolicy::SlowDown);
+        let report = slow.update(0.55).unwrap();
+        assert_eq!((report.ticks, report.overrun_ticks), (2, 3));
+        assert_eq!(report.backlog, slow.config().frame_time() * 2);
+        assert!(report.dropped > Duration::ZERO);
+        assert_eq!(slow.update(0.0).unwrap().ticks, 2);
+        assert_eq!(slow.update(0.0).unwrap().ticks, 0);
+
+        let mut drop = budgeted(ExcessTimePolicy::Drop);
+        let report = drop.update(0.55).unwrap();
+        assert_eq!(report.ticks, 2);
+        assert!(report.backlog < drop.config().frame_time());
+        assert_eq!(drop.update(0.0).unwrap().ticks, 0);
+
+        let mut unlimited = Runtime::new(
+            RuntimeConfig {
+                tick_rate_hz: 10,
+                ..RuntimeConfig::default_60hz()
+            },
+            LocalChannel::default(),
+        );
+        let report = unlimited.update(2.05).unwrap();
+        assert_eq!(report.ticks, 20);
+        assert!(!report.overran());
+    }
+
+    #[test]
+    fn update_rejects_non_finite_dt() {
+        let mut runtime = build_runtime(LocalChannel::default());
+        assert!(runtime.update(f32::NAN).is_err());
+        assert!(runtime.update(f32::INFINITY).is_err());
+    }
+
+    #[test]
+    fn update_rejects_oversized_dt() {
+        let mut runtime = build_runtime(LocalChannel::default());
+        assert!(runtime.update(f32::MAX).is_err());
+
+        let boundary = Duration::MAX.as_secs_f64() as f32;
+        assert!(runtime.update(boundary).is_err());
+    }
+
+    #[test]
+    fn update_rejects_invalid_tick_rate() {
+        let mut runtime = Runtime::new(
+            RuntimeConfig {
+                tick_rate_hz: 0,
+                ..RuntimeConfig::default_60hz()
+            },
+            LocalChannel::default(),
+        );
         assert!(runtime.update(0.016).is_err());
 
         let mut runtime = Runtime::new(
             RuntimeConfig {
                 tick_rate_hz: u32::MAX,
+                ..RuntimeConfig::default_60hz()
             },
             LocalChannel::default(),
         );
@@ -819,17 +1469,22 @@ mod tests {
         let mut runtime = build_runtime(LocalChannel::default());
 
         let object = runtime.spawn_world_object("enemy", 1.0, 2.0, 3.0).unwrap();
-        assert_eq!(object.id, "obj-1");
+        assert_eq!(object.id, object.entity.to_string());
 
         runtime.tick_once().unwrap();
         let outputs = runtime.drain_output_buffer();
         assert_eq!(outputs.len(), 1);
         assert_eq!(
-            runtime.inspect_world_state().objects,
+            runtime.inspect_world_state().unwrap().objects,
             vec![WorldObject {
-                id: "obj-1".to_string(),
+                entity: object.entity,
+                id: object.id.clone(),
+                named: false,
                 kind: "enemy".to_string(),
                 transform: WorldTransform::new(1.0, 2.0, 3.0),
+                parent: None,
+                local: None,
+                collider: None,
             }]
         );
 
@@ -838,10 +1493,949 @@ mod tests {
             .unwrap();
         assert_eq!(moved.transform, WorldTransform::new(4.0, 5.0, 6.0));
 
-        let snapshot = runtime.inspect_world_state();
+        let snapshot = runtime.inspect_world_state().unwrap();
         assert_eq!(snapshot.objects, vec![moved]);
     }
 
+    #[test]
+    fn world_api_despawn_removes_one_object_and_notifies_render() {
+        let mut runtime = build_runtime(LocalChannel::default());
+
+        let enemy = runtime.spawn_world_object("enemy", 1.0, 0.0, 1.0).unwrap();
+        let treasure = runtime
+            .spawn_world_object("treasure", 2.0, 0.0, 2.0)
+            .unwrap();
+        runtime.despawn_world_object(&enemy.id).unwrap();
+        assert!(runtime.despawn_world_object(&enemy.id).is_err());
+
+        runtime.tick_once().unwrap();
+        let addresses = runtime
+            .drain_output_buffer()
+            .into_iter()
+            .flat_map(|bundle| bundle.messages)
+            .map(|message| (message.address, message.args[0].clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            addresses,
+            vec![
+                (
+                    "/render/enemy/despawn".to_string(),
+                    OscArg::Str(enemy.id.clone())
+                ),
+                (
+                    "/render/treasure/transform".to_string(),
+                    OscArg::Str(treasure.id.clone())
+                ),
+            ]
+        );
+        assert_eq!(
+            runtime.inspect_world_state().unwrap().objects,
+            vec![treasure]
+        );
+    }
+
+    #[test]
+    fn render_outputs_name_kinds_that_are_not_address_segments_in_an_argument() {
+        let mut runtime = build_runtime(LocalChannel::default());
+        let boss = runtime
+            .spawn_world_object("Boss/Enemy", 1.0, 0.0, 2.0)
+            .unwrap();
+        let object = runtime.spawn_world_object("object", 0.0, 0.0, 0.0).unwrap();
+        runtime.tick_once().unwrap();
+        runtime.despawn_world_object(&boss.id).unwrap();
+        runtime.tick_once().unwrap();
+
+        let messages = runtime
+            .drain_output_buffer()
+            .into_iter()
+            .flat_map(|bundle| bundle.messages)
+            .collect::<Vec<_>>();
+        let addresses = messages
+            .iter()
+            .map(|message| message.address.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            addresses,
+            [
+                "/render/object/transform",
+                "/render/object/transform",
+                "/render/object/despawn"
+            ]
+        );
+        assert_eq!(
+            messages[0].args,
+            vec![
+                OscArg::Str(boss.id.clone()),
+                OscArg::Int64(0),
+                OscArg::Float(1.0),
+                OscArg::Float(2.0),
+                OscArg::Float(0.0),
+                OscArg::Str("Boss/Enemy".to_string()),
+            ]
+        );
+        assert_eq!(messages[1].args[0], OscArg::Str(object.id.clone()));
+        assert_eq!(messages[1].args[5], OscArg::Str("object".to_string()));
+        assert_eq!(
+            messages[2].args,
+            vec![
+                OscArg::Str(boss.id),
+                OscArg::Int64(1),
+                OscArg::Str("Boss/Enemy".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn spatial_queries_skip_entities_that_are_not_world_objects() {
+        let mut runtime = build_runtime(LocalChannel::default());
+        let far = runtime.spawn_world_object("enemy", 6.0, 0.0, 0.0).unwrap();
+        let near = runtime.spawn_world_object("enemy", 0.0, 0.0, 3.0).unwrap();
+        let helper = runtime.world_mut().spawn_entity();
+        runtime
+            .world_mut()
+            .insert_component(helper, WorldTransform::new(0.5, 0.0, 0.0))
+            .unwrap();
+
+        assert_eq!(
+            runtime.nearest_world_objects(Vec3::ZERO, 2),
+            vec![near.clone(), far.clone()]
+        );
+        assert_eq!(
+            runtime.world_objects_in_box(Vec3::new(-1.0, -1.0, -1.0), Vec3::new(7.0, 1.0, 1.0)),
+            vec![far.clone()]
+        );
+
+        runtime.move_world_object(&far.id, 0.0, 0.0, 1.0).unwrap();
+        assert_eq!(
+            runtime.world_objects_within_radius(Vec3::ZERO, 3.0),
+            vec![
+                runtime.inspect_world_state().unwrap().objects[0].clone(),
+                near
+            ]
+        );
+    }
+
+    #[test]
+    fn hierarchy_children_follow_moves_and_despawn_with_parent() {
+        let mut runtime = build_runtime(LocalChannel::default());
+        let ship = runtime.spawn_world_object("ship", 0.0, 0.0, 0.0).unwrap();
+        let turret = runtime.spawn_world_object("turret", 1.0, 0.0, 0.0).unwrap();
+        runtime
+            .world_mut()
+            .attach_child(ship.entity, turret.entity)
+            .unwrap();
+        runtime.tick_once().unwrap();
+        runtime.drain_output_buffer();
+
+        let mut move_message = OscMessage::new("/input/move");
+        move_message.push_arg(OscArg::Str(ship.id.clone()));
+        move_message.push_arg(OscArg::Float(0.0));
+        move_message.push_arg(OscArg::Float(2.0));
+        let mut input = OscBundle::new();
+        input.push(move_message);
+        runtime.enqueue_input(input);
+        runtime.tick_once().unwrap();
+
+        let transforms = runtime
+            .drain_output_buffer()
+            .into_iter()
+            .flat_map(|bundle| bundle.messages)
+            .map(|message| (message.args[0].clone(), message.args[3].clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            transforms,
+            vec![
+                (OscArg::Str(ship.id.clone()), OscArg::Float(2.0)),
+                (OscArg::Str(turret.id.clone()), OscArg::Float(2.0)),
+            ]
+        );
+
+        let removed = runtime.despawn_world_object(&ship.id).unwrap();
+        assert_eq!(
+            removed.iter().map(|object| &object.id).collect::<Vec<_>>(),
+            vec![&ship.id, &turret.id]
+        );
+        runtime.tick_once().unwrap();
+        let despawned = runtime
+            .drain_output_buffer()
+            .into_iter()
+            .flat_map(|bundle| bundle.messages)
+            .map(|message| message.address)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            despawned,
+            ["/render/ship/despawn", "/render/turret/despawn"]
+        );
+        assert!(runtime.inspect_world_state().unwrap().objects.is_empty());
+    }
+
+    fn move_input(id: &str, dx: f32) -> OscBundle {
+        let mut message = OscMessage::new("/input/move");
+        message.push_arg(OscArg::Str(id.to_string()));
+        message.push_arg(OscArg::Float(dx));
+        message.push_arg(OscArg::Float(0.0));
+        let mut bundle = OscBundle::new();
+        bundle.push(message);
+        bundle
+    }
+
+    #[test]
+    fn restored_runtime_continues_like_the_original() {
+        let mut original = build_runtime(LocalChannel::default());
+        let doomed = original.spawn_world_object("enemy", 5.0, 0.0, 0.0).unwrap();
+        original.despawn_world_object(&doomed.id).unwrap();
+        original.enqueue_input(move_input("player:local", 1.0));
+        original.tick_once().unwrap();
+        original.tick_once().unwrap();
+        original.drain_output_buffer();
+
+        let json = original.inspect_world_state().unwrap().to_json().unwrap();
+        let mut restored = build_runtime(LocalChannel::default());
+        let stale = restored.spawn_world_object("prop", 0.0, 0.0, 0.0).unwrap();
+        restored.enqueue_input(move_input("player:ghost", 1.0));
+        restored
+            .restore_world_state(&WorldSnapshot::from_json(&json).unwrap())
+            .unwrap();
+
+        assert_eq!(restored.current_tick(), original.current_tick());
+        assert_eq!(
+            restored.state_hash().unwrap(),
+            original.state_hash().unwrap()
+        );
+        assert_eq!(
+            restored.inspect_world_state().unwrap(),
+            original.inspect_world_state().unwrap()
+        );
+        restored.tick_once().unwrap();
+        let outputs = restored
+            .drain_output_buffer()
+            .into_iter()
+            .flat_map(|bundle| bundle.messages)
+            .map(|message| (message.address, message.args[0].clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outputs,
+            vec![
+                (
+                    "/render/prop/despawn".to_string(),
+                    OscArg::Str(stale.id.clone())
+                ),
+                (
+                    "/render/player/transform".to_string(),
+                    OscArg::Str("player:local".to_string())
+                ),
+            ]
+        );
+        original.tick_once().unwrap();
+        original.drain_output_buffer();
+
+        for runtime in [&mut original, &mut restored] {
+            runtime.enqueue_input(move_input("player:local", 0.5));
+            runtime.tick_once().unwrap();
+        }
+        assert_eq!(
+            restored.inspect_world_state().unwrap(),
+            original.inspect_world_state().unwrap()
+        );
+        assert_eq!(
+            restored.spawn_world_object("enemy", 0.0, 0.0, 0.0).unwrap(),
+            original.spawn_world_object("enemy", 0.0, 0.0, 0.0).unwrap()
+        );
+    }
+
+    #[test]
+    fn state_hashes_match_for_identical_runs_and_flag_divergence() {
+        let run = |late_dx: f32| {
+            let mut runtime = build_runtime(LocalChannel::default());
+            runtime.record_state_hash_every(1);
+            runtime.spawn_world_object("enemy", 0.0, 0.0, 3.0).unwrap();
+            runtime.enqueue_input(move_input("player:local", 1.0));
+            runtime.run_for_ticks(2).unwrap();
+            runtime.enqueue_input(move_input("player:local", late_dx));
+            runtime.run_for_ticks(2).unwrap();
+            runtime.drain_state_hashes()
+        };
+
+        let first = run(0.5);
+        assert_eq!(first, run(0.5));
+        assert_eq!(
+            first.iter().map(|(tick, _)| tick.get()).collect::<Vec<_>>(),
+            [1, 2, 3, 4]
+        );
+
+        let diverged = run(0.25);
+        assert_eq!(first[..2], diverged[..2]);
+        assert_ne!(first[2], diverged[2]);
+    }
+
+    #[test]
+    fn paused_update_keeps_polling_transport_without_ticking() {
+        let mut transport = LocalChannel::default();
+        for message in move_input("player:local", 1.0).messages {
+            transport.send(message).unwrap();
+        }
+        let mut runtime = build_runtime(transport);
+        runtime.update(1.0 / 120.0).unwrap();
+        runtime.pause();
+
+        assert_eq!(runtime.update(1.0).unwrap().ticks, 0);
+        assert_eq!(runtime.current_tick().get(), 0);
+        assert!(runtime.inspect_world_state().unwrap().objects.is_empty());
+
+        runtime.step(1).unwrap();
+        assert_eq!(runtime.inspect_world_state().unwrap().objects.len(), 1);
+        runtime.resume();
+        assert_eq!(runtime.update(1.0 / 120.0).unwrap().ticks, 1);
+        assert_eq!(runtime.current_tick().get(), 2);
+    }
+
+    #[test]
+    fn late_input_resimulates_to_the_on_time_result_and_emits_corrections() {
+        let mut on_time = build_runtime(LocalChannel::default());
+        on_time.record_state_hash_every(1);
+        on_time.enqueue_input(move_input("player:local", 1.0));
+        on_time.tick_once().unwrap();
+        on_time.enqueue_input(move_input("player:local", 0.5));
+        on_time.run_for_ticks(3).unwrap();
+
+        let mut late = build_runtime(LocalChannel::default());
+        late.set_rollback_window(3);
+        late.record_state_hash_every(1);
+        late.enqueue_input(move_input("player:local", 1.0));
+        late.run_for_ticks(4).unwrap();
+        late.drain_output_buffer();
+        let ticks: Vec<u64> = late
+            .rollback_ticks()
+            .iter()
+            .map(|tick| tick.get())
+            .collect();
+        assert_eq!(ticks, [1, 2, 3]);
+
+        let resimulated = late
+            .enqueue_input_at(Tick::start().next(), move_input("player:local", 0.5))
+            .unwrap();
+        assert_eq!(resimulated, 3);
+        assert_eq!(late.current_tick().get(), 4);
+        assert_eq!(late.state_hash().unwrap(), on_time.state_hash().unwrap());
+        assert_eq!(late.drain_state_hashes(), on_time.drain_state_hashes());
+        assert_eq!(late.rollback_ticks().len(), 3);
+
+        late.tick_once().unwrap();
+        let outputs = late.drain_output_buffer();
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].messages[0].address, "/render/player/correction");
+        assert_eq!(
+            outputs[0].messages[0].args,
+            vec![
+                OscArg::Str("player:local".to_string()),
+                OscArg::Int64(3),
+                OscArg::Float(1.5),
+                OscArg::Float(0.0),
+                OscArg::Float(0.0),
+            ]
+        );
+
+        let on_time_input = late
+            .enqueue_input_at(late.current_tick(), move_input("player:local", 1.0))
+            .unwrap();
+        assert_eq!(on_time_input, 0);
+    }
+
+    #[test]
+    fn collisions_block_players_and_reach_systems_and_outputs() {
+        struct ContactLog {
+            cursor: kitu_ecs::EventCursor<Contact>,
+            seen: Arc<Mutex<Vec<(u64, Entity, Entity)>>>,
+        }
+
+        impl kitu_ecs::System for ContactLog {
+            fn run(&mut self, world: &mut EcsWorld, tick: Tick) -> Result<()> {
+                let mut seen = self.seen.lock().unwrap();
+                for contact in world.read_events(&mut self.cursor) {
+                    seen.push((tick.get(), contact.a, contact.b));
+                }
+                Ok(())
+            }
+        }
+
+        let mut runtime = build_runtime(LocalChannel::default());
+        runtime.enable_collisions(CollisionSettings {
+            response: true,
+            emit_output: true,
+        });
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        runtime
+            .world_mut()
+            .schedule_system(ContactLog {
+                cursor: kitu_ecs::EventCursor::default(),
+                seen: Arc::clone(&seen),
+            })
+            .unwrap();
+        let wall = runtime.spawn_world_object("wall", 2.0, 0.0, 0.0).unwrap();
+        let player = runtime.spawn_world_object("player", 0.0, 0.0, 0.0).unwrap();
+        let wall_collider = Collider::aabb(Vec3::new(0.5, 1.0, 1.0)).into_static();
+        runtime
+            .set_world_object_collider(&wall.id, wall_collider)
+            .unwrap();
+        runtime
+            .set_world_object_collider(&player.id, Collider::sphere(0.5))
+            .unwrap();
+        runtime.drain_output_buffer();
+
+        runtime.enqueue_input(move_input(&player.id, 0.75));
+        runtime.tick_once().unwrap();
+        runtime.enqueue_input(move_input(&player.id, 0.75));
+        runtime.tick_once().unwrap();
+
+        let state = runtime.inspect_world_state().unwrap();
+        assert_eq!(state.objects[1].transform.x, 1.0);
+        assert_eq!(state.objects[0].collider, Some(wall_collider));
+        let collisions: Vec<_> = runtime
+            .drain_output_buffer()
+            .into_iter()
+            .flat_map(|bundle| bundle.messages)
+            .filter(|message| message.address == "/game/collision")
+            .map(|message| message.args)
+            .collect();
+        assert_eq!(
+            collisions,
+            vec![vec![
+                OscArg::Str(wall.id.clone()),
+                OscArg::Str(player.id.clone()),
+                OscArg::Int64(1),
+                OscArg::Float(-1.0),
+                OscArg::Float(0.0),
+                OscArg::Float(0.0),
+                OscArg::Float(0.5),
+            ]]
+        );
+
+        runtime.tick_once().unwrap();
+        assert_eq!(*seen.lock().unwrap(), vec![(2, wall.entity, player.entity)]);
+        assert_eq!(
+            runtime.remove_world_object_collider(&wall.id).unwrap(),
+            Some(wall_collider)
+        );
+    }
+
+    #[test]
+    fn rollback_replays_the_contacts_systems_read_next_tick() {
+        struct ContactCounter {
+            cursor: kitu_ecs::EventCursor<Contact>,
+        }
+
+        impl kitu_ecs::System for ContactCounter {
+            fn run(&mut self, world: &mut EcsWorld, _tick: Tick) -> Result<()> {
+                let seen = world.read_events(&mut self.cursor).len() as u32;
+                if let Some(count) = world.get_resource_mut::<u32>() {
+                    *count += seen;
+                }
+                Ok(())
+            }
+        }
+
+        let run = |late: bool| {
+            let mut runtime = build_runtime(LocalChannel::default());
+            runtime.enable_collisions(CollisionSettings::default());
+            runtime
+                .world_mut()
+                .insert_serializable_resource("contacts_seen", 0_u32)
+                .unwrap();
+            runtime
+                .world_mut()
+                .schedule_system(ContactCounter {
+                    cursor: kitu_ecs::EventCursor::default(),
+                })
+                .unwrap();
+            let wall = runtime.spawn_world_object("wall", 2.0, 0.0, 0.0).unwrap();
+            let player = runtime.spawn_world_object("player", 0.0, 0.0, 0.0).unwrap();
+            runtime
+                .set_world_object_collider(
+                    &wall.id,
+                    Collider::aabb(Vec3::new(0.5, 1.0, 1.0)).into_static(),
+                )
+                .unwrap();
+            runtime
+                .set_world_object_collider(&player.id, Collider::sphere(0.5))
+                .unwrap();
+            runtime.set_rollback_window(8);
+
+            // The player touches the wall from tick 1 on; the late input is stamped for tick 3.
+            for tick in 0..5 {
+                if !(late && tick == 3) {
+                    runtime.enqueue_input(move_input(&player.id, 0.75));
+                }
+                runtime.tick_once().unwrap();
+            }
+            if late {
+                let stamped = Tick::start().advance_by(3);
+                runtime
+                    .enqueue_input_at(stamped, move_input(&player.id, 0.75))
+                    .unwrap();
+            }
+            runtime.tick_once().unwrap();
+            runtime
+        };
+
+        let on_time = run(false);
+        let late = run(true);
+        let state = on_time.inspect_world_state().unwrap();
+        assert_eq!(state.resources["contacts_seen"], 4);
+        assert_eq!(late.inspect_world_state().unwrap(), state);
+    }
+
+    #[test]
+    fn client_outputs_follow_address_and_kind_interest() {
+        let mut runtime = build_runtime(LocalChannel::default());
+        runtime.enable_collisions(CollisionSettings {
+            response: true,
+            emit_output: true,
+        });
+        let wall = runtime.spawn_world_object("wall", 2.0, 0.0, 0.0).unwrap();
+        let player = runtime.spawn_world_object("player", 0.0, 0.0, 0.0).unwrap();
+        runtime
+            .set_world_object_collider(&wall.id, Collider::aabb(Vec3::ONE).into_static())
+            .unwrap();
+        runtime
+            .set_world_object_collider(&player.id, Collider::sphere(0.5))
+            .unwrap();
+        runtime.drain_output_buffer();
+        runtime
+            .set_client_interest("everyone", InterestFilter::default())
+            .unwrap();
+        runtime
+            .set_client_interest(
+                "collisions",
+                InterestFilter::default().with_address_prefix("/game/"),
+            )
+            .unwrap();
+        runtime
+            .set_client_interest("walls", InterestFilter::default().with_kind("wall"))
+            .unwrap();
+        runtime
+            .set_client_interest("lobby", InterestFilter::default().with_kind("npc"))
+            .unwrap();
+        assert!(runtime
+            .set_client_interest(
+                "broken",
+                InterestFilter::default().within_radius(&player.id, -1.0)
+            )
+            .is_err());
+
+        runtime.enqueue_input(move_input(&player.id, 1.0));
+        runtime.tick_once().unwrap();
+
+        let addresses = |bundles: &Vec<OscBundle>| -> Vec<String> {
+            bundles
+                .iter()
+                .flat_map(|bundle| &bundle.messages)
+                .map(|message| message.address.clone())
+                .collect()
+        };
+        let routed = runtime.drain_client_outputs();
+        assert_eq!(
+            routed.keys().collect::<Vec<_>>(),
+            ["collisions", "everyone", "lobby", "walls"]
+        );
+        // Objects spawned before the first tick are extracted along with the moved player.
+        assert_eq!(
+            addresses(&routed["everyone"]),
+            [
+                "/game/collision",
+                "/render/wall/transform",
+                "/render/player/transform"
+            ]
+        );
+        assert_eq!(addresses(&routed["collisions"]), ["/game/collision"]);
+        assert_eq!(
+            addresses(&routed["walls"]),
+            ["/game/collision", "/render/wall/transform"]
+        );
+        assert_eq!(
+            routed["walls"][1].messages[0].args[0],
+            OscArg::Str(wall.id.clone())
+        );
+        assert!(routed["lobby"].is_empty());
+
+        let mut notice = OscMessage::new("/game/notice");
+        notice.push_arg(OscArg::Str("round over".to_string()));
+        assert_eq!(
+            runtime.interested_clients(&notice),
+            ["collisions", "everyone", "lobby", "walls"]
+        );
+        assert!(runtime.remove_client_interest("lobby").is_some());
+        assert!(runtime.client_interest("lobby").is_none());
+    }
+
+    #[test]
+    fn random_streams_replay_through_snapshots_and_rollback() {
+        #[derive(Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct LootRolls(Vec<u64>);
+
+        struct LootRoller;
+
+        impl kitu_ecs::System for LootRoller {
+            fn run(&mut self, world: &mut EcsWorld, _tick: Tick) -> Result<()> {
+                let roll = world
+                    .get_resource_mut::<RngStreams>()
+                    .expect("runtime is seeded")
+                    .stream("loot")
+                    .gen_range(0..1000);
+                world
+                    .get_resource_mut::<LootRolls>()
+                    .expect("rolls are registered")
+                    .0
+                    .push(roll);
+                Ok(())
+            }
+        }
+
+        let seeded = |seed| {
+            let mut runtime = build_runtime(LocalChannel::default());
+            runtime.seed_rng(seed).unwrap();
+            runtime
+                .world_mut()
+                .insert_serializable_resource("test.loot_rolls", LootRolls::default())
+                .unwrap();
+            runtime.world_mut().schedule_system(LootRoller).unwrap();
+            runtime
+        };
+        let rolls = |runtime: &Runtime<LocalChannel>| {
+            runtime.world.get_resource::<LootRolls>().unwrap().0.clone()
+        };
+
+        let mut on_time = seeded(7);
+        on_time.enqueue_input(move_input("player:local", 1.0));
+        on_time.run_for_ticks(6).unwrap();
+        assert_eq!(on_time.rng_seed(), Some(7));
+        assert_eq!(rolls(&on_time).len(), 6);
+
+        let mut other_seed = seeded(8);
+        other_seed.run_for_ticks(6).unwrap();
+        assert_ne!(rolls(&other_seed), rolls(&on_time));
+
+        // The late input resimulates five ticks; the streams rewind with the snapshots.
+        let mut late = seeded(7);
+        late.set_rollback_window(8);
+        late.run_for_ticks(6).unwrap();
+        late.enqueue_input_at(Tick::start(), move_input("player:local", 1.0))
+            .unwrap();
+        assert_eq!(rolls(&late), rolls(&on_time));
+        assert_eq!(late.state_hash().unwrap(), on_time.state_hash().unwrap());
+
+        let snapshot = on_time.inspect_world_state().unwrap();
+        let mut unseeded = build_runtime(LocalChannel::default());
+        unseeded
+            .world_mut()
+            .insert_serializable_resource("test.loot_rolls", LootRolls::default())
+            .unwrap();
+        assert!(matches!(
+            unseeded.restore_world_state(&snapshot),
+            Err(KituError::Serialization(message)) if message.contains(RNG_STREAMS_KEY)
+        ));
+        assert_eq!(unseeded.current_tick(), Tick::start());
+        assert_eq!(unseeded.rng_seed(), None);
+
+        let mut restored = seeded(99);
+        restored.restore_world_state(&snapshot).unwrap();
+        assert_eq!(restored.rng_seed(), Some(7));
+        on_time.tick_once().unwrap();
+        restored.tick_once().unwrap();
+        assert_eq!(rolls(&restored), rolls(&on_time));
+        assert_eq!(
+            restored.rng_stream("loot").unwrap().next_u64(),
+            on_time.rng_stream("loot").unwrap().next_u64()
+        );
+    }
+
+    #[test]
+    fn host_draws_from_random_streams_clear_the_rollback_history() {
+        let mut runtime = build_runtime(LocalChannel::default());
+        runtime.set_rollback_window(4);
+        runtime.run_for_ticks(2).unwrap();
+        assert!(runtime.rng_stream("loot").is_none());
+        assert_eq!(runtime.rollback_ticks().len(), 2);
+
+        runtime.seed_rng(3).unwrap();
+        runtime.run_for_ticks(2).unwrap();
+        assert_eq!(runtime.rollback_ticks().len(), 2);
+        runtime.rng_stream("loot").unwrap().next_u64();
+        assert!(runtime.rollback_ticks().is_empty());
+        assert!(runtime
+            .enqueue_input_at(Tick::start().advance_by(3), move_input("player:local", 1.0))
+            .is_err());
+    }
+
+    #[test]
+    fn kinematic_moves_set_intent_and_integrate_deterministically() {
+        let settings = KinematicSettings {
+            acceleration: 120.0,
+            max_speed: 3.0,
+            friction: 90.0,
+        };
+        let mut runtime = build_runtime(LocalChannel::default());
+        runtime.enable_kinematic_movement(settings).unwrap();
+        runtime.set_rollback_window(8);
+        runtime.enqueue_input(move_input("player:local", 5.0));
+        runtime.run_for_ticks(4).unwrap();
+
+        let body = runtime.kinematic_body("player:local").unwrap();
+        assert_eq!(body.intent, Vec3::new(1.0, 0.0, 0.0));
+        assert!((body.velocity.x - 3.0).abs() < 1e-4);
+        let moving_x = runtime.inspect_world_state().unwrap().objects[0]
+            .transform
+            .x;
+        assert!(moving_x > 0.0 && moving_x < 0.2);
+
+        let mut on_time = build_runtime(LocalChannel::default());
+        on_time.enable_kinematic_movement(settings).unwrap();
+        on_time.enqueue_input(move_input("player:local", 5.0));
+        on_time.run_for_ticks(4).unwrap();
+        on_time.enqueue_input(move_input("player:local", 0.0));
+        on_time.run_for_ticks(4).unwrap();
+        let body = on_time.kinematic_body("player:local").unwrap();
+        assert_eq!(body.velocity, Vec3::ZERO);
+        let resting_x = on_time.inspect_world_state().unwrap().objects[0]
+            .transform
+            .x;
+        assert!(resting_x > moving_x);
+
+        // The stop arrives late; resimulation must land on the same bits as the on-time run.
+        runtime.run_for_ticks(4).unwrap();
+        assert_ne!(runtime.state_hash().unwrap(), on_time.state_hash().unwrap());
+        runtime
+            .enqueue_input_at(Tick::start().advance_by(4), move_input("player:local", 0.0))
+            .unwrap();
+        assert_eq!(runtime.state_hash().unwrap(), on_time.state_hash().unwrap());
+
+        assert_eq!(
+            runtime.enable_kinematic_movement(KinematicSettings {
+                max_speed: 0.0,
+                ..settings
+            }),
+            Err(KituError::InvalidInput(
+                "kinematic settings must be finite, non-negative and allow a positive max speed"
+            ))
+        );
+        runtime.despawn_world_object("player:local").unwrap();
+        assert_eq!(runtime.kinematic_body("player:local"), None);
+    }
+
+    #[test]
+    fn fixed_point_mode_moves_collides_and_hashes_in_fixed_point() {
+        let start = |mode| {
+            let mut runtime = build_runtime(LocalChannel::default());
+            runtime.set_numeric_mode(mode).unwrap();
+            runtime
+                .enable_kinematic_movement(KinematicSettings {
+                    acceleration: 120.0,
+                    max_speed: 3.0,
+                    friction: 90.0,
+                })
+                .unwrap();
+            runtime.enable_collisions(CollisionSettings::default());
+            let wall = runtime.spawn_world_object("wall", 2.0, 0.0, 0.0).unwrap();
+            runtime
+                .set_world_object_collider(&wall.id, Collider::aabb(Vec3::ONE).into_static())
+                .unwrap();
+            runtime.enqueue_input(move_input("player:local", 1.0));
+            runtime.tick_once().unwrap();
+            runtime
+                .set_world_object_collider("player:local", Collider::sphere(0.5))
+                .unwrap();
+            runtime
+        };
+        let player_x = |runtime: &Runtime<LocalChannel>| {
+            runtime
+                .inspect_world_state()
+                .unwrap()
+                .objects
+                .into_iter()
+                .find(|object| object.id == "player:local")
+                .unwrap()
+                .transform
+                .x
+        };
+
+        let mut fixed = start(NumericMode::FixedPoint);
+        let mut float = start(NumericMode::Float);
+        fixed.run_for_ticks(4).unwrap();
+        float.run_for_ticks(4).unwrap();
+        assert_eq!(player_x(&fixed).to_bits(), 0x3e6e_eef0);
+        assert!((player_x(&fixed) - player_x(&float)).abs() < 1e-5);
+        assert_eq!(
+            fixed.state_hash().unwrap(),
+            fixed.inspect_world_state().unwrap().fixed_state_hash()
+        );
+
+        // Velocities and positions stay in fixed point; transforms are their rounding.
+        let position = fixed.fixed_position("player:local").unwrap();
+        assert_eq!(position.x.to_bits(), 1_002_159_107);
+        assert_eq!(position.x.to_f32(), player_x(&fixed));
+        let body = fixed.fixed_kinematic_body("player:local").unwrap();
+        // Clamping to the max speed rounds down by one step, which `f32` cannot show.
+        assert_eq!(body.velocity.x, Fixed::from_int(3) - Fixed::EPSILON);
+        assert_eq!(
+            fixed.kinematic_body("player:local").unwrap().velocity.x,
+            3.0
+        );
+        assert_eq!(float.fixed_position("player:local"), None);
+
+        // Snapshots carry the exact position, not its rounding.
+        let mut restored = start(NumericMode::FixedPoint);
+        restored
+            .restore_world_state(&fixed.inspect_world_state().unwrap())
+            .unwrap();
+        assert_eq!(restored.fixed_position("player:local"), Some(position));
+        restored.run_for_ticks(2).unwrap();
+        fixed.run_for_ticks(2).unwrap();
+        assert_eq!(restored.state_hash().unwrap(), fixed.state_hash().unwrap());
+
+        // The wall stops the player exactly at its face.
+        let mut blocked = start(NumericMode::FixedPoint);
+        blocked.run_for_ticks(20).unwrap();
+        assert_eq!(
+            blocked.fixed_position("player:local").unwrap().x,
+            Fixed::HALF
+        );
+        assert_eq!(player_x(&blocked), 0.5);
+
+        // A late stop resimulates through the fixed-point steps onto the on-time bits.
+        let mut on_time = start(NumericMode::FixedPoint);
+        on_time.run_for_ticks(6).unwrap();
+        on_time.enqueue_input(move_input("player:local", 0.0));
+        on_time.run_for_ticks(4).unwrap();
+        fixed.set_rollback_window(8);
+        fixed.run_for_ticks(4).unwrap();
+        fixed
+            .enqueue_input_at(Tick::start().advance_by(7), move_input("player:local", 0.0))
+            .unwrap();
+        assert_eq!(fixed.state_hash().unwrap(), on_time.state_hash().unwrap());
+        assert_eq!(fixed.numeric_mode(), NumericMode::FixedPoint);
+    }
+
+    #[test]
+    fn future_inputs_apply_on_their_stamped_tick() {
+        let mut runtime = build_runtime(LocalChannel::default());
+        runtime.run_for_ticks(2).unwrap();
+        let stamped = runtime.current_tick().advance_by(3);
+        let queued = runtime
+            .enqueue_input_at(stamped, move_input("player:local", 1.0))
+            .unwrap();
+        assert_eq!(queued, 0);
+
+        runtime.run_for_ticks(3).unwrap();
+        assert_eq!(runtime.current_tick(), stamped);
+        assert!(runtime.inspect_world_state().unwrap().objects.is_empty());
+        assert!(runtime.drain_output_buffer().is_empty());
+
+        runtime.tick_once().unwrap();
+        let outputs = runtime.drain_output_buffer();
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(
+            outputs[0].messages[0].args[1],
+            OscArg::Int64(stamped.get() as i64)
+        );
+        assert_eq!(runtime.inspect_world_state().unwrap().objects.len(), 1);
+    }
+
+    #[test]
+    fn rejected_late_inputs_leave_the_runtime_unchanged() {
+        let mut runtime = build_runtime(LocalChannel::default());
+        runtime.set_rollback_window(2);
+        runtime.enqueue_input(move_input("player:local", 1.0));
+        runtime.run_for_ticks(3).unwrap();
+        runtime.enqueue_input(move_input("player:local", 1.0));
+        runtime.tick_once().unwrap();
+        runtime.drain_output_buffer();
+        let state = runtime.inspect_world_state().unwrap();
+
+        let too_old = runtime.enqueue_input_at(Tick::start(), move_input("player:local", 1.0));
+        assert!(matches!(too_old, Err(KituError::InvalidInput(_))));
+
+        let mut invalid = OscMessage::new("/input/move");
+        invalid.push_arg(OscArg::Str("player:local".to_string()));
+        let mut invalid_batch = OscBundle::new();
+        invalid_batch.push(invalid);
+        let tick = runtime.rollback_ticks()[1];
+        assert!(runtime.enqueue_input_at(tick, invalid_batch).is_err());
+
+        assert_eq!(runtime.inspect_world_state().unwrap(), state);
+        assert_eq!(runtime.rollback_ticks().len(), 2);
+        // The late message counts after the move already recorded for its tick.
+        let rejections = runtime.drain_input_rejections();
+        assert_eq!((rejections[0].tick, rejections[0].index), (tick, 1));
+        runtime.tick_once().unwrap();
+        let outputs = runtime.drain_output_buffer();
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].messages[0].address, "/debug/input/rejected");
+
+        runtime.spawn_world_object("crate", 0.0, 0.0, 0.0).unwrap();
+        assert!(runtime.rollback_ticks().is_empty());
+        let tick = Tick::start().advance_by(4);
+        assert!(runtime
+            .enqueue_input_at(tick, move_input("player:local", 1.0))
+            .is_err());
+    }
+
+    #[test]
+    fn registered_components_survive_late_inputs_and_others_block_them() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Health(u32);
+
+        struct Poison;
+
+        impl kitu_ecs::System for Poison {
+            fn run(&mut self, world: &mut EcsWorld, _tick: Tick) -> Result<()> {
+                for entity in world.query_entities::<(Health,)>() {
+                    if let Some(health) = world.get_component_mut::<Health>(entity) {
+                        health.0 -= 1;
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        let mut runtime = build_runtime(LocalChannel::default());
+        runtime.set_rollback_window(3);
+        runtime
+            .world_mut()
+            .register_serializable_component::<Health>("health")
+            .unwrap();
+        runtime.world_mut().schedule_system(Poison).unwrap();
+        runtime.enqueue_input(move_input("player:local", 1.0));
+        runtime.tick_once().unwrap();
+        let player = runtime.world_mut().entity_by_name("player:local").unwrap();
+        runtime
+            .world_mut()
+            .insert_component(player, Health(10))
+            .unwrap();
+        runtime.run_for_ticks(3).unwrap();
+
+        let tick = runtime.rollback_ticks()[0];
+        runtime
+            .enqueue_input_at(tick, move_input("player:local", 0.5))
+            .unwrap();
+        assert_eq!(
+            runtime.world_mut().get_component::<Health>(player),
+            Some(&Health(7))
+        );
+
+        struct Marker;
+        runtime
+            .world_mut()
+            .insert_component(player, Marker)
+            .unwrap();
+        let state = runtime.inspect_world_state().unwrap();
+        let refused = runtime.enqueue_input_at(tick, move_input("player:local", 1.0));
+        assert!(matches!(refused, Err(KituError::InvalidInput(_))));
+        assert_eq!(runtime.inspect_world_state().unwrap(), state);
+        assert!(runtime
+            .world_mut()
+            .get_component::<Marker>(player)
+            .is_some());
+    }
+
     #[test]
     fn world_api_reset_clears_runtime_state() {
         let mut runtime = build_runtime(LocalChannel::default());
@@ -851,7 +2445,7 @@ mod tests {
             .unwrap();
         runtime.reset_world_objects();
 
-        assert!(runtime.inspect_world_state().objects.is_empty());
+        assert!(runtime.inspect_world_state().unwrap().objects.is_empty());
         runtime.tick_once().unwrap();
         assert!(runtime.drain_output_buffer().is_empty());
     }
@@ -869,7 +2463,7 @@ mod tests {
         let outcome = runtime.run_app_action("spawn-object", &inputs).unwrap();
 
         assert_eq!(outcome.message.address, "/admin/world/spawn");
-        assert_eq!(runtime.inspect_world_state().objects.len(), 1);
+        assert_eq!(runtime.inspect_world_state().unwrap().objects.len(), 1);
     }
 
     #[test]
@@ -916,15 +2510,22 @@ mod tests {
         let mut runtime = build_runtime(LocalChannel::default());
         let seen_ticks = Arc::new(Mutex::new(Vec::new()));
 
-        runtime.world_mut().schedule_system(TickCaptureSystem {
-            seen_ticks: Arc::clone(&seen_ticks),
-        });
+        runtime
+            .world_mut()
+            .schedule_system(TickCaptureSystem {
+                seen_ticks: Arc::clone(&seen_ticks),
+            })
+            .unwrap();
 
         runtime.tick_once().unwrap();
 
         let ticks = seen_ticks.lock().unwrap().clone();
         assert_eq!(ticks, vec![0]);
         assert_eq!(runtime.current_tick().get(), 1);
+
+        runtime.run_for_ticks(2).unwrap();
+        let ticks = seen_ticks.lock().unwrap().clone();
+        assert_eq!(ticks, vec![0, 1, 2]);
     }
 
     #[test]
@@ -957,12 +2558,18 @@ mod tests {
         runtime.tick_once().unwrap();
         let outputs = runtime.drain_output_buffer();
         assert_eq!(outputs.len(), 1);
+        let player = runtime.world_mut().entity_by_name("player:local").unwrap();
         assert_eq!(
-            runtime.inspect_world_state().objects,
+            runtime.inspect_world_state().unwrap().objects,
             vec![WorldObject {
+                entity: player,
                 id: "player:local".to_string(),
+                named: true,
                 kind: "player".to_string(),
                 transform: WorldTransform::new(1.0, 0.0, -0.25),
+                parent: None,
+                local: None,
+                collider: None,
             }]
         );
         let render = &outputs[0].messages[0];
@@ -979,6 +2586,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn render_extraction_emits_only_entities_changed_this_tick() {
+        struct DriftSystem;
+
+        impl kitu_ecs::System for DriftSystem {
+            fn run(&mut self, world: &mut EcsWorld, tick: Tick) -> Result<()> {
+                if tick.get() == 2 {
+                    world.move_world_object("drifter", WorldTransform::new(5.0, 0.0, 5.0))?;
+                }
+                Ok(())
+            }
+        }
+
+        let mut runtime = build_runtime(LocalChannel::default());
+        runtime
+            .world_mut()
+            .spawn_world_object_with_id("drifter", "enemy", WorldTransform::new(0.0, 0.0, 0.0))
+            .unwrap();
+        runtime.world_mut().schedule_system(DriftSystem).unwrap();
+        let rock = runtime.spawn_world_object("rock", 1.0, 0.0, 1.0).unwrap();
+
+        runtime.tick_once().unwrap();
+        assert_eq!(runtime.drain_output_buffer().len(), 2);
+
+        let mut idle = OscMessage::new("/input/move");
+        idle.push_arg(OscArg::Str(rock.id.clone()));
+        idle.push_arg(OscArg::Float(0.0));
+        idle.push_arg(OscArg::Float(0.0));
+        let mut bundle = OscBundle::new();
+        bundle.push(idle);
+        runtime.enqueue_input(bundle);
+        runtime.tick_once().unwrap();
+        assert!(runtime.drain_output_buffer().is_empty());
+
+        runtime.tick_once().unwrap();
+        let outputs = runtime.drain_output_buffer();
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(
+            outputs[0].messages[0].args,
+            vec![
+                OscArg::Str("drifter".to_string()),
+                OscArg::Int64(2),
+                OscArg::Float(5.0),
+                OscArg::Float(5.0),
+                OscArg::Float(0.0),
+            ]
+        );
+
+        runtime.move_world_object(&rock.id, 3.0, 0.0, 4.0).unwrap();
+        runtime.tick_once().unwrap();
+        let outputs = runtime.drain_output_buffer();
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].messages[0].args[0], OscArg::Str(rock.id.clone()));
+        assert_eq!(outputs[0].messages[0].args[1], OscArg::Int64(3));
+    }
+
     #[test]
     fn move_input_received_during_tick_is_applied_next_tick() {
         struct ScriptedTransport {
@@ -1049,9 +2712,19 @@ mod tests {
 
         runtime.tick_once().unwrap();
         let outputs = runtime.drain_output_buffer();
-        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(outputs[0].messages[0].address, "/debug/input/rejected");
         assert_eq!(
             outputs[0].messages[0].args,
+            vec![
+                OscArg::Int64(0),
+                OscArg::Int(1),
+                OscArg::Str("/input/move".to_string()),
+                OscArg::Str("invalid input: /input/move expects [entity_id, x, y]".to_string()),
+            ]
+        );
+        assert_eq!(
+            outputs[1].messages[0].args,
             vec![
                 OscArg::Str("player:local".to_string()),
                 OscArg::Int64(0),
@@ -1082,7 +2755,8 @@ mod tests {
         let mut runtime = build_runtime(LocalChannel::default());
         runtime
             .world_mut()
-            .schedule_system(FailsOnceSystem { has_failed: false });
+            .schedule_system(FailsOnceSystem { has_failed: false })
+            .unwrap();
 
         let mut move_message = OscMessage::new("/input/move");
         move_message.push_arg(OscArg::Str("player:local".to_string()));
@@ -1126,9 +2800,12 @@ mod tests {
 
         let mut runtime = build_runtime(LocalChannel::default());
         let runs = Arc::new(Mutex::new(0));
-        runtime.world_mut().schedule_system(CounterSystem {
-            runs: Arc::clone(&runs),
-        });
+        runtime
+            .world_mut()
+            .schedule_system(CounterSystem {
+                runs: Arc::clone(&runs),
+            })
+            .unwrap();
 
         let mut invalid = OscMessage::new("/input/move");
         invalid.push_arg(OscArg::Str("player:local".to_string()));
@@ -1163,18 +2840,30 @@ mod tests {
         runtime.tick_once().unwrap();
         let outputs = runtime.drain_output_buffer();
         assert_eq!(outputs.len(), 2);
+        let one = runtime.world_mut().entity_by_name("player:one").unwrap();
+        let two = runtime.world_mut().entity_by_name("player:two").unwrap();
         assert_eq!(
-            runtime.inspect_world_state().objects,
+            runtime.inspect_world_state().unwrap().objects,
             vec![
                 WorldObject {
+                    entity: one,
                     id: "player:one".to_string(),
+                    named: true,
                     kind: "player".to_string(),
                     transform: WorldTransform::new(1.0, 0.0, 0.0),
+                    parent: None,
+                    local: None,
+                    collider: None,
                 },
                 WorldObject {
+                    entity: two,
                     id: "player:two".to_string(),
+                    named: true,
                     kind: "player".to_string(),
                     transform: WorldTransform::new(0.0, 0.0, 2.0),
+                    parent: None,
+                    local: None,
+                    collider: None,
                 },
             ]
         );
@@ -1225,7 +2914,95 @@ mod tests {
 
         runtime.tick_once().unwrap();
         let outputs = runtime.drain_output_buffer();
-        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(outputs[0].messages[0].address, "/debug/input/rejected");
+        assert_eq!(outputs[1].messages[0].address, "/render/player/transform");
+    }
+
+    #[test]
+    fn reject_message_policy_keeps_valid_inputs_and_reports_the_rest() {
+        let mut runtime = build_runtime(LocalChannel::default());
+        runtime.set_input_rejection_policy(InputRejectionPolicy::RejectMessage);
+
+        let mut invalid = OscMessage::new("/input/move");
+        invalid.push_arg(OscArg::Str("player:b".to_string()));
+        invalid.push_arg(OscArg::Str("left".to_string()));
+        invalid.push_arg(OscArg::Float(0.0));
+        let mut batch = move_input("player:a", 1.0);
+        batch.push(OscMessage::new("/input/unhandled"));
+        batch.push(invalid);
+        runtime.enqueue_input(batch);
+        runtime.enqueue_input(move_input("player:c", 2.0));
+
+        runtime.tick_once().unwrap();
+        let ids: Vec<String> = runtime
+            .inspect_world_state()
+            .unwrap()
+            .objects
+            .into_iter()
+            .map(|object| object.id)
+            .collect();
+        assert_eq!(ids, ["player:a", "player:c"]);
+
+        assert_eq!(
+            runtime.drain_input_rejections(),
+            vec![InputRejection {
+                tick: Tick::start(),
+                index: 2,
+                address: "/input/move".to_string(),
+                reason: "invalid input: /input/move x must be numeric".to_string(),
+            }]
+        );
+        let outputs = runtime.drain_output_buffer();
+        let addresses: Vec<&str> = outputs
+            .iter()
+            .map(|bundle| bundle.messages[0].address.as_str())
+            .collect();
+        assert_eq!(
+            addresses,
+            [
+                "/debug/input/rejected",
+                "/render/player/transform",
+                "/render/player/transform"
+            ]
+        );
+    }
+
+    #[test]
+    fn input_handler_errors_reject_the_whole_batch() {
+        #[derive(Debug, PartialEq)]
+        struct Jump(String);
+
+        let mut runtime = build_runtime(LocalChannel::default());
+        runtime
+            .register_input_handler("/input/jump", |message| match message.args.first() {
+                Some(OscArg::Str(id)) => Ok(Jump(id.clone())),
+                _ => Err(KituError::InvalidInput("/input/jump expects [entity_id]")),
+            })
+            .unwrap();
+        assert!(runtime
+            .register_input_handler("/input/jump", |_| Ok(Jump(String::new())))
+            .is_err());
+        assert!(runtime
+            .register_input_handler("input/jump", |_| Ok(Jump(String::new())))
+            .is_err());
+
+        let mut batch = move_input("player:local", 1.0);
+        batch.push(OscMessage::new("/input/jump"));
+        runtime.enqueue_input(batch);
+        assert!(runtime.tick_once().is_err());
+        assert!(runtime.inspect_world_state().unwrap().objects.is_empty());
+        assert_eq!(runtime.world_mut().iter_events::<Jump>().count(), 0);
+
+        let mut jump = OscMessage::new("/input/jump");
+        jump.push_arg(OscArg::Str("player:local".to_string()));
+        let mut batch = move_input("player:local", 1.0);
+        batch.push(jump);
+        runtime.enqueue_input(batch);
+        runtime.tick_once().unwrap();
+        assert_eq!(runtime.inspect_world_state().unwrap().objects.len(), 1);
+        let jumps: Vec<&Jump> = runtime.world_mut().iter_events::<Jump>().collect();
+        assert_eq!(jumps, [&Jump("player:local".to_string())]);
     }
 
     #[test]
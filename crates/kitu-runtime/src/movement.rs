@@ -0,0 +1,414 @@
+//! Kinematic movement for `/input/move`.
+//!
+//! By default a move input is a displacement added to the object's position in the tick it
+//! arrives. With kinematic movement enabled it is an intent axis instead: the object keeps
+//! accelerating toward that direction, up to a maximum speed, until a new intent arrives, and
+//! friction brings it to rest once the intent is zero. Every tick integrates each body over
+//! [`RuntimeConfig::frame_time`](crate::RuntimeConfig::frame_time).
+//!
+//! Integration only uses IEEE-754 single-precision addition, multiplication, division and square
+//! root, visits bodies in id order, and keeps velocities in a serializable resource. Runs on
+//! different machines therefore produce bit-identical positions, and snapshots, state hashes and
+//! rollback cover the velocities as well as the positions. Under
+//! [`NumericMode::FixedPoint`](crate::NumericMode) bodies hold [`FixedVec3`] intents and
+//! velocities, integrate over a frame time of exactly `nanoseconds / 10^9`, and move the
+//! fixed-point positions of their objects; nothing is rounded to `f32` between ticks.
+
+use std::collections::BTreeMap;
+
+use kitu_core::{Fixed, FixedVec3, KituError, Result};
+use kitu_ecs::{Vec3, WorldTransform};
+use kitu_transport::Transport;
+use serde::{Deserialize, Serialize};
+
+use crate::{NumericMode, Runtime};
+
+/// Snapshot resource key holding every [`KinematicBody`], in the vector type of the current
+/// [`NumericMode`](crate::NumericMode).
+pub const KINEMATIC_BODIES_KEY: &str = "kitu.kinematic_bodies";
+
+/// Tuning for kinematic movement, in world units and seconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KinematicSettings {
+    /// Speed gained per second along the intent axis.
+    pub acceleration: f32,
+    /// Highest speed a body reaches; faster bodies are clamped to it.
+    pub max_speed: f32,
+    /// Speed lost per second while the intent is zero.
+    pub friction: f32,
+}
+
+impl Default for KinematicSettings {
+    fn default() -> Self {
+        Self {
+            acceleration: 20.0,
+            max_speed: 5.0,
+            friction: 30.0,
+        }
+    }
+}
+
+/// Movement state of one object driven by `/input/move`.
+///
+/// Both vectors lie in the ground plane: `/input/move [id, x, y]` maps to world `x` and `z`, and
+/// `y` is always zero. `V` is [`Vec3`] under [`NumericMode::Float`](crate::NumericMode) and
+/// [`FixedVec3`] under [`NumericMode::FixedPoint`](crate::NumericMode).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct KinematicBody<V = Vec3> {
+    /// Latest intent axis, at most unit length.
+    pub intent: V,
+    /// Velocity in world units per second.
+    pub velocity: V,
+}
+
+impl Default for KinematicBody {
+    fn default() -> Self {
+        Self {
+            intent: Vec3::ZERO,
+            velocity: Vec3::ZERO,
+        }
+    }
+}
+
+impl Default for KinematicBody<FixedVec3> {
+    fn default() -> Self {
+        Self {
+            intent: FixedVec3::ZERO,
+            velocity: FixedVec3::ZERO,
+        }
+    }
+}
+
+impl From<KinematicBody> for KinematicBody<FixedVec3> {
+    fn from(body: KinematicBody) -> Self {
+        Self {
+            intent: body.intent.into(),
+            velocity: body.velocity.into(),
+        }
+    }
+}
+
+impl From<KinematicBody<FixedVec3>> for KinematicBody {
+    /// Rounds both vectors to `f32`.
+    fn from(body: KinematicBody<FixedVec3>) -> Self {
+        Self {
+            intent: body.intent.into(),
+            velocity: body.velocity.into(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+struct KinematicBodies<V = Vec3>(BTreeMap<String, KinematicBody<V>>);
+
+impl<V> Default for KinematicBodies<V> {
+    fn default() -> Self {
+        Self(BTreeMap::new())
+    }
+}
+
+impl<T: Transport> Runtime<T> {
+    /// Switches `/input/move` from displacement to intent and integrates velocities every tick.
+    ///
+    /// Calling it again replaces the settings and keeps current velocities. Fails if any setting
+    /// is negative or not finite, or if `max_speed` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_osc_ir::{OscArg, OscBundle, OscMessage};
+    /// use kitu_runtime::{build_runtime, KinematicSettings};
+    /// use kitu_transport::LocalChannel;
+    ///
+    /// let mut runtime = build_runtime(LocalChannel::connected());
+    /// runtime
+    ///     .enable_kinematic_movement(KinematicSettings {
+    ///         acceleration: 60.0,
+    ///         max_speed: 6.0,
+    ///         friction: 60.0,
+    ///     })
+    ///     .unwrap();
+    ///
+    /// let mut message = OscMessage::new("/input/move");
+    /// message.push_arg(OscArg::Str("player:local".to_string()));
+    /// message.push_arg(OscArg::Float(1.0));
+    /// message.push_arg(OscArg::Float(0.0));
+    /// let mut input = OscBundle::new();
+    /// input.push(message);
+    /// runtime.enqueue_input(input);
+    ///
+    /// // The intent persists: the player speeds up by 1 unit/s per tick until it hits max speed.
+    /// runtime.run_for_ticks(10).unwrap();
+    /// let body = runtime.kinematic_body("player:local").unwrap();
+    /// assert!((body.velocity.x - 6.0).abs() < 1e-4);
+    /// ```
+    pub fn enable_kinematic_movement(&mut self, settings: KinematicSettings) -> Result<()> {
+        let valid = |value: f32| value.is_finite() && value >= 0.0;
+        if !(valid(settings.acceleration) && valid(settings.max_speed) && valid(settings.friction))
+            || settings.max_speed == 0.0
+        {
+            return Err(KituError::InvalidInput(
+                "kinematic settings must be finite, non-negative and allow a positive max speed",
+            ));
+        }
+        if self.fixed_point() {
+            if !self.world.contains_resource::<KinematicBodies<FixedVec3>>() {
+                self.world.insert_serializable_resource(
+                    KINEMATIC_BODIES_KEY,
+                    KinematicBodies::<FixedVec3>::default(),
+                )?;
+            }
+        } else if !self.world.contains_resource::<KinematicBodies>() {
+            self.world.insert_serializable_resource(
+                KINEMATIC_BODIES_KEY,
+                KinematicBodies::<Vec3>::default(),
+            )?;
+        }
+        self.kinematic = Some(settings);
+        Ok(())
+    }
+
+    /// Returns the kinematic settings, or `None` while moves are plain displacements.
+    pub fn kinematic_settings(&self) -> Option<KinematicSettings> {
+        self.kinematic
+    }
+
+    /// Returns the movement state of `id`, if it has received a move input under kinematic
+    /// movement.
+    ///
+    /// Under [`NumericMode::FixedPoint`](crate::NumericMode) this is the state rounded to `f32`;
+    /// see [`fixed_kinematic_body`](Self::fixed_kinematic_body).
+    pub fn kinematic_body(&self, id: &str) -> Option<KinematicBody> {
+        if self.fixed_point() {
+            return self.fixed_kinematic_body(id).map(KinematicBody::from);
+        }
+        self.world
+            .get_resource::<KinematicBodies>()
+            .and_then(|bodies| bodies.0.get(id).copied())
+    }
+
+    /// Returns the exact movement state of `id` under
+    /// [`NumericMode::FixedPoint`](crate::NumericMode), and `None` in float mode.
+    pub fn fixed_kinematic_body(&self, id: &str) -> Option<KinematicBody<FixedVec3>> {
+        self.world
+            .get_resource::<KinematicBodies<FixedVec3>>()
+            .and_then(|bodies| bodies.0.get(id).copied())
+    }
+
+    /// Drops the movement state of objects that were removed from the world.
+    pub(crate) fn forget_kinematic_bodies(&mut self, ids: &[String]) {
+        if let Some(bodies) = self.world.get_resource_mut::<KinematicBodies>() {
+            for id in ids {
+                bodies.0.remove(id);
+            }
+        }
+        if let Some(bodies) = self.world.get_resource_mut::<KinematicBodies<FixedVec3>>() {
+            for id in ids {
+                bodies.0.remove(id);
+            }
+        }
+    }
+
+    /// Re-registers the kinematic bodies in the vector type of `mode`.
+    pub(crate) fn convert_kinematic_bodies(&mut self, mode: NumericMode) -> Result<()> {
+        match mode {
+            NumericMode::FixedPoint => {
+                if let Some(bodies) = self.world.remove_resource::<KinematicBodies>() {
+                    let bodies = bodies.0.into_iter().map(|(id, body)| (id, body.into()));
+                    self.world.insert_serializable_resource(
+                        KINEMATIC_BODIES_KEY,
+                        KinematicBodies::<FixedVec3>(bodies.collect()),
+                    )?;
+                }
+            }
+            NumericMode::Float => {
+                if let Some(bodies) = self.world.remove_resource::<KinematicBodies<FixedVec3>>() {
+                    let bodies = bodies.0.into_iter().map(|(id, body)| (id, body.into()));
+                    self.world.insert_serializable_resource(
+                        KINEMATIC_BODIES_KEY,
+                        KinematicBodies::<Vec3>(bodies.collect()),
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn apply_kinematic_moves(
+        &mut self,
+        settings: KinematicSettings,
+        parsed_moves: Vec<(String, f32, f32)>,
+    ) -> Result<()> {
+        if self.fixed_point() {
+            let mut bodies = self
+                .world
+                .remove_resource::<KinematicBodies<FixedVec3>>()
+                .unwrap_or_default();
+            let result = self.integrate_fixed_bodies(settings, parsed_moves, &mut bodies.0);
+            self.world
+                .insert_serializable_resource(KINEMATIC_BODIES_KEY, bodies)?;
+            return result;
+        }
+        let mut bodies = self
+            .world
+            .remove_resource::<KinematicBodies>()
+            .unwrap_or_default();
+        let result = self.integrate_bodies(settings, parsed_moves, &mut bodies.0);
+        self.world
+            .insert_serializable_resource(KINEMATIC_BODIES_KEY, bodies)?;
+        result
+    }
+
+    fn integrate_bodies(
+        &mut self,
+        settings: KinematicSettings,
+        parsed_moves: Vec<(String, f32, f32)>,
+        bodies: &mut BTreeMap<String, KinematicBody>,
+    ) -> Result<()> {
+        for (entity_id, x, y) in parsed_moves {
+            self.spawn_moved_player(&entity_id)?;
+            bodies.entry(entity_id).or_default().intent = clamp_length(Vec3::new(x, 0.0, y), 1.0);
+        }
+
+        let dt = self.config.frame_time().as_secs_f32();
+        bodies.retain(|id, _| self.world.world_object(id).is_some());
+        for (id, body) in bodies.iter_mut() {
+            body.velocity = step_velocity(settings, *body, dt);
+            if body.velocity == Vec3::ZERO {
+                continue;
+            }
+            let Some(object) = self.world.world_object(id) else {
+                continue;
+            };
+            let mut transform = object.transform;
+            transform.x += body.velocity.x * dt;
+            transform.z += body.velocity.z * dt;
+            self.world.move_world_object(id, transform)?;
+        }
+        Ok(())
+    }
+
+    /// [`integrate_bodies`](Self::integrate_bodies) on fixed-point bodies and positions.
+    fn integrate_fixed_bodies(
+        &mut self,
+        settings: KinematicSettings,
+        parsed_moves: Vec<(String, f32, f32)>,
+        bodies: &mut BTreeMap<String, KinematicBody<FixedVec3>>,
+    ) -> Result<()> {
+        for (entity_id, x, y) in parsed_moves {
+            self.spawn_moved_player(&entity_id)?;
+            bodies.entry(entity_id).or_default().intent =
+                FixedVec3::from_f32(x, 0.0, y).clamp_length(Fixed::ONE);
+        }
+
+        let dt = Fixed::from_ratio(self.config.frame_time().as_nanos() as i64, 1_000_000_000);
+        bodies.retain(|id, _| self.world.world_object(id).is_some());
+        for (id, body) in bodies.iter_mut() {
+            body.velocity = step_velocity_fixed(settings, *body, dt);
+            if body.velocity == FixedVec3::ZERO {
+                continue;
+            }
+            let Some(object) = self.world.world_object(id) else {
+                continue;
+            };
+            let position = self.fixed_translation(id, &object.transform) + body.velocity * dt;
+            self.place_fixed(id, object.transform, position)?;
+        }
+        Ok(())
+    }
+
+    fn spawn_moved_player(&mut self, id: &str) -> Result<()> {
+        if self.world.world_object(id).is_none() {
+            self.world.spawn_world_object_with_id(
+                id,
+                "player",
+                WorldTransform::new(0.0, 0.0, 0.0),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn step_velocity(settings: KinematicSettings, body: KinematicBody, dt: f32) -> Vec3 {
+    let velocity = if body.intent == Vec3::ZERO {
+        let speed = length(body.velocity);
+        let slowed = speed - settings.friction * dt;
+        if slowed <= 0.0 {
+            Vec3::ZERO
+        } else {
+            body.velocity * (slowed / speed)
+        }
+    } else {
+        body.velocity + body.intent * (settings.acceleration * dt)
+    };
+    clamp_length(velocity, settings.max_speed)
+}
+
+/// [`step_velocity`] in fixed point; intent and velocity have no `y` component, so the 3D length
+/// equals the ground-plane length.
+fn step_velocity_fixed(
+    settings: KinematicSettings,
+    body: KinematicBody<FixedVec3>,
+    dt: Fixed,
+) -> FixedVec3 {
+    let velocity = if body.intent == FixedVec3::ZERO {
+        let speed = body.velocity.length();
+        let slowed = speed - Fixed::from_f32(settings.friction) * dt;
+        if slowed <= Fixed::ZERO {
+            FixedVec3::ZERO
+        } else {
+            body.velocity * (slowed / speed)
+        }
+    } else {
+        body.velocity + body.intent * (Fixed::from_f32(settings.acceleration) * dt)
+    };
+    velocity.clamp_length(Fixed::from_f32(settings.max_speed))
+}
+
+fn length(vector: Vec3) -> f32 {
+    (vector.x * vector.x + vector.z * vector.z).sqrt()
+}
+
+fn clamp_length(vector: Vec3, max: f32) -> Vec3 {
+    let length = length(vector);
+    if length > max {
+        vector * (max / length)
+    } else {
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn friction_stops_a_body_without_reversing_it() {
+        let settings = KinematicSettings {
+            acceleration: 0.0,
+            max_speed: 10.0,
+            friction: 10.0,
+        };
+        let body = KinematicBody {
+            intent: Vec3::ZERO,
+            velocity: Vec3::new(3.0, 0.0, 4.0),
+        };
+
+        let slowed = step_velocity(settings, body, 0.1);
+        assert!((length(slowed) - 4.0).abs() < 1e-6);
+        assert!(slowed.x > 0.0 && slowed.z > 0.0);
+
+        let stopped = step_velocity(settings, body, 1.0);
+        assert_eq!(stopped, Vec3::ZERO);
+
+        let body = KinematicBody::<FixedVec3>::from(body);
+        let slowed = step_velocity_fixed(settings, body, Fixed::from_ratio(1, 10));
+        assert!((slowed.length() - Fixed::from_int(4)).abs() < Fixed::from_ratio(1, 1_000_000));
+        assert!(slowed.x > Fixed::ZERO && slowed.z > Fixed::ZERO);
+        assert_eq!(
+            step_velocity_fixed(settings, body, Fixed::ONE),
+            FixedVec3::ZERO
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! Opt-in fixed-point simulation math.
+//!
+//! Under [`NumericMode::FixedPoint`] the state owned by the runtime's own simulation steps (the
+//! `/input/move` slice, kinematic integration and collision response) is kept in
+//! [`Fixed`](kitu_core::Fixed) arithmetic: kinematic bodies hold [`FixedVec3`] intents and
+//! velocities, and every position those steps write is stored in a serializable resource under
+//! [`FIXED_POSITIONS_KEY`]. World transforms are derived from these positions by rounding to `f32`,
+//! and render outputs carry the rounded values; the simulation never reads them back unless
+//! something else moved the object. Snapshots and rollback therefore restore the exact
+//! fixed-point state, and [`Runtime::state_hash`] switches to
+//! [`WorldSnapshot::fixed_state_hash`](kitu_ecs::WorldSnapshot::fixed_state_hash). ECS systems
+//! registered by the game keep their own arithmetic.
+
+use std::collections::BTreeMap;
+
+use kitu_core::{FixedVec3, Result};
+use kitu_ecs::{Entity, Vec3, WorldTransform};
+use kitu_transport::Transport;
+use serde::{Deserialize, Serialize};
+
+use crate::Runtime;
+
+/// Snapshot resource key holding the fixed-point positions written under
+/// [`NumericMode::FixedPoint`].
+pub const FIXED_POSITIONS_KEY: &str = "kitu.fixed_positions";
+
+/// Arithmetic used by the runtime's built-in simulation steps.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NumericMode {
+    /// IEEE-754 single precision, the default.
+    #[default]
+    Float,
+    /// Q32.32 fixed point through [`Fixed`](kitu_core::Fixed).
+    FixedPoint,
+}
+
+/// Authoritative positions by world object id.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+struct FixedPositions(BTreeMap<String, FixedVec3>);
+
+impl<T: Transport> Runtime<T> {
+    /// Selects the arithmetic of movement, collisions and state hashing.
+    ///
+    /// Switching to fixed point registers the [`FIXED_POSITIONS_KEY`] resource and converts
+    /// kinematic bodies to [`FixedVec3`]; switching back rounds them to `f32` and drops the
+    /// fixed-point positions, so snapshots only restore into a runtime in the mode they were
+    /// taken in. Switching modes clears the rollback history, since resimulated ticks would
+    /// otherwise use different math than the ticks they replace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_osc_ir::{OscArg, OscBundle, OscMessage};
+    /// use kitu_runtime::{build_runtime, Fixed, NumericMode};
+    /// use kitu_transport::LocalChannel;
+    ///
+    /// let mut runtime = build_runtime(LocalChannel::connected());
+    /// runtime.set_numeric_mode(NumericMode::FixedPoint).unwrap();
+    ///
+    /// let mut message = OscMessage::new("/input/move");
+    /// message.push_arg(OscArg::Str("player:local".to_string()));
+    /// message.push_arg(OscArg::Float(0.1));
+    /// message.push_arg(OscArg::Float(0.0));
+    /// let mut input = OscBundle::new();
+    /// input.push(message.clone());
+    /// input.push(message);
+    /// runtime.enqueue_input(input);
+    /// runtime.tick_once().unwrap();
+    ///
+    /// // The first move spawns the player; the second is added in fixed point.
+    /// let x = runtime.fixed_position("player:local").unwrap().x;
+    /// assert_eq!(x, Fixed::from_f32(0.1) + Fixed::from_f32(0.1));
+    /// let rendered = runtime.inspect_world_state().unwrap().objects[0].transform.x;
+    /// assert_eq!(rendered, x.to_f32());
+    /// assert_eq!(runtime.state_hash().unwrap(), runtime.inspect_world_state().unwrap().fixed_state_hash());
+    /// ```
+    pub fn set_numeric_mode(&mut self, mode: NumericMode) -> Result<()> {
+        if self.numeric == mode {
+            return Ok(());
+        }
+        match mode {
+            NumericMode::FixedPoint => {
+                self.world
+                    .insert_serializable_resource(FIXED_POSITIONS_KEY, FixedPositions::default())?;
+            }
+            NumericMode::Float => {
+                self.world.remove_resource::<FixedPositions>();
+            }
+        }
+        self.convert_kinematic_bodies(mode)?;
+        self.numeric = mode;
+        self.rollback.clear();
+        Ok(())
+    }
+
+    /// Returns the arithmetic of the built-in simulation steps.
+    pub fn numeric_mode(&self) -> NumericMode {
+        self.numeric
+    }
+
+    /// Returns the authoritative position of world object `id` under
+    /// [`NumericMode::FixedPoint`], and `None` in float mode or for unknown objects.
+    pub fn fixed_position(&self, id: &str) -> Option<FixedVec3> {
+        if !self.fixed_point() {
+            return None;
+        }
+        let object = self.world.world_object(id)?;
+        Some(self.fixed_translation(id, &object.transform))
+    }
+
+    pub(crate) fn fixed_point(&self) -> bool {
+        self.numeric == NumericMode::FixedPoint
+    }
+
+    /// Returns the stored position of `id`, unless a system or host call has moved the object's
+    /// transform away from it since; then the transform's translation wins.
+    pub(crate) fn fixed_translation(&self, id: &str, transform: &WorldTransform) -> FixedVec3 {
+        let translation = transform.translation();
+        self.world
+            .get_resource::<FixedPositions>()
+            .and_then(|positions| positions.0.get(id).copied())
+            .filter(|position| Vec3::from(*position) == translation)
+            .unwrap_or_else(|| translation.into())
+    }
+
+    /// Stores `position` for `id` and moves the object's transform to its `f32` rounding.
+    pub(crate) fn place_fixed(
+        &mut self,
+        id: &str,
+        transform: WorldTransform,
+        position: FixedVec3,
+    ) -> Result<()> {
+        self.fixed_positions_mut()?
+            .0
+            .insert(id.to_string(), position);
+        self.world
+            .move_world_object(id, transform.with_translation(position.into()))?;
+        Ok(())
+    }
+
+    /// Returns the authoritative position of every stored object, by entity, for the fixed-point
+    /// collision steps.
+    pub(crate) fn fixed_entity_positions(&self) -> BTreeMap<Entity, FixedVec3> {
+        let Some(positions) = self.world.get_resource::<FixedPositions>() else {
+            return BTreeMap::new();
+        };
+        positions
+            .0
+            .keys()
+            .filter_map(|id| {
+                let object = self.world.world_object(id)?;
+                Some((object.entity, self.fixed_translation(id, &object.transform)))
+            })
+            .collect()
+    }
+
+    /// Stores positions produced by the fixed-point collision response.
+    pub(crate) fn store_fixed_entity_positions(
+        &mut self,
+        positions: BTreeMap<Entity, FixedVec3>,
+    ) -> Result<()> {
+        let positions: Vec<(String, FixedVec3)> = positions
+            .into_iter()
+            .filter_map(|(entity, position)| {
+                let object = self.world.world_object_for_entity(entity)?;
+                Some((object.id, position))
+            })
+            .collect();
+        self.fixed_positions_mut()?.0.extend(positions);
+        Ok(())
+    }
+
+    /// Drops the fixed-point positions of objects that were removed from the world.
+    pub(crate) fn forget_fixed_positions(&mut self, ids: &[String]) {
+        if let Some(positions) = self.world.get_resource_mut::<FixedPositions>() {
+            for id in ids {
+                positions.0.remove(id);
+            }
+        }
+    }
+
+    fn fixed_positions_mut(&mut self) -> Result<&mut FixedPositions> {
+        if !self.world.contains_resource::<FixedPositions>() {
+            self.world
+                .insert_serializable_resource(FIXED_POSITIONS_KEY, FixedPositions::default())?;
+        }
+        Ok(self
+            .world
+            .get_resource_mut::<FixedPositions>()
+            .expect("fixed positions were just registered"))
+    }
+}
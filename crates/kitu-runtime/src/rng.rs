@@ -0,0 +1,60 @@
+//! Seeded gameplay randomness kept in the authoritative state.
+//!
+//! [`Runtime::seed_rng`] stores an [`RngStreams`] as a serializable resource, so the position of
+//! every named stream is part of snapshots, state hashes and rollback. Systems draw from it with
+//! `world.get_resource_mut::<RngStreams>()`; a resimulated tick therefore sees the same numbers as
+//! the original, and a replay with the same seed and inputs reproduces every roll.
+
+use kitu_core::{DeterministicRng, Result, RngStreams};
+use kitu_transport::Transport;
+
+use crate::Runtime;
+
+/// Snapshot resource key holding the runtime's [`RngStreams`].
+pub const RNG_STREAMS_KEY: &str = "kitu.rng";
+
+impl<T: Transport> Runtime<T> {
+    /// Seeds the runtime's random streams, discarding their previous positions.
+    ///
+    /// Runtimes that restore a snapshot with random streams must be seeded first, with any seed,
+    /// so the resource is registered; [`restore_world_state`](Self::restore_world_state) rejects
+    /// such a snapshot otherwise. Reseeding clears the rollback history.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_runtime::build_runtime;
+    /// use kitu_transport::LocalChannel;
+    ///
+    /// let mut runtime = build_runtime(LocalChannel::connected());
+    /// runtime.seed_rng(42).unwrap();
+    /// let spawn_lane = runtime.rng_stream("spawns").unwrap().gen_range(0..3);
+    ///
+    /// let mut replay = build_runtime(LocalChannel::connected());
+    /// replay.seed_rng(42).unwrap();
+    /// assert_eq!(replay.rng_stream("spawns").unwrap().gen_range(0..3), spawn_lane);
+    /// ```
+    pub fn seed_rng(&mut self, seed: u64) -> Result<()> {
+        self.world
+            .insert_serializable_resource(RNG_STREAMS_KEY, RngStreams::new(seed))?;
+        self.rollback.clear();
+        Ok(())
+    }
+
+    /// Returns the seed of the random streams, or `None` if the runtime was never seeded.
+    pub fn rng_seed(&self) -> Option<u64> {
+        self.world
+            .get_resource::<RngStreams>()
+            .map(RngStreams::seed)
+    }
+
+    /// Returns the random stream `name` for host-side draws between ticks.
+    ///
+    /// Draws made here change the authoritative state like any other world edit, so returning a
+    /// stream clears the rollback history.
+    pub fn rng_stream(&mut self, name: &str) -> Option<&mut DeterministicRng> {
+        let streams = self.world.get_resource_mut::<RngStreams>()?;
+        self.rollback.clear();
+        Some(streams.stream(name))
+    }
+}
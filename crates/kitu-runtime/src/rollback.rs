@@ -0,0 +1,257 @@
+//! Rollback and resimulation of recent ticks.
+//!
+//! While a rollback window is configured, every tick records the world snapshot it started from
+//! together with the input batch it committed. A late input stamped for one of those ticks is
+//! merged into that tick's batch and the affected ticks are simulated again; objects whose
+//! state differs from what was already rendered are reported as corrections.
+
+use std::collections::VecDeque;
+
+use kitu_core::{KituError, Result, Tick};
+use kitu_ecs::Contact;
+use kitu_osc_ir::OscBundle;
+use kitu_transport::Transport;
+
+use crate::{render_despawn_message, render_transform_message, Runtime, WorldSnapshot};
+
+/// Input and starting state of one recorded tick.
+#[derive(Debug, Clone)]
+pub(crate) struct RollbackFrame {
+    pub(crate) tick: Tick,
+    pub(crate) snapshot: WorldSnapshot,
+    pub(crate) inputs: Vec<OscBundle>,
+    /// Contacts of the previous tick, which restoring the snapshot drops from the event buffers.
+    pub(crate) contacts: Vec<Contact>,
+}
+
+/// Ring buffer of the most recent [`RollbackFrame`]s, oldest first.
+#[derive(Debug, Default)]
+pub(crate) struct RollbackHistory {
+    window: usize,
+    frames: VecDeque<RollbackFrame>,
+}
+
+impl RollbackHistory {
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.window != 0
+    }
+
+    fn set_window(&mut self, window: usize) {
+        self.window = window;
+        self.trim();
+    }
+
+    pub(crate) fn record(&mut self, frame: RollbackFrame) {
+        // A tick that failed after recording is retried with the same start state.
+        if self
+            .frames
+            .back()
+            .is_some_and(|last| last.tick == frame.tick)
+        {
+            self.frames.pop_back();
+        }
+        self.frames.push_back(frame);
+        self.trim();
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.frames.clear();
+    }
+
+    fn trim(&mut self) {
+        while self.frames.len() > self.window {
+            self.frames.pop_front();
+        }
+    }
+
+    fn position(&self, tick: Tick) -> Option<usize> {
+        self.frames.iter().position(|frame| frame.tick == tick)
+    }
+}
+
+impl<T: Transport> Runtime<T> {
+    /// Keeps the last `ticks` ticks available for [`enqueue_input_at`](Self::enqueue_input_at);
+    /// `0` disables rollback and drops the recorded history.
+    ///
+    /// Each recorded tick holds a world snapshot, so memory grows with the window and world size.
+    /// Rollback restores exactly what [`WorldSnapshot`] captures: objects, hierarchy, registered
+    /// serializable resources and components, and the entity allocator. Other components would be
+    /// dropped by the restore, so [`enqueue_input_at`](Self::enqueue_input_at) refuses to roll
+    /// back while any entity holds one; register them with
+    /// [`EcsWorld::register_serializable_component`](kitu_ecs::EcsWorld::register_serializable_component).
+    /// State kept inside systems or in non-serializable resources is not rewound.
+    pub fn set_rollback_window(&mut self, ticks: usize) {
+        self.rollback.set_window(ticks);
+    }
+
+    /// Returns the ticks that can currently be rolled back to, oldest first.
+    pub fn rollback_ticks(&self) -> Vec<Tick> {
+        self.rollback
+            .frames
+            .iter()
+            .map(|frame| frame.tick)
+            .collect()
+    }
+
+    /// Enqueues an input stamped for `tick`, resimulating from that tick if it already ran.
+    ///
+    /// An input for the current tick is queued like [`enqueue_input`](Self::enqueue_input), and
+    /// an input for a future tick is held until [`tick_once`](Self::tick_once) reaches that tick;
+    /// both return `0`. A late input is appended to the recorded batch of its tick, the world is
+    /// restored to that tick's starting state and every tick up to the present is simulated again
+    /// without polling transport or emitting render transforms. Afterwards a
+    /// `/render/<kind>/correction` output, laid out like `/render/<kind>/transform`, is staged for
+    /// each object whose transform differs from the state before the rollback, and a
+    /// `/render/<kind>/despawn [id, tick]` for each object that no longer exists. State hashes
+    /// recorded for resimulated ticks and not yet
+    /// [drained](Self::drain_state_hashes) are replaced by the hashes of the new states. Returns
+    /// the number of resimulated ticks.
+    ///
+    /// Fails without changing the runtime if `tick` is outside the rollback window, or if the
+    /// world holds components that a restore would drop (see
+    /// [`EcsWorld::has_unsnapshotted_components`](kitu_ecs::EcsWorld::has_unsnapshotted_components)).
+    /// Malformed messages in `input` are reported like any other
+    /// [input rejection](Self::set_input_rejection_policy), at their position after the messages
+    /// already recorded for `tick`; under
+    /// [`InputRejectionPolicy::RejectBatch`](crate::InputRejectionPolicy::RejectBatch) the call
+    /// then fails before anything is resimulated. Direct world edits between ticks (spawn, move,
+    /// despawn, reset, restore) clear the history, since replaying recorded inputs would discard
+    /// them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kitu_osc_ir::{OscArg, OscBundle, OscMessage};
+    /// use kitu_runtime::build_runtime;
+    /// use kitu_transport::LocalChannel;
+    ///
+    /// let mut runtime = build_runtime(LocalChannel::connected());
+    /// runtime.set_rollback_window(8);
+    /// runtime.run_for_ticks(3).unwrap();
+    /// let late_tick = runtime.rollback_ticks()[1];
+    ///
+    /// let mut message = OscMessage::new("/input/move");
+    /// message.push_arg(OscArg::Str("player:late".to_string()));
+    /// message.push_arg(OscArg::Float(1.0));
+    /// message.push_arg(OscArg::Float(0.0));
+    /// let mut input = OscBundle::new();
+    /// input.push(message);
+    ///
+    /// assert_eq!(runtime.enqueue_input_at(late_tick, input).unwrap(), 2);
+    /// assert_eq!(runtime.inspect_world_state().unwrap().objects[0].id, "player:late");
+    /// ```
+    pub fn enqueue_input_at(&mut self, tick: Tick, input: OscBundle) -> Result<u64> {
+        if tick == self.tick {
+            self.enqueue_input(input);
+            return Ok(0);
+        }
+        if tick > self.tick {
+            self.inputs.schedule(tick, input);
+            return Ok(0);
+        }
+        let start = self.rollback.position(tick).ok_or(KituError::InvalidInput(
+            "input tick is outside the rollback window",
+        ))?;
+        if self.world.has_unsnapshotted_components() {
+            return Err(KituError::InvalidInput(
+                "world holds components that snapshots do not capture",
+            ));
+        }
+        let recorded = self.rollback.frames[start]
+            .inputs
+            .iter()
+            .map(|bundle| bundle.messages.len())
+            .sum();
+        self.parse_inputs(std::slice::from_ref(&input), tick, recorded)?;
+
+        let present = self.tick;
+        let before = self.inspect_world_state()?;
+        let original: Vec<RollbackFrame> = self.rollback.frames.range(start..).cloned().collect();
+        let mut frames = original.clone();
+        frames[0].inputs.push(input);
+
+        match self.resimulate(&mut frames) {
+            Ok(()) => {
+                self.tick = present;
+                self.world.set_change_tick(present);
+                let resimulated = frames.len() as u64;
+                let last_tick = frames[frames.len() - 1].tick;
+                self.rollback.frames.truncate(start);
+                self.rollback.frames.extend(frames);
+                self.rehash_resimulated_ticks(tick)?;
+                self.stage_corrections(&before, last_tick)?;
+                Ok(resimulated)
+            }
+            Err(error) => {
+                self.world.restore_world_snapshot(&before)?;
+                self.resend_contacts(&self.last_contacts.clone());
+                self.tick = present;
+                self.world.set_change_tick(present);
+                self.rollback.frames.truncate(start);
+                self.rollback.frames.extend(original);
+                Err(error)
+            }
+        }
+    }
+
+    fn resimulate(&mut self, frames: &mut [RollbackFrame]) -> Result<()> {
+        self.world.restore_world_snapshot(&frames[0].snapshot)?;
+        let mut contacts = frames[0].contacts.clone();
+        self.resend_contacts(&contacts);
+        for frame in frames {
+            self.tick = frame.tick;
+            self.world.set_change_tick(frame.tick);
+            frame.snapshot = self.world.world_snapshot()?;
+            frame.contacts = contacts;
+            // Rejections in recorded batches were reported when the tick first ran.
+            let parsed = self.input_handlers.parse_batch(&frame.inputs);
+            // Like transforms, collision outputs are not staged again for resimulated ticks.
+            contacts = self.simulate_tick(parsed)?;
+        }
+        self.last_contacts = contacts;
+        Ok(())
+    }
+
+    /// Sends the previous tick's contacts again after a restore dropped them from the events.
+    fn resend_contacts(&mut self, contacts: &[Contact]) {
+        for contact in contacts {
+            self.world.send_event(*contact);
+        }
+    }
+
+    /// Recomputes recorded hashes of the states that followed `from`, which resimulation replaced.
+    fn rehash_resimulated_ticks(&mut self, from: Tick) -> Result<()> {
+        let present = self.state_hash()?;
+        let mut hashes = std::mem::take(&mut self.state_hashes);
+        for (tick, hash) in hashes.iter_mut().filter(|(tick, _)| *tick > from) {
+            if *tick == self.tick {
+                *hash = present;
+            } else if let Some(frame) = self.rollback.frames.iter().find(|f| f.tick == *tick) {
+                *hash = self.hash_snapshot(&frame.snapshot);
+            }
+        }
+        self.state_hashes = hashes;
+        Ok(())
+    }
+
+    fn stage_corrections(&mut self, before: &WorldSnapshot, tick: Tick) -> Result<()> {
+        let after = self.world.world_snapshot()?;
+        for object in &after.objects {
+            let unchanged = before
+                .objects
+                .iter()
+                .any(|old| old.id == object.id && old.transform == object.transform);
+            if !unchanged {
+                let output = render_transform_message("correction", tick, object)?;
+                self.queue_output(output);
+            }
+        }
+        for object in &before.objects {
+            if after.objects.iter().all(|new| new.id != object.id) {
+                let output = render_despawn_message(tick, object)?;
+                self.queue_output(output);
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,8 +1,9 @@
 use std::collections::HashMap;
 
 use kitu_app_actions::ActionValue;
+use kitu_core::KituError;
 use kitu_osc_ir::OscArg;
-use kitu_runtime::{build_runtime, WorldTransform};
+use kitu_runtime::{build_runtime, WorldTransform, MAX_STEP_TICKS};
 use kitu_transport::LocalChannel;
 
 #[test]
@@ -21,9 +22,10 @@ fn app_action_world_flow_updates_runtime_state_and_outputs() {
         )
         .expect("spawn app action should succeed");
 
-    let spawned = runtime.inspect_world_state().objects;
+    let spawned = runtime.inspect_world_state().unwrap().objects;
     assert_eq!(spawned.len(), 1);
-    assert_eq!(spawned[0].id, "obj-1");
+    let id = spawned[0].id.clone();
+    assert_eq!(id, spawned[0].entity.to_string());
     assert_eq!(spawned[0].kind, "enemy");
     assert_eq!(spawned[0].transform, WorldTransform::new(1.0, 2.0, 3.0));
 
@@ -34,12 +36,12 @@ fn app_action_world_flow_updates_runtime_state_and_outputs() {
     assert_eq!(spawn_outputs.len(), 1);
     assert_eq!(
         spawn_outputs[0].messages[0].address,
-        "/render/player/transform"
+        "/render/enemy/transform"
     );
     assert_eq!(
         spawn_outputs[0].messages[0].args,
         vec![
-            OscArg::Str("obj-1".to_string()),
+            OscArg::Str(id.clone()),
             OscArg::Int64(0),
             OscArg::Float(1.0),
             OscArg::Float(3.0),
@@ -51,7 +53,7 @@ fn app_action_world_flow_updates_runtime_state_and_outputs() {
         .run_app_action(
             "move-object",
             &HashMap::from([
-                ("id".to_string(), ActionValue::String("obj-1".to_string())),
+                ("id".to_string(), ActionValue::String(id.clone())),
                 ("x".to_string(), ActionValue::Float(2.0)),
                 ("y".to_string(), ActionValue::Float(5.0)),
                 ("z".to_string(), ActionValue::Float(4.0)),
@@ -59,7 +61,7 @@ fn app_action_world_flow_updates_runtime_state_and_outputs() {
         )
         .expect("move app action should succeed");
 
-    let moved = runtime.inspect_world_state().objects;
+    let moved = runtime.inspect_world_state().unwrap().objects;
     assert_eq!(moved.len(), 1);
     assert_eq!(moved[0].transform, WorldTransform::new(2.0, 5.0, 4.0));
 
@@ -71,11 +73,102 @@ fn app_action_world_flow_updates_runtime_state_and_outputs() {
     assert_eq!(
         move_outputs[0].messages[0].args,
         vec![
-            OscArg::Str("obj-1".to_string()),
+            OscArg::Str(id.clone()),
             OscArg::Int64(1),
             OscArg::Float(2.0),
             OscArg::Float(4.0),
             OscArg::Float(0.0),
         ]
     );
+
+    runtime
+        .run_app_action(
+            "despawn-object",
+            &HashMap::from([("id".to_string(), ActionValue::String(id.clone()))]),
+        )
+        .expect("despawn app action should succeed");
+    assert!(runtime.inspect_world_state().unwrap().objects.is_empty());
+    assert!(runtime
+        .run_app_action(
+            "despawn-object",
+            &HashMap::from([("id".to_string(), ActionValue::String(id.clone()))]),
+        )
+        .is_err());
+
+    runtime
+        .tick_once()
+        .expect("despawn output tick should succeed");
+    let despawn_outputs = runtime.drain_output_buffer();
+    assert_eq!(despawn_outputs.len(), 1);
+    assert_eq!(
+        despawn_outputs[0].messages[0].address,
+        "/render/enemy/despawn"
+    );
+    assert_eq!(
+        despawn_outputs[0].messages[0].args,
+        vec![OscArg::Str(id), OscArg::Int64(2)]
+    );
+}
+
+#[test]
+fn simulation_control_actions_pause_step_and_scale_time() {
+    let mut runtime = build_runtime(LocalChannel::default());
+    let frame = 1.0 / 60.0;
+
+    runtime
+        .run_app_action("pause-simulation", &HashMap::new())
+        .expect("pause app action should succeed");
+    assert!(runtime.is_paused());
+    assert_eq!(runtime.update(1.0).unwrap().ticks, 0);
+
+    runtime
+        .run_app_action("spawn-object", &HashMap::new())
+        .expect("spawn app action should work while paused");
+    runtime
+        .run_app_action(
+            "step-simulation",
+            &HashMap::from([("ticks".to_string(), ActionValue::Int(2))]),
+        )
+        .expect("step app action should succeed");
+    assert_eq!(runtime.current_tick().get(), 2);
+    assert_eq!(runtime.drain_output_buffer().len(), 1);
+    assert!(runtime
+        .run_app_action(
+            "step-simulation",
+            &HashMap::from([("ticks".to_string(), ActionValue::Int(-1))]),
+        )
+        .is_err());
+    let too_many = i32::try_from(MAX_STEP_TICKS + 1).unwrap();
+    assert!(runtime
+        .run_app_action(
+            "step-simulation",
+            &HashMap::from([("ticks".to_string(), ActionValue::Int(too_many))]),
+        )
+        .is_err());
+    assert!(matches!(
+        runtime.step(MAX_STEP_TICKS + 1),
+        Err(KituError::InvalidInput(_))
+    ));
+    assert_eq!(runtime.current_tick().get(), 2);
+
+    runtime
+        .run_app_action(
+            "set-time-scale",
+            &HashMap::from([("scale".to_string(), ActionValue::Float(0.5))]),
+        )
+        .expect("time scale app action should succeed");
+    runtime
+        .run_app_action("resume-simulation", &HashMap::new())
+        .expect("resume app action should succeed");
+    assert_eq!(runtime.update(frame).unwrap().ticks, 0);
+    assert_eq!(runtime.update(frame).unwrap().ticks, 1);
+    assert_eq!(runtime.current_tick().get(), 3);
+
+    assert!(runtime
+        .run_app_action(
+            "set-time-scale",
+            &HashMap::from([("scale".to_string(), ActionValue::Float(-1.0))]),
+        )
+        .is_err());
+    assert_eq!(runtime.time_scale(), 0.5);
 }
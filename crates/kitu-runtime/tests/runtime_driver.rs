@@ -0,0 +1,132 @@
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use kitu_core::KituError;
+use kitu_osc_ir::{OscArg, OscBundle, OscMessage};
+use kitu_runtime::{
+    DriverCommand, DriverEvent, InterestFilter, Runtime, RuntimeConfig, RuntimeDriver,
+    MAX_STEP_TICKS,
+};
+use kitu_transport::LocalChannel;
+
+const WAIT: Duration = Duration::from_secs(5);
+
+fn fast_runtime() -> Runtime<LocalChannel> {
+    let config = RuntimeConfig {
+        tick_rate_hz: 200,
+        ..RuntimeConfig::default_60hz()
+    };
+    Runtime::new(config, LocalChannel::default())
+}
+
+fn move_input(id: &str, x: f32) -> OscBundle {
+    let mut message = OscMessage::new("/input/move");
+    message.push_arg(OscArg::Str(id.to_string()));
+    message.push_arg(OscArg::Float(x));
+    message.push_arg(OscArg::Float(0.0));
+    let mut bundle = OscBundle::new();
+    bundle.push(message);
+    bundle
+}
+
+fn next_outputs(events: &std::sync::mpsc::Receiver<DriverEvent>) -> Vec<OscBundle> {
+    match events.recv_timeout(WAIT).expect("driver event") {
+        DriverEvent::Outputs { bundles, .. } => bundles,
+        DriverEvent::Error { message, .. } => panic!("unexpected driver error: {message}"),
+    }
+}
+
+#[test]
+fn driver_ticks_in_real_time_without_a_host_loop() {
+    let driver = RuntimeDriver::spawn(fast_runtime());
+    let started = Instant::now();
+    thread::sleep(Duration::from_millis(100));
+    let ticks = driver
+        .with_runtime(|runtime| runtime.current_tick().get())
+        .unwrap();
+    let elapsed = started.elapsed().as_secs_f64();
+
+    // 200 Hz over the measured time, with generous slack for loaded CI machines.
+    assert!(ticks >= 5, "only {ticks} ticks in {elapsed:.3}s");
+    assert!(
+        ticks as f64 <= elapsed * 200.0 + 2.0,
+        "{ticks} ticks in {elapsed:.3}s"
+    );
+
+    let runtime = driver.shutdown().unwrap();
+    assert!(runtime.current_tick().get() >= ticks);
+}
+
+#[test]
+fn subscribers_receive_their_outputs_and_errors() {
+    let driver = RuntimeDriver::spawn(fast_runtime());
+    let everything = driver.subscribe(InterestFilter::default()).unwrap();
+    let npcs = driver
+        .subscribe(InterestFilter::default().with_kind("npc"))
+        .unwrap();
+    assert!(driver
+        .subscribe(InterestFilter::default().within_radius("player:local", -1.0))
+        .is_err());
+
+    let commands = driver.commands();
+    thread::spawn(move || {
+        commands
+            .send(DriverCommand::Input(move_input("player:local", 1.5)))
+            .unwrap();
+    })
+    .join()
+    .unwrap();
+
+    let bundles = next_outputs(&everything);
+    assert_eq!(bundles.len(), 1);
+    assert_eq!(bundles[0].messages[0].address, "/render/player/transform");
+    assert_eq!(
+        bundles[0].messages[0].args[0],
+        OscArg::Str("player:local".to_string())
+    );
+
+    driver.set_time_scale(0.0).unwrap();
+    assert!(matches!(
+        everything.recv_timeout(WAIT).unwrap(),
+        DriverEvent::Error { .. }
+    ));
+    assert!(matches!(
+        npcs.recv_timeout(WAIT).unwrap(),
+        DriverEvent::Error { .. }
+    ));
+    assert!(npcs.try_recv().is_err());
+}
+
+#[test]
+fn paused_driver_only_advances_by_steps() {
+    let driver = RuntimeDriver::spawn(fast_runtime());
+    driver.pause().unwrap();
+    let paused_at = driver
+        .with_runtime(|runtime| runtime.current_tick().get())
+        .unwrap();
+    thread::sleep(Duration::from_millis(30));
+    assert_eq!(
+        driver
+            .with_runtime(|runtime| runtime.current_tick().get())
+            .unwrap(),
+        paused_at
+    );
+
+    driver.step(3).unwrap();
+    assert!(matches!(
+        driver.step(MAX_STEP_TICKS + 1),
+        Err(KituError::InvalidInput(_))
+    ));
+    assert_eq!(
+        driver
+            .with_runtime(|runtime| runtime.current_tick().get())
+            .unwrap(),
+        paused_at + 3
+    );
+
+    driver.resume().unwrap();
+    let runtime = driver.shutdown().unwrap();
+    assert!(!runtime.is_paused());
+}
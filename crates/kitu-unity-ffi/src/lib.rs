@@ -18,14 +18,20 @@ use std::{
 
 use kitu_core::Result;
 use kitu_osc_ir::{OscArg, OscBundle, OscMessage};
-use kitu_runtime::{build_runtime, Runtime};
+use kitu_runtime::{
+    build_runtime, InterestFilter, NumericMode, Runtime, UpdateReport, MAX_STEP_TICKS,
+};
 use kitu_transport::LocalChannel;
 
 const MAX_ENTITY_ID_BYTES: usize = 64;
+const MAX_KIND_BYTES: usize = 64;
+/// Client id the handle registers its interest filter under.
+const UNITY_CLIENT: &str = "unity";
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct RenderTransformEvent {
     pub entity_id: String,
+    pub kind: String,
     pub tick: u64,
     pub x: f32,
     pub y: f32,
@@ -41,28 +47,38 @@ pub struct KituRenderTransformEvent {
     pub x: f32,
     pub y: f32,
     pub z: f32,
+    pub kind_len: u32,
+    pub kind: [u8; MAX_KIND_BYTES],
 }
 
 impl KituRenderTransformEvent {
     fn from_event(event: &RenderTransformEvent) -> Option<Self> {
-        let bytes = event.entity_id.as_bytes();
-        if bytes.len() > MAX_ENTITY_ID_BYTES {
-            return None;
-        }
-
-        let mut entity_id = [0_u8; MAX_ENTITY_ID_BYTES];
-        entity_id[..bytes.len()].copy_from_slice(bytes);
+        let (entity_id_len, entity_id) = encode_fixed::<MAX_ENTITY_ID_BYTES>(&event.entity_id)?;
+        let (kind_len, kind) = encode_fixed::<MAX_KIND_BYTES>(&event.kind)?;
         Some(Self {
-            entity_id_len: u32::try_from(bytes.len()).ok()?,
+            entity_id_len,
             entity_id,
             tick: event.tick,
             x: event.x,
             y: event.y,
             z: event.z,
+            kind_len,
+            kind,
         })
     }
 }
 
+fn encode_fixed<const N: usize>(value: &str) -> Option<(u32, [u8; N])> {
+    let bytes = value.as_bytes();
+    if bytes.len() > N {
+        return None;
+    }
+
+    let mut buffer = [0_u8; N];
+    buffer[..bytes.len()].copy_from_slice(bytes);
+    Some((u32::try_from(bytes.len()).ok()?, buffer))
+}
+
 /// Managed handle exposed to Unity.
 #[derive(Clone)]
 pub struct UnityHandle {
@@ -73,7 +89,10 @@ pub struct UnityHandle {
 impl UnityHandle {
     /// Initializes the runtime and returns a handle safe to share across FFI boundaries.
     pub fn initialize() -> Self {
-        let runtime = build_runtime(LocalChannel::connected());
+        let mut runtime = build_runtime(LocalChannel::connected());
+        runtime
+            .set_client_interest(UNITY_CLIENT, InterestFilter::default())
+            .expect("default interest filter is valid");
         Self {
             runtime: Arc::new(Mutex::new(runtime)),
             pending_render_events: Arc::new(Mutex::new(VecDeque::new())),
@@ -105,7 +124,63 @@ impl UnityHandle {
         guard.tick_once()
     }
 
-    /// Pops one `/render/player/transform` event emitted by the runtime output path.
+    /// Advances the runtime by the ticks that `dt` seconds allow, honoring pause and time scale.
+    ///
+    /// Returns the tick count and any overrun of the runtime's tick budget.
+    pub fn update(&self, dt: f32) -> Result<UpdateReport> {
+        let mut guard = self.runtime.lock().expect("runtime mutex poisoned");
+        guard.update(dt)
+    }
+
+    /// Pauses or resumes time-driven ticking in [`update`](Self::update).
+    pub fn set_paused(&self, paused: bool) {
+        let mut guard = self.runtime.lock().expect("runtime mutex poisoned");
+        if paused {
+            guard.pause();
+        } else {
+            guard.resume();
+        }
+    }
+
+    /// Runs exactly `count` ticks, also while paused.
+    pub fn step(&self, count: u64) -> Result<()> {
+        let mut guard = self.runtime.lock().expect("runtime mutex poisoned");
+        guard.step(count)
+    }
+
+    /// Sets the factor applied to `dt` by [`update`](Self::update).
+    pub fn set_time_scale(&self, scale: f32) -> Result<()> {
+        let mut guard = self.runtime.lock().expect("runtime mutex poisoned");
+        guard.set_time_scale(scale)
+    }
+
+    /// Selects float or fixed-point arithmetic for the runtime's movement, collisions and state
+    /// hashes.
+    pub fn set_numeric_mode(&self, mode: NumericMode) -> Result<()> {
+        let mut guard = self.runtime.lock().expect("runtime mutex poisoned");
+        guard.set_numeric_mode(mode)
+    }
+
+    /// Replaces the interest filter that decides which outputs reach this handle.
+    ///
+    /// Outputs already queued for [`pop_render_transform`](Self::pop_render_transform) stay
+    /// queued. Fails if the filter's radius is negative or not finite.
+    pub fn set_interest(&self, filter: InterestFilter) -> Result<()> {
+        let mut guard = self.runtime.lock().expect("runtime mutex poisoned");
+        guard.set_client_interest(UNITY_CLIENT, filter)
+    }
+
+    /// Returns the interest filter of this handle.
+    pub fn interest(&self) -> InterestFilter {
+        let guard = self.runtime.lock().expect("runtime mutex poisoned");
+        guard
+            .client_interest(UNITY_CLIENT)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Pops one `/render/<kind>/transform` or `/render/object/transform` event that passes the
+    /// handle's interest filter.
     pub fn pop_render_transform(&self) -> Option<RenderTransformEvent> {
         if let Some(event) = self
             .pending_render_events
@@ -117,7 +192,10 @@ impl UnityHandle {
         }
 
         let mut runtime = self.runtime.lock().expect("runtime mutex poisoned");
-        let outputs = runtime.drain_output_buffer();
+        let outputs = runtime
+            .drain_client_outputs()
+            .remove(UNITY_CLIENT)
+            .unwrap_or_default();
         drop(runtime);
 
         let mut pending = self
@@ -158,6 +236,101 @@ pub unsafe extern "C" fn kitu_tick(handle: *mut UnityHandle) -> i32 {
     }
 }
 
+/// C ABI entry point to advance the runtime by elapsed host time.
+///
+/// Returns the number of executed ticks, or a negative value on failure.
+///
+/// # Safety
+///
+/// - `handle` must be a valid pointer created by [`kitu_init`].
+/// - The pointed-to handle must not be freed while this function runs.
+#[no_mangle]
+pub unsafe extern "C" fn kitu_update(handle: *mut UnityHandle, dt: f32) -> i32 {
+    let Some(handle) = handle.as_ref() else {
+        return -1;
+    };
+    match handle.update(dt) {
+        Ok(report) => i32::try_from(report.ticks).unwrap_or(i32::MAX),
+        Err(_) => -2,
+    }
+}
+
+/// C ABI entry point to pause (`paused != 0`) or resume time-driven ticking.
+///
+/// # Safety
+///
+/// - `handle` must be a valid pointer created by [`kitu_init`].
+#[no_mangle]
+pub unsafe extern "C" fn kitu_set_paused(handle: *mut UnityHandle, paused: i32) -> i32 {
+    let Some(handle) = handle.as_ref() else {
+        return -1;
+    };
+    handle.set_paused(paused != 0);
+    0
+}
+
+/// C ABI entry point to switch the runtime to fixed-point (`enabled != 0`) or float arithmetic.
+///
+/// Returns `1` when the runtime cannot register its fixed-point state.
+///
+/// # Safety
+///
+/// - `handle` must be a valid pointer created by [`kitu_init`].
+#[no_mangle]
+pub unsafe extern "C" fn kitu_set_fixed_point(handle: *mut UnityHandle, enabled: i32) -> i32 {
+    let Some(handle) = handle.as_ref() else {
+        return -1;
+    };
+    let mode = if enabled != 0 {
+        NumericMode::FixedPoint
+    } else {
+        NumericMode::Float
+    };
+    match handle.set_numeric_mode(mode) {
+        Ok(()) => 0,
+        Err(_) => 1,
+    }
+}
+
+/// C ABI entry point to run exactly `count` ticks, also while paused.
+///
+/// Returns `-2` when `count` exceeds [`MAX_STEP_TICKS`].
+///
+/// # Safety
+///
+/// - `handle` must be a valid pointer created by [`kitu_init`].
+#[no_mangle]
+pub unsafe extern "C" fn kitu_step(handle: *mut UnityHandle, count: u32) -> i32 {
+    let Some(handle) = handle.as_ref() else {
+        return -1;
+    };
+    if u64::from(count) > MAX_STEP_TICKS {
+        return -2;
+    }
+    match handle.step(u64::from(count)) {
+        Ok(()) => 0,
+        Err(_) => 1,
+    }
+}
+
+/// C ABI entry point to set the time scale applied by [`kitu_update`].
+///
+/// Returns `-2` when `scale` is not finite and greater than zero.
+///
+/// # Safety
+///
+/// - `handle` must be a valid pointer created by [`kitu_init`].
+#[no_mangle]
+pub unsafe extern "C" fn kitu_set_time_scale(handle: *mut UnityHandle, scale: f32) -> i32 {
+    let Some(handle) = handle.as_ref() else {
+        return -1;
+    };
+    match handle.set_time_scale(scale) {
+        Ok(()) => 0,
+        Err(_) => -2,
+    }
+}
+
 /// C ABI entry point to submit one move input.
 ///
 /// # Safety
@@ -218,14 +391,120 @@ pub unsafe extern "C" fn kitu_pop_render_transform(
     1
 }
 
-fn parse_render_transform(bundle: OscBundle) -> Option<RenderTransformEvent> {
-    let message = bundle
-        .messages
-        .into_iter()
-        .find(|m| m.address == "/render/player/transform")?;
-    if message.args.len() != 5 {
-        return None;
+/// C ABI entry point to only receive objects within `radius` of the object `center_id`.
+///
+/// Returns `-4` when `radius` is negative or not finite.
+///
+/// # Safety
+///
+/// - `handle` must be a valid pointer created by [`kitu_init`].
+/// - `center_id` must be a valid, NUL-terminated C string.
+#[no_mangle]
+pub unsafe extern "C" fn kitu_set_interest_radius(
+    handle: *mut UnityHandle,
+    center_id: *const c_char,
+    radius: f32,
+) -> i32 {
+    update_interest(handle, center_id, |filter, center| {
+        filter.within_radius(center, radius)
+    })
+}
+
+/// C ABI entry point to accept objects of `kind`; once any kind is added, other kinds are
+/// filtered out.
+///
+/// # Safety
+///
+/// - `handle` must be a valid pointer created by [`kitu_init`].
+/// - `kind` must be a valid, NUL-terminated C string.
+#[no_mangle]
+pub unsafe extern "C" fn kitu_add_interest_kind(
+    handle: *mut UnityHandle,
+    kind: *const c_char,
+) -> i32 {
+    update_interest(handle, kind, |filter, kind| filter.with_kind(kind))
+}
+
+/// C ABI entry point to accept OSC addresses starting with `prefix`; once any prefix is added,
+/// other addresses are filtered out.
+///
+/// # Safety
+///
+/// - `handle` must be a valid pointer created by [`kitu_init`].
+/// - `prefix` must be a valid, NUL-terminated C string.
+#[no_mangle]
+pub unsafe extern "C" fn kitu_add_interest_prefix(
+    handle: *mut UnityHandle,
+    prefix: *const c_char,
+) -> i32 {
+    update_interest(handle, prefix, |filter, prefix| {
+        filter.with_address_prefix(prefix)
+    })
+}
+
+/// C ABI entry point to drop every interest constraint, receiving all outputs again.
+///
+/// # Safety
+///
+/// - `handle` must be a valid pointer created by [`kitu_init`].
+#[no_mangle]
+pub unsafe extern "C" fn kitu_clear_interest(handle: *mut UnityHandle) -> i32 {
+    let Some(handle) = handle.as_ref() else {
+        return -1;
+    };
+    match handle.set_interest(InterestFilter::default()) {
+        Ok(()) => 0,
+        Err(_) => -4,
     }
+}
+
+/// Applies `update` with the decoded string argument to the handle's interest filter.
+///
+/// # Safety
+///
+/// Same contract as the `kitu_*_interest_*` entry points calling it.
+unsafe fn update_interest(
+    handle: *mut UnityHandle,
+    value: *const c_char,
+    update: impl FnOnce(InterestFilter, &str) -> InterestFilter,
+) -> i32 {
+    let Some(handle) = handle.as_ref() else {
+        return -1;
+    };
+    if value.is_null() {
+        return -2;
+    }
+    let Ok(value) = CStr::from_ptr(value).to_str() else {
+        return -3;
+    };
+    match handle.set_interest(update(handle.interest(), value)) {
+        Ok(()) => 0,
+        Err(_) => -4,
+    }
+}
+
+fn parse_render_transform(bundle: OscBundle) -> Option<RenderTransformEvent> {
+    // The runtime names plain kinds in the address and every other kind in a trailing argument
+    // of `/render/object/transform`.
+    let (message, kind) = bundle.messages.into_iter().find_map(|message| {
+        let family = message
+            .address
+            .strip_prefix("/render/")?
+            .strip_suffix("/transform")?;
+        if family.contains('/') {
+            return None;
+        }
+        let kind = match (family, message.args.len()) {
+            ("object", 6) => match &message.args[5] {
+                OscArg::Str(kind) => kind.clone(),
+                _ => return None,
+            },
+            ("object", _) => return None,
+            (family, 5) => family.to_string(),
+            _ => return None,
+        };
+        Some((message, kind))
+    })?;
     let entity_id = match &message.args[0] {
         OscArg::Str(value) => value.clone(),
         _ => return None,
@@ -250,6 +529,7 @@ fn parse_render_transform(bundle: OscBundle) -> Option<RenderTransformEvent> {
 
     Some(RenderTransformEvent {
         entity_id,
+        kind,
         tick,
         x,
         y,
@@ -260,6 +540,7 @@ fn parse_render_transform(bundle: OscBundle) -> Option<RenderTransformEvent> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use kitu_runtime::Fixed;
     use std::ffi::CString;
 
     #[test]
@@ -295,6 +576,27 @@ mod tests {
         assert_eq!(event.z, 0.0);
     }
 
+    #[test]
+    fn render_transforms_carry_the_object_kind() {
+        let handle = UnityHandle::initialize();
+        {
+            let mut runtime = handle.runtime.lock().unwrap();
+            runtime.spawn_world_object("enemy", 1.0, 0.0, 2.0).unwrap();
+            runtime
+                .spawn_world_object("Boss Enemy", 3.0, 0.0, 4.0)
+                .unwrap();
+        }
+        handle.tick().unwrap();
+
+        let enemy = handle.pop_render_transform().expect("enemy transform");
+        assert_eq!(enemy.kind, "enemy");
+        assert_eq!((enemy.x, enemy.y), (1.0, 2.0));
+        let boss = handle.pop_render_transform().expect("boss transform");
+        assert_eq!(boss.kind, "Boss Enemy");
+        assert_eq!((boss.x, boss.y), (3.0, 4.0));
+        assert!(handle.pop_render_transform().is_none());
+    }
+
     #[test]
     fn ffi_boundary_smoke_executes_move_slice() {
         let ptr = kitu_init();
@@ -310,6 +612,8 @@ mod tests {
             x: 0.0,
             y: 0.0,
             z: 0.0,
+            kind_len: 0,
+            kind: [0; MAX_KIND_BYTES],
         };
         let pop_status = unsafe { kitu_pop_render_transform(ptr, &mut out as *mut _) };
         assert_eq!(pop_status, 1);
@@ -321,7 +625,90 @@ mod tests {
             std::str::from_utf8(&out.entity_id[..out.entity_id_len as usize]).unwrap(),
             "ffi-player"
         );
+        assert_eq!(&out.kind[..out.kind_len as usize], b"player");
+
+        unsafe { drop(Box::from_raw(ptr)) };
+    }
+
+    #[test]
+    fn ffi_fixed_point_mode_adds_moves_in_fixed_point() {
+        let ptr = kitu_init();
+        assert_eq!(unsafe { kitu_set_fixed_point(ptr, 1) }, 0);
+        assert_eq!(unsafe { kitu_set_fixed_point(std::ptr::null_mut(), 1) }, -1);
+        let handle = unsafe { &*ptr };
+        for _ in 0..3 {
+            assert!(handle.submit_move_input("ffi-player", 0.1, 0.0));
+            handle.tick().unwrap();
+        }
+
+        // Positions stay in fixed point between ticks; only the rendered value is rounded.
+        let step = Fixed::from_f32(0.1);
+        let expected = step + step + step;
+        let last = std::iter::from_fn(|| handle.pop_render_transform())
+            .last()
+            .unwrap();
+        assert_eq!(last.x, expected.to_f32());
+        assert_eq!(
+            handle.runtime.lock().unwrap().numeric_mode(),
+            NumericMode::FixedPoint
+        );
+        unsafe { drop(Box::from_raw(ptr)) };
+    }
+
+    #[test]
+    fn ffi_time_controls_pause_step_and_scale_updates() {
+        let ptr = kitu_init();
+        assert_eq!(unsafe { kitu_set_paused(ptr, 1) }, 0);
+        assert_eq!(unsafe { kitu_update(ptr, 1.0) }, 0);
+        assert_eq!(unsafe { kitu_step(ptr, 2) }, 0);
+        assert_eq!(unsafe { kitu_step(ptr, u32::MAX) }, -2);
+
+        assert_eq!(unsafe { kitu_set_time_scale(ptr, 0.0) }, -2);
+        assert_eq!(unsafe { kitu_set_time_scale(ptr, 3.0) }, 0);
+        assert_eq!(unsafe { kitu_set_paused(ptr, 0) }, 0);
+        assert_eq!(unsafe { kitu_update(ptr, 0.02) }, 3);
+        assert_eq!(unsafe { kitu_update(ptr, f32::NAN) }, -2);
+        assert_eq!(unsafe { kitu_update(std::ptr::null_mut(), 0.02) }, -1);
+
+        let handle = unsafe { &*ptr };
+        assert_eq!(handle.runtime.lock().unwrap().current_tick().get(), 5);
+        unsafe { drop(Box::from_raw(ptr)) };
+    }
+
+    #[test]
+    fn ffi_interest_radius_filters_render_events() {
+        let ptr = kitu_init();
+        let center = CString::new("ffi-player").unwrap();
+        assert_eq!(
+            unsafe { kitu_set_interest_radius(ptr, center.as_ptr(), 5.0) },
+            0
+        );
+        assert_eq!(
+            unsafe { kitu_set_interest_radius(ptr, center.as_ptr(), -1.0) },
+            -4
+        );
+        let kind = CString::new("player").unwrap();
+        assert_eq!(unsafe { kitu_add_interest_kind(ptr, kind.as_ptr()) }, 0);
+        assert_eq!(
+            unsafe { kitu_add_interest_prefix(ptr, std::ptr::null()) },
+            -2
+        );
+
+        let handle = unsafe { &*ptr };
+        assert!(handle.submit_move_input("ffi-player", 1.0, 0.0));
+        assert!(handle.submit_move_input("near", 4.0, 0.0));
+        assert!(handle.submit_move_input("far", 40.0, 0.0));
+        handle.tick().unwrap();
+
+        let mut seen = Vec::new();
+        while let Some(event) = handle.pop_render_transform() {
+            seen.push(event.entity_id);
+        }
+        seen.sort();
+        assert_eq!(seen, ["ffi-player", "near"]);
 
+        assert_eq!(unsafe { kitu_clear_interest(ptr) }, 0);
+        assert_eq!(handle.interest(), InterestFilter::default());
         unsafe { drop(Box::from_raw(ptr)) };
     }
 
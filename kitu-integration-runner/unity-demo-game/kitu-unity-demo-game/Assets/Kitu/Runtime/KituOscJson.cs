@@ -9,9 +9,10 @@ namespace Kitu.Runtime
 {
     public readonly struct KituRenderTransformEvent
     {
-        public KituRenderTransformEvent(string entityId, long tick, float x, float y, float z)
+        public KituRenderTransformEvent(string entityId, string kind, long tick, float x, float y, float z)
         {
             EntityId = entityId;
+            Kind = kind;
             Tick = tick;
             X = x;
             Y = y;
@@ -19,6 +20,7 @@ namespace Kitu.Runtime
         }
 
         public string EntityId { get; }
+        public string Kind { get; }
         public long Tick { get; }
         public float X { get; }
         public float Y { get; }
@@ -59,6 +61,11 @@ namespace Kitu.Runtime
 
     public static class KituOscJson
     {
+        // Plain kinds get their own `/render/<kind>/transform` address; every other kind is sent
+        // as `/render/object/transform` with the kind as a trailing argument.
+        private static readonly Regex RenderTransformAddressRegex = new Regex(
+            "\\\"address\\\":\\\"/render/(?<family>[^/\\\"]+)/transform\\\"");
+
         private static readonly Regex ArgRegex = new Regex(
             "\\{\\\"type\\\":\\\"(?<type>[^\\\"]+)\\\",\\\"value\\\":(?<value>\\\"(?:\\\\.|[^\\\"])*\\\"|-?\\d+(?:\\.\\d+)?(?:[eE][+-]?\\d+)?|true|false)\\}");
 
@@ -76,14 +83,32 @@ namespace Kitu.Runtime
         public static bool TryParseRenderTransform(string json, out KituRenderTransformEvent renderEvent)
         {
             renderEvent = default;
-            if (json.IndexOf("\"type\":\"osc\"", StringComparison.Ordinal) < 0
-                || json.IndexOf("\"address\":\"/render/player/transform\"", StringComparison.Ordinal) < 0)
+            if (json.IndexOf("\"type\":\"osc\"", StringComparison.Ordinal) < 0)
+            {
+                return false;
+            }
+
+            var address = RenderTransformAddressRegex.Match(json);
+            if (!address.Success)
             {
                 return false;
             }
 
+            var family = address.Groups["family"].Value;
             var args = ParseArgs(json);
-            if (args.Count != 5)
+            string kind;
+            if (family == "object")
+            {
+                if (args.Count != 6 || !args[5].TryString(out kind))
+                {
+                    return false;
+                }
+            }
+            else if (args.Count == 5)
+            {
+                kind = family;
+            }
+            else
             {
                 return false;
             }
@@ -97,7 +122,7 @@ namespace Kitu.Runtime
                 return false;
             }
 
-            renderEvent = new KituRenderTransformEvent(entityId, tick, x, y, z);
+            renderEvent = new KituRenderTransformEvent(entityId, kind, tick, x, y, z);
             return true;
         }
 
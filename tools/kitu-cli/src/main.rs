@@ -34,6 +34,9 @@ fn run(args: Vec<String>) -> Result<()> {
         [scope, command, rest @ ..] if scope == "world" => {
             run_world_command(&catalog, command, rest)?;
         }
+        [scope, command, rest @ ..] if scope == "sim" => {
+            run_sim_command(&catalog, command, rest)?;
+        }
         _ => {
             print_help();
             anyhow::bail!("unsupported command");
@@ -99,9 +102,36 @@ fn run_world_command(catalog: &AppActionCatalog, command: &str, args: &[String])
                 ("z", "z", ActionInputType::Float),
             ],
         ),
+        "despawn" => ("despawn-object", &[("id", "id", ActionInputType::String)]),
         "reset" => ("reset-world", &[]),
         _ => anyhow::bail!("unsupported world command `{command}`"),
     };
+    run_aliased_action(catalog, action_id, aliases, args)
+}
+
+fn run_sim_command(catalog: &AppActionCatalog, command: &str, args: &[String]) -> Result<()> {
+    let (action_id, aliases): (&str, &[(&str, &str, ActionInputType)]) = match command {
+        "pause" => ("pause-simulation", &[]),
+        "resume" => ("resume-simulation", &[]),
+        "step" => (
+            "step-simulation",
+            &[("ticks", "ticks", ActionInputType::Int)],
+        ),
+        "time-scale" => (
+            "set-time-scale",
+            &[("scale", "scale", ActionInputType::Float)],
+        ),
+        _ => anyhow::bail!("unsupported sim command `{command}`"),
+    };
+    run_aliased_action(catalog, action_id, aliases, args)
+}
+
+fn run_aliased_action(
+    catalog: &AppActionCatalog,
+    action_id: &str,
+    aliases: &[(&str, &str, ActionInputType)],
+    args: &[String],
+) -> Result<()> {
     let mut inputs = HashMap::new();
     for (flag, name, value_type) in aliases {
         if let Some(raw) = flag_value(args, flag) {
@@ -178,8 +208,13 @@ fn print_help() {
     println!("kitu-cli app action describe <action-id>");
     println!("kitu-cli app action run <action-id> --arg key=value");
     println!("kitu-cli world spawn --kind enemy --x 1 --y 0 --z 2");
-    println!("kitu-cli world move --id obj-1 --x 4 --y 0 --z 6");
+    println!("kitu-cli world move --id 0v0 --x 4 --y 0 --z 6");
+    println!("kitu-cli world despawn --id 0v0");
     println!("kitu-cli world reset");
+    println!("kitu-cli sim pause");
+    println!("kitu-cli sim resume");
+    println!("kitu-cli sim step --ticks 1");
+    println!("kitu-cli sim time-scale --scale 0.25");
 }
 
 #[cfg(test)]
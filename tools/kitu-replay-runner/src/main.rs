@@ -7,7 +7,7 @@ use std::{
 
 use anyhow::{bail, Context, Result};
 use kitu_osc_ir::{OscArg, OscBundle, OscMessage};
-use kitu_runtime::build_runtime;
+use kitu_runtime::{build_runtime, NumericMode};
 use kitu_transport::LocalChannel;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -90,9 +90,22 @@ fn print_help() {
 struct Scenario {
     schema_version: u32,
     scenario_id: String,
+    #[serde(default)]
+    initial_state: InitialState,
     steps: Vec<ScenarioStep>,
 }
 
+#[derive(Debug, Default, Deserialize)]
+struct InitialState {
+    /// Seed of the runtime's random streams; unseeded runtimes have none.
+    #[serde(default)]
+    rng_seed: Option<u64>,
+    /// Runs the runtime in fixed-point mode and compares positions exactly at `f32` precision
+    /// instead of within [`FLOAT_TOLERANCE`].
+    #[serde(default)]
+    fixed_point: bool,
+}
+
 #[derive(Debug, Deserialize)]
 struct ScenarioStep {
     at_tick: u64,
@@ -131,6 +144,8 @@ struct ExpectedOutput {
 struct ExpectedSummary {
     status: String,
     output_count: usize,
+    #[serde(default)]
+    final_state_hash: Option<String>,
 }
 
 #[derive(Debug, Serialize, PartialEq)]
@@ -148,8 +163,20 @@ struct ReplaySummary {
 
 #[derive(Debug, Serialize, PartialEq)]
 struct ObservedSummary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rng_seed: Option<u64>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    fixed_point: bool,
     output_count: usize,
     mismatch_count: usize,
+    final_state_hash: String,
+    state_hashes: Vec<ObservedStateHash>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+struct ObservedStateHash {
+    tick: u64,
+    hash: String,
 }
 
 #[derive(Debug, Serialize, PartialEq)]
@@ -164,6 +191,14 @@ fn run_replay(scenario_path: &Path, expected_path: &Path) -> Result<ReplaySummar
     validate_contract_pair(&scenario, &expected)?;
 
     let mut runtime = build_runtime(LocalChannel::default());
+    if let Some(seed) = scenario.initial_state.rng_seed {
+        runtime.seed_rng(seed)?;
+    }
+    let fixed_point = scenario.initial_state.fixed_point;
+    if fixed_point {
+        runtime.set_numeric_mode(NumericMode::FixedPoint)?;
+    }
+    runtime.record_state_hash_every(1);
     let mut observed_outputs = Vec::new();
     let run_until_tick = run_until_tick(&scenario, &expected);
 
@@ -181,7 +216,14 @@ fn run_replay(scenario_path: &Path, expected_path: &Path) -> Result<ReplaySummar
         }
     }
 
-    let mismatch_count = mismatch_count(&expected.expected_outputs, &observed_outputs);
+    let final_state_hash = format_state_hash(runtime.state_hash()?);
+    let state_hash_mismatch = expected
+        .expected_summary
+        .final_state_hash
+        .as_ref()
+        .is_some_and(|hash| *hash != final_state_hash);
+    let mismatch_count = mismatch_count(&expected.expected_outputs, &observed_outputs, fixed_point)
+        + usize::from(state_hash_mismatch);
     let output_count_matches = observed_outputs.len() == expected.expected_summary.output_count;
     let expected_status_allows_pass = expected.expected_summary.status == "pass";
     let status = if mismatch_count == 0 && output_count_matches && expected_status_allows_pass {
@@ -199,8 +241,19 @@ fn run_replay(scenario_path: &Path, expected_path: &Path) -> Result<ReplaySummar
         started_at: "1970-01-01T00:00:00Z".to_string(),
         finished_at: "1970-01-01T00:00:00Z".to_string(),
         observed: ObservedSummary {
+            rng_seed: runtime.rng_seed(),
+            fixed_point,
             output_count: observed_outputs.len(),
             mismatch_count,
+            final_state_hash,
+            state_hashes: runtime
+                .drain_state_hashes()
+                .into_iter()
+                .map(|(tick, hash)| ObservedStateHash {
+                    tick: tick.get(),
+                    hash: format_state_hash(hash),
+                })
+                .collect(),
         },
         files: SummaryFiles {
             scenario: scenario_path.display().to_string(),
@@ -209,6 +262,11 @@ fn run_replay(scenario_path: &Path, expected_path: &Path) -> Result<ReplaySummar
     })
 }
 
+/// Formats a state hash as fixed-width hex; JSON numbers cannot carry all 64 bits portably.
+fn format_state_hash(hash: u64) -> String {
+    format!("{hash:016x}")
+}
+
 fn read_json<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<T> {
     let source = fs::read_to_string(path).with_context(|| format!("read `{}`", path.display()))?;
     serde_json::from_str(&source).with_context(|| format!("parse `{}`", path.display()))
@@ -320,23 +378,24 @@ fn float_arg(arg: &OscArg, name: &str) -> Result<f32> {
     }
 }
 
-fn mismatch_count(expected: &[ExpectedOutput], observed: &[ExpectedOutput]) -> usize {
+fn mismatch_count(expected: &[ExpectedOutput], observed: &[ExpectedOutput], exact: bool) -> usize {
     let pair_mismatches = expected
         .iter()
         .zip(observed)
-        .filter(|(expected, observed)| !outputs_match(expected, observed))
+        .filter(|(expected, observed)| !outputs_match(expected, observed, exact))
         .count();
     let length_mismatches = expected.len().abs_diff(observed.len());
     pair_mismatches + length_mismatches
 }
 
-fn outputs_match(expected: &ExpectedOutput, observed: &ExpectedOutput) -> bool {
+/// Compares an output pair; `exact` requires positions to round to the same `f32`.
+fn outputs_match(expected: &ExpectedOutput, observed: &ExpectedOutput, exact: bool) -> bool {
     expected.tick == observed.tick
         && expected.address == observed.address
-        && args_match(&expected.args, &observed.args)
+        && args_match(&expected.args, &observed.args, exact)
 }
 
-fn args_match(expected: &Value, observed: &Value) -> bool {
+fn args_match(expected: &Value, observed: &Value, exact: bool) -> bool {
     let Some(expected_object) = expected.as_object() else {
         return expected == observed;
     };
@@ -350,7 +409,7 @@ fn args_match(expected: &Value, observed: &Value) -> bool {
         };
 
         if key == "position" {
-            if !position_matches(expected_value, observed_value) {
+            if !position_matches(expected_value, observed_value, exact) {
                 return false;
             }
         } else if expected_value != observed_value {
@@ -361,11 +420,12 @@ fn args_match(expected: &Value, observed: &Value) -> bool {
     expected_object.len() == observed_object.len()
 }
 
-fn position_matches(expected: &Value, observed: &Value) -> bool {
+fn position_matches(expected: &Value, observed: &Value, exact: bool) -> bool {
     ["x", "y", "z"].iter().all(|axis| {
         let expected = expected.get(axis).and_then(Value::as_f64);
         let observed = observed.get(axis).and_then(Value::as_f64);
         match (expected, observed) {
+            (Some(expected), Some(observed)) if exact => expected as f32 == observed as f32,
             (Some(expected), Some(observed)) => (expected - observed).abs() <= FLOAT_TOLERANCE,
             _ => false,
         }
@@ -462,7 +522,13 @@ mod tests {
             }),
         };
 
-        assert!(outputs_match(&expected, &observed));
+        assert!(outputs_match(&expected, &observed, false));
+        assert!(outputs_match(&expected, &observed, true));
+
+        let mut nudged = observed;
+        nudged.args["position"]["x"] = Value::from(0.1000001);
+        assert!(outputs_match(&expected, &nudged, false));
+        assert!(!outputs_match(&expected, &nudged, true));
     }
 
     #[test]
@@ -479,5 +545,127 @@ mod tests {
         assert_eq!(first.status, "pass");
         assert_eq!(first.observed.output_count, 1);
         assert_eq!(first.observed.mismatch_count, 0);
+        assert_eq!(
+            first.observed.state_hashes.last().map(|entry| &entry.hash),
+            Some(&first.observed.final_state_hash)
+        );
+    }
+
+    #[test]
+    fn replay_fails_when_final_state_hash_differs_from_expected() {
+        let fixture_root = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../kitu-integration-runner/scenarios/smoke/player-move-basic");
+        let mut expected: Value =
+            serde_json::from_str(&fs::read_to_string(fixture_root.join("expected.json")).unwrap())
+                .unwrap();
+        expected["expected_summary"]["final_state_hash"] = Value::from("0000000000000000");
+        let expected_path = env::temp_dir().join(format!(
+            "kitu-replay-runner-hash-mismatch-{}.json",
+            std::process::id()
+        ));
+        fs::write(&expected_path, expected.to_string()).unwrap();
+
+        let summary = run_replay(&fixture_root.join("scenario.json"), &expected_path).unwrap();
+        fs::remove_file(&expected_path).unwrap();
+
+        assert_eq!(summary.status, "fail");
+        assert_eq!(summary.observed.mismatch_count, 1);
+    }
+
+    #[test]
+    fn scenario_rng_seed_reaches_the_runtime_and_summary() {
+        let fixture_root = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../kitu-integration-runner/scenarios/smoke/player-move-basic");
+        let mut scenario: Value =
+            serde_json::from_str(&fs::read_to_string(fixture_root.join("scenario.json")).unwrap())
+                .unwrap();
+        scenario["initial_state"]["rng_seed"] = Value::from(1234_u64);
+        let mut expected: Value =
+            serde_json::from_str(&fs::read_to_string(fixture_root.join("expected.json")).unwrap())
+                .unwrap();
+        expected["expected_summary"]
+            .as_object_mut()
+            .unwrap()
+            .remove("final_state_hash");
+        let temp = env::temp_dir();
+        let scenario_path = temp.join(format!(
+            "kitu-replay-runner-seeded-scenario-{}.json",
+            std::process::id()
+        ));
+        let expected_path = temp.join(format!(
+            "kitu-replay-runner-seeded-expected-{}.json",
+            std::process::id()
+        ));
+        fs::write(&scenario_path, scenario.to_string()).unwrap();
+        fs::write(&expected_path, expected.to_string()).unwrap();
+
+        let first = run_replay(&scenario_path, &expected_path).unwrap();
+        let second = run_replay(&scenario_path, &expected_path).unwrap();
+        let unseeded = run_replay(
+            &fixture_root.join("scenario.json"),
+            &fixture_root.join("expected.json"),
+        )
+        .unwrap();
+        fs::remove_file(&scenario_path).unwrap();
+        fs::remove_file(&expected_path).unwrap();
+
+        assert_eq!(first.status, "pass");
+        assert_eq!(first.observed.rng_seed, Some(1234));
+        assert_eq!(first.observed, second.observed);
+        assert_eq!(unseeded.observed.rng_seed, None);
+        assert_ne!(
+            first.observed.final_state_hash,
+            unseeded.observed.final_state_hash
+        );
+        assert!(!serde_json::to_string(&unseeded)
+            .unwrap()
+            .contains("rng_seed"));
+    }
+
+    #[test]
+    fn fixed_point_scenarios_run_in_fixed_point_mode() {
+        let fixture_root = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../kitu-integration-runner/scenarios/smoke/player-move-basic");
+        let mut scenario: Value =
+            serde_json::from_str(&fs::read_to_string(fixture_root.join("scenario.json")).unwrap())
+                .unwrap();
+        scenario["initial_state"]["fixed_point"] = Value::from(true);
+        let mut expected: Value =
+            serde_json::from_str(&fs::read_to_string(fixture_root.join("expected.json")).unwrap())
+                .unwrap();
+        expected["expected_summary"]
+            .as_object_mut()
+            .unwrap()
+            .remove("final_state_hash");
+        let temp = env::temp_dir();
+        let scenario_path = temp.join(format!(
+            "kitu-replay-runner-fixed-scenario-{}.json",
+            std::process::id()
+        ));
+        let expected_path = temp.join(format!(
+            "kitu-replay-runner-fixed-expected-{}.json",
+            std::process::id()
+        ));
+        fs::write(&scenario_path, scenario.to_string()).unwrap();
+        fs::write(&expected_path, expected.to_string()).unwrap();
+
+        let summary = run_replay(&scenario_path, &expected_path).unwrap();
+        let float = run_replay(
+            &fixture_root.join("scenario.json"),
+            &fixture_root.join("expected.json"),
+        )
+        .unwrap();
+        fs::remove_file(&scenario_path).unwrap();
+        fs::remove_file(&expected_path).unwrap();
+
+        assert_eq!(summary.status, "pass");
+        assert!(summary.observed.fixed_point);
+        assert_ne!(
+            summary.observed.final_state_hash,
+            float.observed.final_state_hash
+        );
+        assert!(!serde_json::to_string(&float)
+            .unwrap()
+            .contains("fixed_point"));
     }
 }
@@ -8,6 +8,7 @@ import type {
   ClientOscMessage,
   DebugLogEntry,
   JsonOscArg,
+  RuntimeWorldSnapshot,
   ServerEvent,
   WorldSnapshot,
 } from "./types";
@@ -68,6 +69,37 @@ export const lastOscSendStatus = writable<OscSendStatus>({
   detail: null,
 });
 
+function colorForKind(kind: string): string {
+  switch (kind) {
+    case "player":
+      return "#38bdf8";
+    case "spawn-point":
+      return "#2dd4bf";
+    case "enemy":
+      return "#fb7185";
+    case "treasure":
+      return "#facc15";
+    case "trigger":
+      return "#a78bfa";
+    default:
+      return "#60a5fa";
+  }
+}
+
+export function toWorldSnapshot(snapshot: RuntimeWorldSnapshot): WorldSnapshot {
+  return {
+    tick: snapshot.tick,
+    objects: snapshot.objects.map((object) => ({
+      id: object.id,
+      kind: object.kind,
+      x: object.transform.x,
+      y: object.transform.y,
+      z: object.transform.z,
+      color: colorForKind(object.kind),
+    })),
+  };
+}
+
 export const objectCount = derived(
   worldSnapshot,
   ($snapshot) => $snapshot.objects.length,
@@ -302,6 +334,12 @@ export async function moveObject(id: string, x: number, y: number, z: number) {
   });
 }
 
+export async function despawnObject(id: string) {
+  return runAppAction("despawn-object", {
+    id: { type: "string", value: id },
+  });
+}
+
 export async function resetWorld() {
   return runAppAction("reset-world", {});
 }
@@ -559,7 +597,7 @@ function applyServerEvent(event: ServerEvent) {
       break;
     }
     case "state":
-      worldSnapshot.set(event.snapshot);
+      worldSnapshot.set(toWorldSnapshot(event.snapshot));
       break;
     case "log":
       debugLogs.update((entries) =>
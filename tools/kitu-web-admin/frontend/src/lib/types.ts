@@ -20,6 +20,26 @@ export type WorldSnapshot = {
   objects: WorldObject[];
 };
 
+export type Vec3 = { x: number; y: number; z: number };
+
+export type Quat = { x: number; y: number; z: number; w: number };
+
+/** Wire format of `kitu_ecs::WorldObject`. */
+export type RuntimeWorldObject = {
+  entity: string;
+  id: string;
+  kind: string;
+  transform: Vec3 & { rotation: Quat; scale: Vec3 };
+};
+
+/** Wire format of `kitu_ecs::WorldSnapshot`. */
+export type RuntimeWorldSnapshot = {
+  schema_version: number;
+  tick: number;
+  objects: RuntimeWorldObject[];
+  resources: Record<string, unknown>;
+};
+
 export type JsonOscArg =
   | { type: "int"; value: number }
   | { type: "int64"; value: number }
@@ -73,12 +93,12 @@ export type AppActionCatalog = {
 export type ActionRunResponse = {
   actionId: string;
   osc: ClientOscMessage;
-  snapshot: WorldSnapshot;
+  snapshot: RuntimeWorldSnapshot;
 };
 
 export type ServerEvent =
   | { type: "connected"; protocol: string; tick: number }
-  | { type: "state"; snapshot: WorldSnapshot }
+  | { type: "state"; snapshot: RuntimeWorldSnapshot }
   | { type: "log"; entry: DebugLogEntry }
   | { type: "osc"; address: string; args: JsonOscArg[] }
   | { type: "error"; message: string };
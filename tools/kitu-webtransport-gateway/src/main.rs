@@ -1,7 +1,6 @@
 use std::{
     collections::VecDeque,
     env,
-    sync::Arc,
     time::{Duration, Instant},
 };
 
@@ -66,17 +65,21 @@ async fn main() -> Result<()> {
         let config = config.clone();
         tokio::spawn(async move {
             match incoming_session.await {
-                Ok(request) => match request.accept().await {
-                    Ok(connection) => {
-                        info!(
-                            connection_id = connection.stable_id(),
-                            remote = %connection.remote_address(),
-                            "accepted WebTransport session"
-                        );
-                        handle_connection(connection, Arc::new(config)).await;
+                Ok(request) => {
+                    let internal_ws_url =
+                        internal_ws_url_for_session(&config.internal_ws_url, request.path());
+                    match request.accept().await {
+                        Ok(connection) => {
+                            info!(
+                                connection_id = connection.stable_id(),
+                                remote = %connection.remote_address(),
+                                "accepted WebTransport session"
+                            );
+                            handle_connection(connection, internal_ws_url).await;
+                        }
+                        Err(err) => warn!("failed to accept WebTransport request: {err}"),
                     }
-                    Err(err) => warn!("failed to accept WebTransport request: {err}"),
-                },
+                }
                 Err(err) => warn!("failed WebTransport session handshake: {err}"),
             }
         });
@@ -120,7 +123,58 @@ async fn load_identity(config: &GatewayConfig) -> Result<Identity> {
     }
 }
 
-async fn handle_connection(connection: wtransport::Connection, config: Arc<GatewayConfig>) {
+/// Interest parameters of the host's WebSocket endpoints that sessions may set.
+const FORWARDED_QUERY_KEYS: [&str; 4] = ["prefixes", "kinds", "focus", "radius"];
+
+/// Appends the interest parameters of a WebTransport session URL to the internal WebSocket URL.
+///
+/// Sessions opened as `/?kinds=player&focus=player:local&radius=20` keep their interest
+/// parameters, so the host routes only the outputs that session can see to its relay. Other
+/// parameters and repeated keys are dropped, and bytes outside a conservative set are
+/// percent-encoded, so a session cannot change anything else about the internal connection.
+fn internal_ws_url_for_session(internal_ws_url: &str, session_path: &str) -> String {
+    let query = session_path
+        .split_once('?')
+        .map_or("", |(_, query)| query.split('#').next().unwrap_or(""));
+    let mut forwarded: Vec<(&str, String)> = Vec::new();
+    for pair in query.split('&') {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        if FORWARDED_QUERY_KEYS.contains(&key) && forwarded.iter().all(|(seen, _)| *seen != key) {
+            forwarded.push((key, encode_query_value(value)));
+        }
+    }
+    if forwarded.is_empty() {
+        return internal_ws_url.to_string();
+    }
+    let query = forwarded
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join("&");
+    let separator = if internal_ws_url.contains('?') {
+        '&'
+    } else {
+        '?'
+    };
+    format!("{internal_ws_url}{separator}{query}")
+}
+
+/// Percent-encodes every byte of a query value except ASCII alphanumerics, `-._~,/:` and `%`,
+/// which keeps values the client already encoded intact.
+fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| {
+            if byte.is_ascii_alphanumeric() || b"-._~,/:%".contains(&byte) {
+                char::from(byte).to_string()
+            } else {
+                format!("%{byte:02X}")
+            }
+        })
+        .collect()
+}
+
+async fn handle_connection(connection: wtransport::Connection, internal_ws_url: String) {
     let mut recent_messages = VecDeque::new();
     let connection_id = connection.stable_id();
     let datagram_connection = connection.clone();
@@ -129,9 +183,8 @@ async fn handle_connection(connection: wtransport::Connection, config: Arc<Gatew
             info!(connection_id, "WebTransport datagram loop closed: {err:#}");
         }
     });
-    let internal_relay = std::sync::Arc::new(Mutex::new(InternalWebSocketRelay::new(
-        config.internal_ws_url.clone(),
-    )));
+    let internal_relay =
+        std::sync::Arc::new(Mutex::new(InternalWebSocketRelay::new(internal_ws_url)));
 
     loop {
         match connection.accept_bi().await {
@@ -312,10 +365,43 @@ mod tests {
     use kitu_transport::{encode_kep_envelope, KepEnvelope};
 
     use super::{
-        handle_datagram_payload, KEP_ROUTE_DATAGRAM_ACK, KEP_ROUTE_DATAGRAM_PROBE,
-        MAX_DATAGRAM_BYTES,
+        handle_datagram_payload, internal_ws_url_for_session, KEP_ROUTE_DATAGRAM_ACK,
+        KEP_ROUTE_DATAGRAM_PROBE, MAX_DATAGRAM_BYTES,
     };
 
+    #[test]
+    fn session_query_is_forwarded_to_the_internal_websocket() {
+        let base = "ws://demo-game:8787/ws";
+        assert_eq!(internal_ws_url_for_session(base, "/"), base);
+        assert_eq!(internal_ws_url_for_session(base, "/?"), base);
+        assert_eq!(
+            internal_ws_url_for_session(base, "/?kinds=player&radius=20&focus=player:local"),
+            "ws://demo-game:8787/ws?kinds=player&radius=20&focus=player:local"
+        );
+        assert_eq!(
+            internal_ws_url_for_session("ws://host/ws?state=delta", "/?prefixes=/render/"),
+            "ws://host/ws?state=delta&prefixes=/render/"
+        );
+    }
+
+    #[test]
+    fn only_known_interest_parameters_are_forwarded() {
+        let base = "ws://demo-game:8787/ws";
+        assert_eq!(
+            internal_ws_url_for_session(base, "/?state=full&token=x&kinds=npc&kinds=player"),
+            "ws://demo-game:8787/ws?kinds=npc"
+        );
+        assert_eq!(internal_ws_url_for_session(base, "/?state=delta"), base);
+        assert_eq!(
+            internal_ws_url_for_session(base, "/?focus=a b#frag&radius=5"),
+            "ws://demo-game:8787/ws?focus=a%20b"
+        );
+        assert_eq!(
+            internal_ws_url_for_session(base, "/?focus=player%3Alocal&radius=2.5"),
+            "ws://demo-game:8787/ws?focus=player%3Alocal&radius=2.5"
+        );
+    }
+
     #[test]
     fn datagram_probe_returns_json_ack() {
         let mut request = KepEnvelope::json(br#"{"type":"probe"}"#.to_vec());